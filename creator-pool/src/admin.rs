@@ -17,7 +17,9 @@
 
 pub use pool_core::admin::{
     ensure_not_drained, execute_cancel_emergency_withdraw,
-    execute_claim_emergency_share, execute_emergency_withdraw_dispatch, execute_pause,
+    execute_claim_emergency_share, execute_collect_protocol_fees,
+    execute_emergency_withdraw_dispatch, execute_pause, execute_set_protocol_fee_share,
+    save_protocol_fee_share,
     execute_sweep_unclaimed_emergency_shares, execute_unpause,
     execute_update_config_from_factory, CoreDrainResult,
 };
@@ -8,6 +8,20 @@
 //! - [`post_threshold`]      — commits after the pool is fully funded
 //! - [`threshold_crossing`]  — the commit that carries the pool across
 //! - [`distribution`]        — post-threshold keeper-driven payout batches
+//! - [`pull_distribution`]   — committer-claimed payouts for `Pull` pools
+//! - [`allowlist`]           — allowlist-only early-access commit phase
+//! - [`creator_wallet`]      — creator-initiated wallet rotation
+//! - [`early_supporter`]     — time-weighted shares for early committers
+//! - [`refund`]              — failed-raise refunds after a missed deadline
+//! - [`revenue_split`]       — weighted collaborator split of creator revenue
+//! - [`simulate`]            — read-only preview behind `SimulateCommit`
+//! - [`sponsorship`]         — sponsor escrows that match pre-threshold commits
+//! - [`subscription`]        — escrowed recurring commits run by keepers
+//! - [`membership`]          — creator-defined tiers that tagged commits buy
+//! - [`referral`]            — referrer balances carved from the creator fee
+//! - [`token_marketing`]     — creator-set CW20 marketing metadata
+//! - [`vesting`]             — linear release of the creator's threshold reward
+//! - [`wallet_cap`]          — per-wallet pre-threshold commit caps
 //!
 //! This file keeps:
 //! - `commit` / `execute_commit_logic` — the entry point + dispatcher
@@ -18,17 +32,49 @@
 //! and re-exports `execute_continue_distribution` so the pool's entry
 //! points don't need to know about the submodule structure.
 
+pub mod allowlist;
+pub mod creator_wallet;
 pub mod distribution;
 pub mod distribution_batch;
+pub mod early_supporter;
+pub mod membership;
 pub mod post_threshold;
 pub mod pre_threshold;
+pub mod pull_distribution;
+pub mod referral;
+pub mod refund;
+pub mod revenue_split;
+pub mod simulate;
+pub mod sponsorship;
+pub mod subscription;
 pub mod threshold_crossing;
 pub mod threshold_payout;
+pub mod token_marketing;
+pub mod vesting;
+pub mod wallet_cap;
 
+pub use allowlist::execute_set_commit_allowlist;
+pub use creator_wallet::{
+    execute_accept_creator_wallet, execute_propose_creator_wallet, execute_veto_creator_wallet,
+};
 pub use distribution::execute_continue_distribution;
+pub use membership::execute_set_subscription_tiers;
+pub use pull_distribution::execute_claim_commit_reward;
+pub use referral::execute_claim_referral_rewards;
+pub use refund::execute_claim_commit_refund;
+pub use revenue_split::{
+    execute_apply_revenue_split, execute_cancel_revenue_split, execute_propose_revenue_split,
+};
+pub use sponsorship::{execute_reclaim_sponsorship, execute_sponsor};
+pub use subscription::{
+    execute_cancel_subscription, execute_renew_subscription, execute_subscribe,
+};
+pub use token_marketing::execute_update_token_marketing;
+pub use vesting::execute_claim_vested_creator_reward;
 
 use cosmwasm_std::{
-    Addr, CosmosMsg, Decimal, DepsMut, Env, Fraction, MessageInfo, Response, Timestamp, Uint128,
+    Addr, CosmosMsg, Decimal, DepsMut, Env, Fraction, HexBinary, MessageInfo, Response, Storage,
+    Timestamp, Uint128,
 };
 
 use crate::admin::ensure_not_drained;
@@ -41,8 +87,8 @@ use crate::generic_helpers::{
 use crate::msg::CommitFeeInfo;
 use crate::state::{
     COMMITFEEINFO, COMMIT_LIMIT_INFO, IS_THRESHOLD_HIT, LAST_THRESHOLD_ATTEMPT, POOL_ANALYTICS,
-    POOL_FEE_STATE, POOL_INFO, POOL_PAUSED, POOL_SPECS, POOL_STATE, THRESHOLD_PAYOUT_AMOUNTS,
-    THRESHOLD_PROCESSING, USD_RAISED_FROM_COMMIT,
+    POOL_FEE_STATE, POOL_INFO, POOL_PAUSED, POOL_SPECS, POOL_STATE, REFERRAL_FEE_SHARE,
+    THRESHOLD_PAYOUT_AMOUNTS, THRESHOLD_PROCESSING, USD_RAISED_FROM_COMMIT,
 };
 use crate::swap_helper::get_oracle_conversion_with_staleness;
 
@@ -86,14 +132,25 @@ pub(crate) fn commit_base_attributes(
     ]
 }
 
+/// The optional fields of `ExecuteMsg::Commit`. Internal callers (e.g.
+/// subscription renewals) set only what they need and default the rest.
+#[derive(Default)]
+pub struct CommitOptions {
+    pub transaction_deadline: Option<Timestamp>,
+    pub belief_price: Option<Decimal>,
+    pub max_spread: Option<Decimal>,
+    pub tier: Option<String>,
+    pub beneficiary: Option<String>,
+    pub referrer: Option<String>,
+    pub allowlist_proof: Option<Vec<HexBinary>>,
+}
+
 pub fn commit(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     asset: TokenInfo,
-    transaction_deadline: Option<Timestamp>,
-    belief_price: Option<Decimal>,
-    max_spread: Option<Decimal>,
+    options: CommitOptions,
 ) -> Result<Response, ContractError> {
     ensure_not_drained(deps.storage)?;
     // admin (or auto-low-liquidity) pause halts ALL
@@ -113,13 +170,18 @@ pub fn commit(
     if POOL_PAUSED.may_load(deps.storage)?.unwrap_or(false) {
         return Err(ContractError::PoolPausedLowLiquidity {});
     }
-    enforce_transaction_deadline(env.block.time, transaction_deadline)?;
+    // A raise that missed its deadline is in (or about to enter) refund
+    // state; new money would just be refunded again.
+    refund::ensure_raise_open(deps.storage, &env)?;
+    enforce_transaction_deadline(env.block.time, options.transaction_deadline)?;
 
     with_reentrancy_guard(deps, |mut deps| {
         let pool_specs = POOL_SPECS.load(deps.storage)?;
+        // Rate limit is keyed on the payer, so gifting can't be used to
+        // fan one wallet's commits out past its own limit.
         let sender = info.sender.clone();
         check_rate_limit(&mut deps, &env, &pool_specs, &sender)?;
-        execute_commit_logic(&mut deps, env, info, asset, belief_price, max_spread)
+        execute_commit_logic(&mut deps, env, info, asset, options)
     })
 }
 
@@ -128,9 +190,17 @@ fn execute_commit_logic(
     env: Env,
     info: MessageInfo,
     asset: TokenInfo,
-    belief_price: Option<Decimal>,
-    max_spread: Option<Decimal>,
+    options: CommitOptions,
 ) -> Result<Response, ContractError> {
+    let CommitOptions {
+        belief_price,
+        max_spread,
+        tier,
+        beneficiary,
+        referrer,
+        allowlist_proof,
+        ..
+    } = options;
     let amount = asset.amount;
     let pool_info = POOL_INFO.load(deps.storage)?;
     let mut pool_state = POOL_STATE.load(deps.storage)?;
@@ -139,7 +209,22 @@ fn execute_commit_logic(
     let mut pool_fee_state = POOL_FEE_STATE.load(deps.storage)?;
     let threshold_payout = THRESHOLD_PAYOUT_AMOUNTS.load(deps.storage)?;
     let fee_info = COMMITFEEINFO.load(deps.storage)?;
-    let sender = info.sender.clone();
+    // `sender` is who the commit is credited to: ledger, distribution
+    // rewards, swap output and membership. `info.sender` only pays.
+    let payer = info.sender.clone();
+    let sender = match &beneficiary {
+        Some(beneficiary) => deps.api.addr_validate(beneficiary)?,
+        None => payer.clone(),
+    };
+    let referrer = referrer
+        .map(|r| deps.api.addr_validate(&r))
+        .transpose()?;
+    if referrer
+        .as_ref()
+        .is_some_and(|r| *r == payer || *r == sender)
+    {
+        return Err(ContractError::SelfReferral {});
+    }
 
     // commits flow only in the bluechip direction.
     // `validate_pool_token_info` pins `asset_infos[0]` to the canonical
@@ -181,6 +266,14 @@ fn execute_commit_logic(
     // commit check here and the main branching below (used later as
     // `threshold_already_hit`). Previously the load was duplicated.
     let threshold_already_hit = IS_THRESHOLD_HIT.load(deps.storage)?;
+    if !threshold_already_hit {
+        allowlist::ensure_commit_allowed(
+            deps.storage,
+            &env,
+            &sender,
+            allowlist_proof.as_deref(),
+        )?;
+    }
     let min_commit = if threshold_already_hit {
         commit_config.min_commit_usd_post_threshold
     } else {
@@ -199,6 +292,40 @@ fn execute_commit_logic(
         });
     }
 
+    // Per-wallet pre-threshold cap. Applied before fees and membership
+    // so everything downstream sees only the accepted part; the rest of
+    // the attached bluechip goes back to the payer. The accepted part
+    // must still clear `min_commit`.
+    let paid_amount = amount;
+    let (asset, usd_value, cap_refund) = if threshold_already_hit {
+        (asset, usd_value, Uint128::zero())
+    } else {
+        let capped = wallet_cap::clamp_to_wallet_cap(
+            deps.storage,
+            &commit_config,
+            &sender,
+            asset,
+            usd_value,
+            min_commit,
+        )?;
+        (capped.asset, capped.usd_value, capped.refund)
+    };
+    let amount = asset.amount;
+
+    // Tier-tagged commits extend the committer's membership. Recorded
+    // before the phase handlers run; any later error reverts it with
+    // the rest of the tx.
+    let membership = match &tier {
+        Some(tier) => Some(membership::record_membership(
+            deps.storage,
+            &env,
+            &sender,
+            tier,
+            usd_value,
+        )?),
+        None => None,
+    };
+
     let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
 
     match &asset.info {
@@ -224,13 +351,19 @@ fn execute_commit_logic(
                     reason: e.to_string(),
                 }
             })?;
-            if sent != amount {
+            if sent != paid_amount {
                 return Err(ContractError::MismatchAmount {});
             }
 
-            let (commit_fee_bluechip_amt, commit_fee_creator_amt) =
-                calculate_commit_fees(amount, &fee_info)?;
-            let total_fees = commit_fee_bluechip_amt.checked_add(commit_fee_creator_amt)?;
+            let referral_share = match &referrer {
+                Some(_) => REFERRAL_FEE_SHARE.may_load(deps.storage)?.unwrap_or_default(),
+                None => Decimal::zero(),
+            };
+            let (commit_fee_bluechip_amt, commit_fee_creator_amt, referral_fee_amt) =
+                calculate_commit_fees(amount, &fee_info, referral_share)?;
+            let total_fees = commit_fee_bluechip_amt
+                .checked_add(commit_fee_creator_amt)?
+                .checked_add(referral_fee_amt)?;
             if total_fees >= amount {
                 return Err(ContractError::InvalidFee {});
             }
@@ -239,12 +372,16 @@ fn execute_commit_logic(
                 return Err(ContractError::InvalidFee {});
             }
 
-            let messages = build_fee_messages(
+            let mut messages = build_fee_messages(
+                deps.storage,
                 &fee_info,
                 denom,
                 commit_fee_bluechip_amt,
                 commit_fee_creator_amt,
             )?;
+            if !cap_refund.is_zero() {
+                messages.push(get_bank_transfer_to_msg(&payer, denom, cap_refund)?);
+            }
 
             // Load `POOL_ANALYTICS` once for this dispatch path; the
             // `total_commit_count` bump is universal to every commit
@@ -304,6 +441,7 @@ fn execute_commit_logic(
                             deps,
                             env,
                             sender,
+                            &payer,
                             &asset,
                             amount,
                             amount_after_fees,
@@ -347,10 +485,10 @@ fn execute_commit_logic(
                         )?
                     }
                 } else {
-                    process_pre_threshold_commit(
+                    let response = process_pre_threshold_commit(
                         deps,
-                        env,
-                        sender,
+                        env.clone(),
+                        sender.clone(),
                         &asset,
                         usd_value,
                         // Net-of-fees bluechip that actually enters the
@@ -360,7 +498,19 @@ fn execute_commit_logic(
                         messages,
                         &pool_state,
                         &mut analytics,
-                    )?
+                    )?;
+                    // Sponsors only match commits that stay below the
+                    // threshold; the crossing commit is the committer's
+                    // alone.
+                    response.add_attributes(sponsorship::match_commit(
+                        deps.storage,
+                        &env,
+                        &sender,
+                        &commit_config,
+                        &pool_state.pool_contract_address,
+                        amount,
+                        usd_value,
+                    )?)
                 }
             } else {
                 process_post_threshold_commit(
@@ -386,7 +536,38 @@ fn execute_commit_logic(
             // (CosmWasm storage is transactional), so this save
             // never persists in error paths.
             POOL_ANALYTICS.save(deps.storage, &analytics)?;
-            Ok(response)
+            let response = if beneficiary.is_some() {
+                response.add_attribute("payer", payer.to_string())
+            } else {
+                response
+            };
+            let response = if cap_refund.is_zero() {
+                response
+            } else {
+                response.add_attribute("wallet_cap_refund", cap_refund.to_string())
+            };
+            // The referral fee stays in the pool's bank balance as the
+            // referrer's claimable balance.
+            let response = match &referrer {
+                Some(referrer) => {
+                    referral::record_referral(
+                        deps.storage,
+                        referrer,
+                        usd_value,
+                        referral_fee_amt,
+                    )?;
+                    response
+                        .add_attribute("referrer", referrer.to_string())
+                        .add_attribute("referral_fee", referral_fee_amt.to_string())
+                }
+                None => response,
+            };
+            Ok(match membership {
+                Some(m) => response
+                    .add_attribute("membership_tier", m.tier)
+                    .add_attribute("membership_expires_at", m.expires_at.seconds().to_string()),
+                None => response,
+            })
         }
         _ => Err(ContractError::AssetMismatch {}),
     }
@@ -396,11 +577,15 @@ fn execute_commit_logic(
 // Helpers
 // ---------------------------------------------------------------------------
 
-/// Calculate both fee portions for a commit. Returns (bluechip_fee, creator_fee).
-fn calculate_commit_fees(
+/// Calculate the fee portions for a commit. Returns (bluechip_fee,
+/// creator_fee, referral_fee): the referral fee is `referral_share` of
+/// the creator's cut and is subtracted from it, so a referred commit
+/// costs the committer the same total fee.
+pub(crate) fn calculate_commit_fees(
     amount: Uint128,
     fee_info: &CommitFeeInfo,
-) -> Result<(Uint128, Uint128), ContractError> {
+    referral_share: Decimal,
+) -> Result<(Uint128, Uint128, Uint128), ContractError> {
     let bluechip_fee = amount
         .checked_mul(fee_info.commit_fee_bluechip.numerator())?
         .checked_div(fee_info.commit_fee_bluechip.denominator())
@@ -409,11 +594,17 @@ fn calculate_commit_fees(
         .checked_mul(fee_info.commit_fee_creator.numerator())?
         .checked_div(fee_info.commit_fee_creator.denominator())
         .map_err(|_| ContractError::DivideByZero)?;
-    Ok((bluechip_fee, creator_fee))
+    let referral_fee = creator_fee
+        .checked_mul(referral_share.numerator())?
+        .checked_div(referral_share.denominator())
+        .map_err(|_| ContractError::DivideByZero)?;
+    Ok((bluechip_fee, creator_fee.checked_sub(referral_fee)?, referral_fee))
 }
 
-/// Build bank-send messages for the two fee recipients.
+/// Build bank-send messages for the bluechip fee and the creator fee,
+/// the latter split across the creator's revenue recipients.
 fn build_fee_messages(
+    storage: &dyn Storage,
     fee_info: &CommitFeeInfo,
     denom: &str,
     bluechip_fee: Uint128,
@@ -428,11 +619,13 @@ fn build_fee_messages(
         )?);
     }
     if !creator_fee.is_zero() {
-        messages.push(get_bank_transfer_to_msg(
+        for (recipient, share) in revenue_split::split_creator_revenue(
+            storage,
             &fee_info.creator_wallet_address,
-            denom,
             creator_fee,
-        )?);
+        )? {
+            messages.push(get_bank_transfer_to_msg(&recipient, denom, share)?);
+        }
     }
     Ok(messages)
 }
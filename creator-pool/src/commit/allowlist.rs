@@ -0,0 +1,154 @@
+//! Allowlisted early-access phase.
+//!
+//! A pool created (or updated through the factory's
+//! `SetPoolCommitAllowlist`) with a `CommitAllowlist` only accepts
+//! pre-threshold commits credited to allowlisted wallets until
+//! `public_at`. A wallet qualifies if it is in `ALLOWLISTED_WALLETS` or
+//! its commit carries a Merkle proof against `COMMIT_ALLOWLIST`'s root.
+//! Post-threshold commits are never gated: they are swaps, and plain
+//! swaps are open to everyone anyway.
+
+use cosmwasm_std::{
+    Addr, Api, DepsMut, Env, HexBinary, MessageInfo, Order, Response, Storage, Timestamp,
+};
+use pool_factory_interfaces::CommitAllowlist;
+use sha2::{Digest, Sha256};
+
+use crate::error::ContractError;
+use crate::state::{
+    CommitAllowlistPhase, ALLOWLISTED_WALLETS, COMMITFEEINFO, COMMIT_ALLOWLIST, POOL_INFO,
+    RAISE_DEADLINE, USD_RAISED_FROM_COMMIT,
+};
+
+/// Replaces any previous phase with `allowlist`. Shape and bounds were
+/// validated by the factory.
+pub(crate) fn save_commit_allowlist(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    allowlist: &CommitAllowlist,
+) -> Result<(), ContractError> {
+    let existing: Vec<Addr> = ALLOWLISTED_WALLETS
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<Result<_, _>>()?;
+    for wallet in existing {
+        ALLOWLISTED_WALLETS.remove(storage, &wallet);
+    }
+    for wallet in &allowlist.wallets {
+        ALLOWLISTED_WALLETS.save(storage, &api.addr_validate(wallet)?, &true)?;
+    }
+    let onchain_wallet_count = ALLOWLISTED_WALLETS
+        .keys(storage, None, None, Order::Ascending)
+        .count() as u32;
+    COMMIT_ALLOWLIST.save(
+        storage,
+        &CommitAllowlistPhase {
+            merkle_root: allowlist.merkle_root.clone(),
+            public_at: allowlist.public_at,
+            onchain_wallet_count,
+        },
+    )?;
+    Ok(())
+}
+
+/// Rejects a pre-threshold commit credited to `wallet` while the phase
+/// is running unless the wallet is listed on-chain or `proof` leads to
+/// the Merkle root.
+pub(crate) fn ensure_commit_allowed(
+    storage: &dyn Storage,
+    env: &Env,
+    wallet: &Addr,
+    proof: Option<&[HexBinary]>,
+) -> Result<(), ContractError> {
+    let Some(phase) = COMMIT_ALLOWLIST.may_load(storage)? else {
+        return Ok(());
+    };
+    if !phase.is_active(env.block.time) || ALLOWLISTED_WALLETS.has(storage, wallet) {
+        return Ok(());
+    }
+    let proven = match (&phase.merkle_root, proof) {
+        (Some(root), Some(proof)) => verify_merkle_proof(root, wallet, proof),
+        _ => false,
+    };
+    if proven {
+        Ok(())
+    } else {
+        Err(ContractError::NotAllowlisted {
+            public_at: phase.public_at,
+        })
+    }
+}
+
+/// Hashes `sha256(wallet)` up through `proof` with sorted-pair parents
+/// and compares against `root`.
+fn verify_merkle_proof(root: &HexBinary, wallet: &Addr, proof: &[HexBinary]) -> bool {
+    let mut node: [u8; 32] = Sha256::digest(wallet.as_bytes()).into();
+    for sibling in proof {
+        let Ok(sibling) = <[u8; 32]>::try_from(sibling.as_slice()) else {
+            return false;
+        };
+        let (left, right) = if node <= sibling {
+            (node, sibling)
+        } else {
+            (sibling, node)
+        };
+        node = Sha256::new()
+            .chain_update(left)
+            .chain_update(right)
+            .finalize()
+            .into();
+    }
+    root.as_slice() == node
+}
+
+/// Factory-forwarded `SetPoolCommitAllowlist`. Allowed before the first
+/// commit, or while the current phase is still running, so a creator
+/// can't close an already-public raise. Once commits have started the
+/// new `public_at` can't be later than the running phase's, so repeated
+/// updates can't keep the raise allowlist-only.
+pub fn execute_set_commit_allowlist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    creator: String,
+    allowlist: CommitAllowlist,
+) -> Result<Response, ContractError> {
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    if info.sender != pool_info.factory_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+    let fee_info = COMMITFEEINFO.load(deps.storage)?;
+    if creator != fee_info.creator_wallet_address.as_str() {
+        return Err(ContractError::Unauthorized {});
+    }
+    let locked = |reason: &str| ContractError::CommitAllowlistLocked {
+        reason: reason.to_string(),
+    };
+    let running_phase = COMMIT_ALLOWLIST
+        .may_load(deps.storage)?
+        .filter(|phase| phase.is_active(env.block.time));
+    if !USD_RAISED_FROM_COMMIT.load(deps.storage)?.is_zero() {
+        match running_phase {
+            None => return Err(locked("public commits have already started")),
+            Some(phase) if allowlist.public_at > phase.public_at => {
+                return Err(locked("public_at can't move later once commits have started"))
+            }
+            Some(_) => {}
+        }
+    }
+    if RAISE_DEADLINE
+        .may_load(deps.storage)?
+        .is_some_and(|deadline: Timestamp| allowlist.public_at >= deadline)
+    {
+        return Err(locked("public_at must be before the raise deadline"));
+    }
+    save_commit_allowlist(deps.storage, deps.api, &allowlist)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_commit_allowlist")
+        .add_attribute("creator", creator)
+        .add_attribute("public_at", allowlist.public_at.seconds().to_string())
+        .add_attribute("onchain_wallets", allowlist.wallets.len().to_string())
+        .add_attribute("has_merkle_root", allowlist.merkle_root.is_some().to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
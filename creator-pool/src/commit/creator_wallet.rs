@@ -0,0 +1,140 @@
+//! Creator wallet rotation.
+//!
+//! The creator proposes a new wallet with `ProposeCreatorWallet`; the
+//! new wallet takes over with `AcceptCreatorWallet` once the factory's
+//! `creator_wallet_veto_seconds` (snapshotted at instantiate) have
+//! passed. Until then the factory admin may drop the proposal with
+//! `VetoPoolCreatorWallet`, forwarded here as `VetoCreatorWallet`.
+//! Accepting moves every creator entitlement that names a wallet:
+//! `COMMITFEEINFO` (fee messages, `ClaimCreatorFees`, threshold reward,
+//! creator-only forwards), the `CREATOR_EXCESS_POSITION` claimant and
+//! the `CREATOR_VESTING` beneficiary. Revenue split recipients are left
+//! as they are. The factory is told with `NotifyCreatorWalletRotated` so
+//! its registry and creator profile follow the new wallet.
+
+use cosmwasm_std::{to_json_binary, DepsMut, Env, MessageInfo, Response, WasmMsg};
+
+use crate::error::ContractError;
+use crate::state::{
+    PendingCreatorWallet, COMMITFEEINFO, CREATOR_EXCESS_POSITION, CREATOR_VESTING,
+    CREATOR_WALLET_VETO_SECONDS, PENDING_CREATOR_WALLET, POOL_INFO,
+};
+
+/// Creator-only. Replaces any earlier proposal and restarts the veto
+/// window.
+pub fn execute_propose_creator_wallet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_wallet: String,
+) -> Result<Response, ContractError> {
+    let fee_info = COMMITFEEINFO.load(deps.storage)?;
+    if info.sender != fee_info.creator_wallet_address {
+        return Err(ContractError::Unauthorized {});
+    }
+    let new_wallet = deps.api.addr_validate(&new_wallet)?;
+    if new_wallet == fee_info.creator_wallet_address {
+        return Err(ContractError::InvalidCreatorWallet {
+            reason: "new wallet is already the creator wallet".to_string(),
+        });
+    }
+    let veto_seconds = CREATOR_WALLET_VETO_SECONDS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let pending = PendingCreatorWallet {
+        new_wallet,
+        proposed_at: env.block.time,
+        accept_after: env.block.time.plus_seconds(veto_seconds),
+    };
+    PENDING_CREATOR_WALLET.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_creator_wallet")
+        .add_attribute("creator", info.sender.to_string())
+        .add_attribute("new_wallet", pending.new_wallet.to_string())
+        .add_attribute("accept_after", pending.accept_after.seconds().to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
+/// Only the proposed wallet, and only after the veto window.
+pub fn execute_accept_creator_wallet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_CREATOR_WALLET
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingCreatorWallet {})?;
+    if info.sender != pending.new_wallet {
+        return Err(ContractError::Unauthorized {});
+    }
+    if env.block.time < pending.accept_after {
+        return Err(ContractError::CreatorWalletVetoWindow {
+            accept_after: pending.accept_after,
+        });
+    }
+    PENDING_CREATOR_WALLET.remove(deps.storage);
+
+    let mut fee_info = COMMITFEEINFO.load(deps.storage)?;
+    let previous = std::mem::replace(
+        &mut fee_info.creator_wallet_address,
+        pending.new_wallet.clone(),
+    );
+    COMMITFEEINFO.save(deps.storage, &fee_info)?;
+    if let Some(mut excess) = CREATOR_EXCESS_POSITION.may_load(deps.storage)? {
+        excess.creator = pending.new_wallet.clone();
+        CREATOR_EXCESS_POSITION.save(deps.storage, &excess)?;
+    }
+    if let Some(mut vesting) = CREATOR_VESTING.may_load(deps.storage)? {
+        vesting.creator = pending.new_wallet.clone();
+        CREATOR_VESTING.save(deps.storage, &vesting)?;
+    }
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let notify_factory = WasmMsg::Execute {
+        contract_addr: pool_info.factory_addr.to_string(),
+        msg: to_json_binary(
+            &pool_factory_interfaces::FactoryExecuteMsg::NotifyCreatorWalletRotated {
+                new_wallet: pending.new_wallet.to_string(),
+            },
+        )?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(notify_factory)
+        .add_attribute("action", "accept_creator_wallet")
+        .add_attribute("previous_wallet", previous.to_string())
+        .add_attribute("new_wallet", pending.new_wallet.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
+/// Factory-only (admin forward). Drops the pending proposal while its
+/// veto window is open.
+pub fn execute_veto_creator_wallet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    if info.sender != pool_info.factory_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+    let pending = PENDING_CREATOR_WALLET
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingCreatorWallet {})?;
+    if env.block.time >= pending.accept_after {
+        return Err(ContractError::InvalidCreatorWallet {
+            reason: "veto window has closed".to_string(),
+        });
+    }
+    PENDING_CREATOR_WALLET.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "veto_creator_wallet")
+        .add_attribute("vetoed_wallet", pending.new_wallet.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
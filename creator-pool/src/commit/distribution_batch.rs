@@ -32,6 +32,7 @@ use crate::state::{
     MAX_CONSECUTIVE_DISTRIBUTION_FAILURES, MAX_DISTRIBUTIONS_PER_TX, NEXT_DIST_MINT_REPLY_ID,
     PENDING_MINT_REPLIES, REPLY_ID_DISTRIBUTION_MINT_BASE,
 };
+use super::early_supporter::{distribution_weight, remove_commit_ledger_entry};
 
 /// Build a `SubMsg::reply_always` that mints `amount` of the pool's
 /// CW20 to `recipient`, wrapped so a per-mint failure is captured by
@@ -161,7 +162,7 @@ pub fn process_distribution_batch(
             let mut batch_distributed = Uint128::zero();
             for (payer, usd_paid) in batch.iter() {
                 let reward = calculate_committer_reward(
-                    *usd_paid,
+                    distribution_weight(storage, payer, *usd_paid)?,
                     dist_state.total_to_distribute,
                     dist_state.total_committed_usd,
                 )?;
@@ -176,7 +177,7 @@ pub fn process_distribution_batch(
                     )?);
                     batch_distributed = batch_distributed.checked_add(reward)?;
                 }
-                COMMIT_LEDGER.remove(storage, payer);
+                remove_commit_ledger_entry(storage, payer);
                 last_processed = Some(payer.clone());
                 processed_count += 1;
             }
@@ -328,7 +329,7 @@ pub fn calculate_effective_batch_size(dist_state: &DistributionState) -> u32 {
     base_batch_size.min(MAX_DISTRIBUTIONS_PER_TX).max(1)
 }
 
-fn calculate_committer_reward(
+pub(crate) fn calculate_committer_reward(
     usd_paid: Uint128,
     total_to_distribute: Uint128,
     total_committed_usd: Uint128,
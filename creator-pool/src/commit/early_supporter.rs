@@ -0,0 +1,87 @@
+//! Early-supporter weighting of the commit-return split.
+//!
+//! A pool created with an `EarlySupporterCurve` records, beside each
+//! `COMMIT_LEDGER` credit, the same USD scaled by the curve's multiplier
+//! at commit time (`WEIGHTED_COMMIT_LEDGER`). Both distribution paths
+//! then share `commit_return_amount` by weighted USD over
+//! `TOTAL_WEIGHTED_COMMIT_USD` instead of raw USD over the raised total.
+//! Every weight is in the denominator, so floor dust stays the only gap
+//! to the tranche and is settled to the creator as before. Unweighted
+//! pools never touch the weighted maps.
+
+use cosmwasm_std::{Addr, Decimal, Env, StdResult, Storage, Uint128};
+
+use crate::error::ContractError;
+use crate::state::{
+    COMMIT_LEDGER, EARLY_SUPPORTER_WEIGHTING, TOTAL_WEIGHTED_COMMIT_USD, WEIGHTED_COMMIT_LEDGER,
+};
+
+/// Multiplier a commit landing now would receive; one on unweighted pools.
+pub(crate) fn current_multiplier(storage: &dyn Storage, env: &Env) -> StdResult<Decimal> {
+    Ok(match EARLY_SUPPORTER_WEIGHTING.may_load(storage)? {
+        Some(weighting) => weighting
+            .curve
+            .multiplier_at(env.block.time.seconds().saturating_sub(weighting.starts_at.seconds())),
+        None => Decimal::one(),
+    })
+}
+
+/// Credits `usd` to `sender`'s ledger entry and, on weighted pools, the
+/// matching weighted entry and total. Replaces a bare
+/// `COMMIT_LEDGER.update` at every pre-threshold credit site.
+pub(crate) fn credit_commit_ledger(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    usd: Uint128,
+) -> Result<(), ContractError> {
+    COMMIT_LEDGER.update::<_, ContractError>(storage, sender, |v| {
+        Ok(v.unwrap_or_default().checked_add(usd)?)
+    })?;
+    if !EARLY_SUPPORTER_WEIGHTING.exists(storage) {
+        return Ok(());
+    }
+    let weighted = usd.mul_floor(current_multiplier(storage, env)?);
+    WEIGHTED_COMMIT_LEDGER.update::<_, ContractError>(storage, sender, |v| {
+        Ok(v.unwrap_or_default().checked_add(weighted)?)
+    })?;
+    let total = TOTAL_WEIGHTED_COMMIT_USD
+        .may_load(storage)?
+        .unwrap_or_default()
+        .checked_add(weighted)?;
+    TOTAL_WEIGHTED_COMMIT_USD.save(storage, &total)?;
+    Ok(())
+}
+
+/// `committer`'s share weight: the weighted entry when one exists,
+/// otherwise the raw `usd_paid`.
+pub(crate) fn distribution_weight(
+    storage: &dyn Storage,
+    committer: &Addr,
+    usd_paid: Uint128,
+) -> StdResult<Uint128> {
+    Ok(WEIGHTED_COMMIT_LEDGER
+        .may_load(storage, committer)?
+        .unwrap_or(usd_paid))
+}
+
+/// Share denominator frozen at threshold crossing: the weighted total on
+/// weighted pools, otherwise `usd_raised`.
+pub(crate) fn total_distribution_weight(
+    storage: &dyn Storage,
+    usd_raised: Uint128,
+) -> StdResult<Uint128> {
+    if EARLY_SUPPORTER_WEIGHTING.exists(storage) {
+        Ok(TOTAL_WEIGHTED_COMMIT_USD
+            .may_load(storage)?
+            .unwrap_or_default())
+    } else {
+        Ok(usd_raised)
+    }
+}
+
+/// Drops `committer`'s ledger entry together with its weighted twin.
+pub(crate) fn remove_commit_ledger_entry(storage: &mut dyn Storage, committer: &Addr) {
+    COMMIT_LEDGER.remove(storage, committer);
+    WEIGHTED_COMMIT_LEDGER.remove(storage, committer);
+}
@@ -0,0 +1,146 @@
+//! Creator-defined membership tiers.
+//!
+//! The creator registers named tiers through the factory's
+//! `SetPoolSubscriptionTiers`, which reaches the pool as
+//! `SetSubscriptionTiers` with the caller stamped in as `creator`. A
+//! commit (or subscription renewal) tagged with a tier buys one
+//! `duration_seconds` of membership for each whole `usd_price` it is
+//! worth. Renewing the same tier before expiry stacks on the current
+//! expiry; switching tiers starts fresh from the commit's block time.
+
+use std::collections::BTreeSet;
+
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Order, Response, Storage, Uint128};
+use pool_factory_interfaces::SubscriptionTier;
+
+use crate::error::ContractError;
+use crate::state::{
+    Membership, COMMITFEEINFO, MAX_MEMBERSHIP_SECONDS, MAX_SUBSCRIPTION_PERIOD_SECONDS,
+    MAX_SUBSCRIPTION_TIERS, MAX_SUBSCRIPTION_TIER_NAME_LEN, MEMBERSHIPS,
+    MIN_SUBSCRIPTION_PERIOD_SECONDS, POOL_INFO, SUBSCRIPTION_TIERS,
+};
+
+/// Factory-only; `creator` must be the pool's creator wallet. Replaces
+/// the whole tier set, so an empty list retires every tier.
+pub fn execute_set_subscription_tiers(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    creator: String,
+    tiers: Vec<SubscriptionTier>,
+) -> Result<Response, ContractError> {
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    if info.sender != pool_info.factory_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+    let fee_info = COMMITFEEINFO.load(deps.storage)?;
+    if creator != fee_info.creator_wallet_address.as_str() {
+        return Err(ContractError::Unauthorized {});
+    }
+    validate_tiers(&tiers)?;
+
+    let existing: Vec<String> = SUBSCRIPTION_TIERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<Result<_, _>>()?;
+    for name in existing {
+        SUBSCRIPTION_TIERS.remove(deps.storage, &name);
+    }
+    for tier in &tiers {
+        SUBSCRIPTION_TIERS.save(deps.storage, &tier.name, tier)?;
+    }
+
+    let names: Vec<&str> = tiers.iter().map(|t| t.name.as_str()).collect();
+    Ok(Response::new()
+        .add_attribute("action", "set_subscription_tiers")
+        .add_attribute("creator", creator)
+        .add_attribute("tiers", names.join(","))
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
+fn validate_tiers(tiers: &[SubscriptionTier]) -> Result<(), ContractError> {
+    let invalid = |reason: String| ContractError::InvalidSubscription { reason };
+    if tiers.len() > MAX_SUBSCRIPTION_TIERS {
+        return Err(invalid(format!(
+            "at most {} tiers may be registered",
+            MAX_SUBSCRIPTION_TIERS
+        )));
+    }
+    let mut seen = BTreeSet::new();
+    for tier in tiers {
+        if tier.name.is_empty() || tier.name.len() > MAX_SUBSCRIPTION_TIER_NAME_LEN {
+            return Err(invalid(format!(
+                "tier name must be 1 to {} bytes",
+                MAX_SUBSCRIPTION_TIER_NAME_LEN
+            )));
+        }
+        if !seen.insert(tier.name.as_str()) {
+            return Err(invalid(format!("duplicate tier \"{}\"", tier.name)));
+        }
+        if tier.usd_price.is_zero() {
+            return Err(invalid(format!("tier \"{}\" has a zero price", tier.name)));
+        }
+        if !(MIN_SUBSCRIPTION_PERIOD_SECONDS..=MAX_SUBSCRIPTION_PERIOD_SECONDS)
+            .contains(&tier.duration_seconds)
+        {
+            return Err(invalid(format!(
+                "tier duration must be between {} and {} seconds",
+                MIN_SUBSCRIPTION_PERIOD_SECONDS, MAX_SUBSCRIPTION_PERIOD_SECONDS
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn load_tier(
+    storage: &dyn Storage,
+    tier: &str,
+) -> Result<SubscriptionTier, ContractError> {
+    SUBSCRIPTION_TIERS
+        .may_load(storage, tier)?
+        .ok_or_else(|| ContractError::UnknownSubscriptionTier {
+            tier: tier.to_string(),
+        })
+}
+
+/// Extends `wallet`'s membership for a commit worth `usd_value` tagged
+/// with `tier`. Errors if the tier is unknown or the commit doesn't
+/// cover one period.
+pub(crate) fn record_membership(
+    storage: &mut dyn Storage,
+    env: &Env,
+    wallet: &Addr,
+    tier: &str,
+    usd_value: Uint128,
+) -> Result<Membership, ContractError> {
+    let config = load_tier(storage, tier)?;
+    let periods = usd_value
+        .checked_div(config.usd_price)
+        .map_err(|_| ContractError::DivideByZero)?;
+    if periods.is_zero() {
+        return Err(ContractError::TierUnderpaid {
+            tier: config.name,
+            got: usd_value,
+            price: config.usd_price,
+        });
+    }
+    let start = match MEMBERSHIPS.may_load(storage, wallet)? {
+        Some(current) if current.tier == config.name && current.expires_at > env.block.time => {
+            current.expires_at
+        }
+        _ => env.block.time,
+    };
+    // Clamp to MAX_MEMBERSHIP_SECONDS past now so an oversized commit
+    // (or endless stacking) can't overflow the timestamp.
+    let extension = periods
+        .checked_mul(Uint128::from(config.duration_seconds))?
+        .min(Uint128::from(MAX_MEMBERSHIP_SECONDS))
+        .u128() as u64;
+    let cap = env.block.time.plus_seconds(MAX_MEMBERSHIP_SECONDS);
+    let membership = Membership {
+        tier: config.name,
+        expires_at: start.plus_seconds(extension).min(cap),
+    };
+    MEMBERSHIPS.save(storage, wallet, &membership)?;
+    Ok(membership)
+}
@@ -21,7 +21,12 @@ use crate::state::{
     PoolAnalytics, PoolFeeState, PoolInfo, PoolSpecs, PoolState, MINIMUM_LIQUIDITY,
     POOL_FEE_STATE, POOL_PAUSED, POOL_STATE, POST_THRESHOLD_COOLDOWN_UNTIL_BLOCK,
 };
-use crate::swap_helper::{assert_max_spread, compute_swap, update_price_accumulator};
+use crate::swap_helper::{
+    accrue_protocol_fee, assert_max_spread, candle_price, compute_swap, credit_launch_fee,
+    effective_commission_rate, enforce_launch_window, record_candles, split_launch_commission,
+    update_price_accumulator,
+};
+use pool_core::history::{record_trade, TradeRecord};
 
 use super::commit_base_attributes;
 
@@ -77,8 +82,12 @@ pub(super) fn process_post_threshold_commit(
         return Err(ContractError::InsufficientReserves {});
     }
 
+    let commission_rate =
+        effective_commission_rate(deps.storage, pool_specs.lp_fee, env.block.height)?;
     let (return_amt, spread_amt, commission_amt) =
-        compute_swap(offer_pool, ask_pool, swap_amount, pool_specs.lp_fee)?;
+        compute_swap(offer_pool, ask_pool, swap_amount, commission_rate)?;
+    let (lp_commission, launch_fee_amt) =
+        split_launch_commission(commission_amt, pool_specs.lp_fee, commission_rate);
 
     // Dust-swap guard: mirror simple_swap's zero-return rejection so a
     // post-threshold commit that would consume the user's bluechip
@@ -103,6 +112,16 @@ pub(super) fn process_post_threshold_commit(
         spread_amt,
     )?;
 
+    enforce_launch_window(
+        deps.storage,
+        env.block.height,
+        &sender,
+        0,
+        swap_amount,
+        offer_pool,
+        return_amt,
+    )?;
+
     update_price_accumulator(pool_state, env.block.time.seconds())?;
 
     let new_reserve0 = offer_pool.checked_add(swap_amount)?;
@@ -123,7 +142,10 @@ pub(super) fn process_post_threshold_commit(
     pool_state.reserve0 = new_reserve0;
     pool_state.reserve1 = new_reserve1;
 
-    update_pool_fee_growth(pool_fee_state, pool_state, 0, commission_amt)?;
+    let protocol_fee_amt = accrue_protocol_fee(deps.storage, 0, lp_commission)?;
+    let lp_commission = lp_commission.checked_sub(protocol_fee_amt)?;
+    update_pool_fee_growth(pool_fee_state, pool_state, 0, lp_commission)?;
+    credit_launch_fee(deps.storage, 0, launch_fee_amt)?;
     POOL_FEE_STATE.save(deps.storage, &*pool_fee_state)?;
     POOL_STATE.save(deps.storage, &*pool_state)?;
 
@@ -158,6 +180,19 @@ pub(super) fn process_post_threshold_commit(
     analytics.total_volume_1 = analytics.total_volume_1.saturating_add(return_amt);
     analytics.last_trade_block = env.block.height;
     analytics.last_trade_timestamp = env.block.time.seconds();
+    record_trade(
+        deps.storage,
+        env.block.time.seconds(),
+        &TradeRecord::from_swap(0, swap_amount, return_amt, lp_commission),
+    )?;
+    record_candles(
+        deps.storage,
+        env.block.time.seconds(),
+        candle_price(offer_pool, ask_pool),
+        candle_price(pool_state.reserve0, pool_state.reserve1),
+        swap_amount,
+        return_amt,
+    )?;
 
     // Effective price: creator tokens received per bluechip spent
     let effective_price = if !swap_amount.is_zero() {
@@ -182,6 +217,8 @@ pub(super) fn process_post_threshold_commit(
         .add_attribute("tokens_received", return_amt.to_string())
         .add_attribute("spread_amount", spread_amt.to_string())
         .add_attribute("commission_amount", commission_amt.to_string())
+        .add_attribute("launch_fee_amount", launch_fee_amt.to_string())
+        .add_attribute("protocol_fee_amount", protocol_fee_amt.to_string())
         .add_attribute("effective_price", effective_price)
         .add_attribute("reserve0_after", pool_state.reserve0.to_string())
         .add_attribute("reserve1_after", pool_state.reserve1.to_string()))
@@ -13,8 +13,10 @@ use crate::asset::TokenInfo;
 use crate::error::ContractError;
 use crate::generic_helpers::update_commit_info;
 use crate::state::{
-    PoolAnalytics, PoolState, COMMIT_LEDGER, NATIVE_RAISED_FROM_COMMIT, USD_RAISED_FROM_COMMIT,
+    PoolAnalytics, PoolState, GROSS_RAISED_FROM_COMMIT, NATIVE_RAISED_FROM_COMMIT,
+    USD_RAISED_FROM_COMMIT,
 };
+use super::early_supporter::credit_commit_ledger;
 
 use super::commit_base_attributes;
 
@@ -30,9 +32,7 @@ pub(super) fn process_pre_threshold_commit(
     pool_state: &PoolState,
     analytics: &mut PoolAnalytics,
 ) -> Result<Response, ContractError> {
-    COMMIT_LEDGER.update::<_, ContractError>(deps.storage, &sender, |v| {
-        Ok(v.unwrap_or_default().checked_add(usd_value)?)
-    })?;
+    credit_commit_ledger(deps.storage, &env, &sender, usd_value)?;
     // Capture the update return values so we don't re-read USD_RAISED /
     // NATIVE_RAISED after the writes. `Item::update` returns the new value.
     //
@@ -49,6 +49,14 @@ pub(super) fn process_pre_threshold_commit(
         .update::<_, ContractError>(deps.storage, |r| Ok(r.checked_add(usd_value)?))?;
     let total_bluechip_raised = NATIVE_RAISED_FROM_COMMIT
         .update::<_, ContractError>(deps.storage, |r| Ok(r.checked_add(net_bluechip)?))?;
+    // Gross counterpart, the denominator for failed-raise refunds.
+    // `may_load` because pools instantiated before refunds existed
+    // never seeded it.
+    let total_gross_raised = GROSS_RAISED_FROM_COMMIT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(asset.amount)?;
+    GROSS_RAISED_FROM_COMMIT.save(deps.storage, &total_gross_raised)?;
 
     update_commit_info(
         deps.storage,
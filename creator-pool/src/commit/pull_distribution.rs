@@ -0,0 +1,93 @@
+//! Pull-mode committer distribution.
+//!
+//! A pool created with `DistributionMode::Pull` never starts the keeper
+//! batch loop. Instead `trigger_threshold_payout` freezes the raise
+//! totals into `PULL_DISTRIBUTION`, and each committer with a
+//! `COMMIT_LEDGER` entry mints their own share with `ClaimCommitReward`
+//! — the same `calculate_committer_reward` the batch loop uses. Claims
+//! never expire. The claim that empties the ledger settles the
+//! floor-division dust to the creator, matching the push loop's final
+//! batch.
+
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage, Uint128};
+
+use crate::admin::ensure_not_drained;
+use crate::error::ContractError;
+use crate::state::{
+    PullDistribution, COMMITFEEINFO, COMMIT_LEDGER, POOL_INFO, POOL_PAUSED, PULL_DISTRIBUTION,
+};
+
+use super::distribution_batch::calculate_committer_reward;
+use super::early_supporter::{distribution_weight, remove_commit_ledger_entry};
+use super::threshold_payout::mint_tokens;
+
+/// `committer`'s unclaimed share, or zero if they have no ledger entry.
+pub(crate) fn commit_reward_owed(
+    storage: &dyn Storage,
+    state: &PullDistribution,
+    committer: &Addr,
+) -> StdResult<Uint128> {
+    match COMMIT_LEDGER.may_load(storage, committer)? {
+        Some(usd_paid) => calculate_committer_reward(
+            distribution_weight(storage, committer, usd_paid)?,
+            state.total_to_distribute,
+            state.total_committed_usd,
+        ),
+        None => Ok(Uint128::zero()),
+    }
+}
+
+pub fn execute_claim_commit_reward(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // Same halt semantics as `ContinueDistribution`: drained pools stop
+    // minting for good, paused pools until the pause clears.
+    ensure_not_drained(deps.storage)?;
+    if POOL_PAUSED.may_load(deps.storage)?.unwrap_or(false) {
+        return Err(ContractError::PoolPausedLowLiquidity {});
+    }
+    let mut state = PULL_DISTRIBUTION
+        .may_load(deps.storage)?
+        .ok_or(ContractError::PullDistributionInactive {})?;
+    if !COMMIT_LEDGER.has(deps.storage, &info.sender) {
+        return Err(ContractError::NoCommitReward {});
+    }
+    let reward = commit_reward_owed(deps.storage, &state, &info.sender)?;
+    remove_commit_ledger_entry(deps.storage, &info.sender);
+    state.claimed = state.claimed.checked_add(reward)?;
+    state.claim_count += 1;
+    PULL_DISTRIBUTION.save(deps.storage, &state)?;
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let mut response = Response::new();
+    if !reward.is_zero() {
+        response = response.add_message(mint_tokens(
+            &pool_info.token_address,
+            &info.sender,
+            reward,
+        )?);
+    }
+
+    let ledger_empty = COMMIT_LEDGER
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_none();
+    if ledger_empty && state.claimed < state.total_to_distribute {
+        let residual = state.total_to_distribute.checked_sub(state.claimed)?;
+        let creator = COMMITFEEINFO.load(deps.storage)?.creator_wallet_address;
+        response = response
+            .add_message(mint_tokens(&pool_info.token_address, &creator, residual)?)
+            .add_attribute("dust_to_creator", residual.to_string());
+    }
+
+    Ok(response
+        .add_attribute("action", "claim_commit_reward")
+        .add_attribute("committer", info.sender.to_string())
+        .add_attribute("reward", reward.to_string())
+        .add_attribute("claimed_total", state.claimed.to_string())
+        .add_attribute("distribution_complete", ledger_empty.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
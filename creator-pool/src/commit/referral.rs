@@ -0,0 +1,65 @@
+//! Referral rewards.
+//!
+//! A commit may name a `referrer`. When the factory has set a non-zero
+//! `REFERRAL_FEE_SHARE` for the pool, that share of the commit's creator
+//! fee is withheld from the creator transfer and credited to the
+//! referrer's `REFERRAL_STATS` balance instead (see
+//! `commit::calculate_commit_fees`). Referrers pull their balance with
+//! `ClaimReferralRewards`.
+
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Storage, Uint128};
+
+use crate::asset::get_native_denom;
+use crate::error::ContractError;
+use crate::generic_helpers::get_bank_transfer_to_msg;
+use crate::state::{ReferralStats, POOL_INFO, REFERRAL_STATS};
+
+/// Credits one referred commit worth `usd_value` and its `referral_fee`
+/// to `referrer`.
+pub(crate) fn record_referral(
+    storage: &mut dyn Storage,
+    referrer: &Addr,
+    usd_value: Uint128,
+    referral_fee: Uint128,
+) -> Result<ReferralStats, ContractError> {
+    let mut stats = REFERRAL_STATS
+        .may_load(storage, referrer)?
+        .unwrap_or_default();
+    stats.referred_commits += 1;
+    stats.referred_usd = stats.referred_usd.checked_add(usd_value)?;
+    stats.total_earned = stats.total_earned.checked_add(referral_fee)?;
+    stats.unclaimed = stats.unclaimed.checked_add(referral_fee)?;
+    REFERRAL_STATS.save(storage, referrer, &stats)?;
+    Ok(stats)
+}
+
+/// Not gated on pause or drain: referral balances never join the
+/// reserves.
+pub fn execute_claim_referral_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut stats = REFERRAL_STATS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    let amount = stats.unclaimed;
+    if amount.is_zero() {
+        return Err(ContractError::NoReferralRewards {});
+    }
+    stats.unclaimed = Uint128::zero();
+    REFERRAL_STATS.save(deps.storage, &info.sender, &stats)?;
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
+    let msg = get_bank_transfer_to_msg(&info.sender, &bluechip_denom, amount)?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "claim_referral_rewards")
+        .add_attribute("referrer", info.sender.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("total_earned", stats.total_earned.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
@@ -0,0 +1,128 @@
+//! Failed-raise refunds.
+//!
+//! A pool created with a `raise_deadline` that passes before the
+//! threshold is crossed stops taking commits (`ensure_raise_open`) and
+//! enters refund state the first time `ClaimCommitRefund` runs: the
+//! net-of-fee bluechip in `NATIVE_RAISED_FROM_COMMIT` and the gross
+//! total in `GROSS_RAISED_FROM_COMMIT` are snapshotted into
+//! `COMMIT_REFUND_STATE`, and each committer with a `COMMIT_LEDGER`
+//! entry can then reclaim their pro-rata share (weighted by
+//! `Committing.total_paid_bluechip`). Commit fees already paid out to
+//! the bluechip and creator wallets are not refunded.
+
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128};
+
+use crate::admin::ensure_not_drained;
+use crate::asset::get_native_denom;
+use crate::error::ContractError;
+use crate::generic_helpers::get_bank_transfer_to_msg;
+use crate::state::{
+    CommitRefundState, COMMIT_INFO, COMMIT_LEDGER, COMMIT_REFUND_STATE,
+    GROSS_RAISED_FROM_COMMIT, IS_THRESHOLD_HIT, NATIVE_RAISED_FROM_COMMIT, POOL_INFO,
+    RAISE_DEADLINE, THRESHOLD_PROCESSING,
+};
+use super::early_supporter::remove_commit_ledger_entry;
+
+/// Rejects commits on a pool whose raise deadline has passed without
+/// crossing the threshold. Post-threshold commits are unaffected.
+pub(crate) fn ensure_raise_open(storage: &dyn Storage, env: &Env) -> Result<(), ContractError> {
+    if IS_THRESHOLD_HIT.load(storage)? {
+        return Ok(());
+    }
+    match RAISE_DEADLINE.may_load(storage)? {
+        Some(deadline) if env.block.time >= deadline => {
+            Err(ContractError::RaiseExpired { deadline })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The refund snapshot once refunds are open: the stored one, or the
+/// one the next claim would take. `None` while the raise is live, has no
+/// deadline, crossed its threshold, or is mid-crossing.
+pub(crate) fn pending_refund_state(
+    storage: &dyn Storage,
+    env: &Env,
+) -> StdResult<Option<CommitRefundState>> {
+    if let Some(state) = COMMIT_REFUND_STATE.may_load(storage)? {
+        return Ok(Some(state));
+    }
+    if IS_THRESHOLD_HIT.load(storage)?
+        || THRESHOLD_PROCESSING.may_load(storage)?.unwrap_or(false)
+    {
+        return Ok(None);
+    }
+    match RAISE_DEADLINE.may_load(storage)? {
+        Some(deadline) if env.block.time >= deadline => Ok(Some(CommitRefundState {
+            opened_at: env.block.time,
+            refundable_bluechip: NATIVE_RAISED_FROM_COMMIT.load(storage)?,
+            total_gross_bluechip: GROSS_RAISED_FROM_COMMIT
+                .may_load(storage)?
+                .unwrap_or_default(),
+            refunded_bluechip: Uint128::zero(),
+            claim_count: 0,
+        })),
+        _ => Ok(None),
+    }
+}
+
+/// `committer`'s share of the refund pool, or zero if they never
+/// committed or already claimed. Capped at what's left so rounding can
+/// never pay out more than the snapshot.
+pub(crate) fn refund_owed(
+    storage: &dyn Storage,
+    state: &CommitRefundState,
+    committer: &Addr,
+) -> StdResult<Uint128> {
+    if COMMIT_LEDGER.may_load(storage, committer)?.is_none()
+        || state.total_gross_bluechip.is_zero()
+    {
+        return Ok(Uint128::zero());
+    }
+    let paid = COMMIT_INFO
+        .may_load(storage, committer)?
+        .map(|c| c.total_paid_bluechip)
+        .unwrap_or_default();
+    let share = state
+        .refundable_bluechip
+        .multiply_ratio(paid, state.total_gross_bluechip);
+    let remaining = state
+        .refundable_bluechip
+        .checked_sub(state.refunded_bluechip)?;
+    Ok(share.min(remaining))
+}
+
+pub fn execute_claim_commit_refund(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_not_drained(deps.storage)?;
+    // First claim after the deadline persists the snapshot; later
+    // claims reuse it so every committer is paid off the same totals.
+    let mut state = pending_refund_state(deps.storage, &env)?
+        .ok_or(ContractError::RefundsNotOpen {})?;
+    let amount = refund_owed(deps.storage, &state, &info.sender)?;
+    if amount.is_zero() {
+        return Err(ContractError::NoCommitRefund {});
+    }
+
+    remove_commit_ledger_entry(deps.storage, &info.sender);
+    state.refunded_bluechip = state.refunded_bluechip.checked_add(amount)?;
+    state.claim_count += 1;
+    COMMIT_REFUND_STATE.save(deps.storage, &state)?;
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
+    let msg = get_bank_transfer_to_msg(&info.sender, &bluechip_denom, amount)?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "claim_commit_refund")
+        .add_attribute("committer", info.sender.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("refunded_total", state.refunded_bluechip.to_string())
+        .add_attribute("refundable_total", state.refundable_bluechip.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
@@ -0,0 +1,177 @@
+//! Creator revenue split.
+//!
+//! By default every creator payout goes to `creator_wallet_address`. A
+//! creator can route it to up to `MAX_REVENUE_RECIPIENTS` collaborators
+//! instead, weighted in basis points: `ProposeRevenueSplit` stages the
+//! list, and `ApplyRevenueSplit` installs it once
+//! `REVENUE_SPLIT_TIMELOCK_SECONDS` have passed. The split applies to
+//! the creator commit fee (`build_fee_messages`), `ClaimCreatorFees`
+//! pot payouts and the threshold creator reward, whether minted at the
+//! crossing or released by `ClaimVestedCreatorReward`. Floor dust goes
+//! to the first recipient so every payout is paid out in full.
+
+use std::collections::BTreeSet;
+
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128};
+
+use crate::error::ContractError;
+use crate::state::{
+    PendingRevenueSplit, RevenueRecipient, COMMITFEEINFO, MAX_REVENUE_RECIPIENTS,
+    PENDING_REVENUE_SPLIT, REVENUE_SPLIT, REVENUE_SPLIT_TIMELOCK_SECONDS,
+};
+
+const TOTAL_BPS: u16 = 10_000;
+
+/// Splits a creator payout of `amount` across the active split, or
+/// hands it all to `creator` when there is none. Zero shares are
+/// dropped.
+pub(crate) fn split_creator_revenue(
+    storage: &dyn Storage,
+    creator: &Addr,
+    amount: Uint128,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let recipients = REVENUE_SPLIT.may_load(storage)?.unwrap_or_default();
+    if recipients.is_empty() {
+        return Ok(vec![(creator.clone(), amount)]);
+    }
+    let mut shares: Vec<(Addr, Uint128)> = recipients
+        .iter()
+        .map(|r| {
+            (
+                r.address.clone(),
+                amount.multiply_ratio(r.bps, TOTAL_BPS),
+            )
+        })
+        .collect();
+    let paid = shares
+        .iter()
+        .try_fold(Uint128::zero(), |acc, (_, share)| acc.checked_add(*share))?;
+    shares[0].1 = shares[0].1.checked_add(amount.checked_sub(paid)?)?;
+    shares.retain(|(_, share)| !share.is_zero());
+    Ok(shares)
+}
+
+/// Whether `sender` may trigger a creator payout: the creator or any
+/// current split recipient.
+pub(crate) fn is_revenue_claimer(
+    storage: &dyn Storage,
+    creator: &Addr,
+    sender: &Addr,
+) -> StdResult<bool> {
+    Ok(sender == creator
+        || REVENUE_SPLIT
+            .may_load(storage)?
+            .unwrap_or_default()
+            .iter()
+            .any(|r| r.address == *sender))
+}
+
+fn ensure_creator(storage: &dyn Storage, sender: &Addr) -> Result<(), ContractError> {
+    if *sender != COMMITFEEINFO.load(storage)?.creator_wallet_address {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn execute_propose_revenue_split(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipients: Vec<RevenueRecipient>,
+) -> Result<Response, ContractError> {
+    ensure_creator(deps.storage, &info.sender)?;
+    let invalid = |reason: String| ContractError::InvalidRevenueSplit { reason };
+    if recipients.len() > MAX_REVENUE_RECIPIENTS {
+        return Err(invalid(format!(
+            "at most {} recipients",
+            MAX_REVENUE_RECIPIENTS
+        )));
+    }
+    let mut seen = BTreeSet::new();
+    let mut total_bps: u32 = 0;
+    let mut validated = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        let address = deps.api.addr_validate(recipient.address.as_str())?;
+        if recipient.bps == 0 {
+            return Err(invalid(format!("{} has a zero share", address)));
+        }
+        if !seen.insert(address.clone()) {
+            return Err(invalid(format!("{} is listed twice", address)));
+        }
+        total_bps += u32::from(recipient.bps);
+        validated.push(RevenueRecipient {
+            address,
+            bps: recipient.bps,
+        });
+    }
+    if !validated.is_empty() && total_bps != u32::from(TOTAL_BPS) {
+        return Err(invalid(format!(
+            "shares sum to {} bps, not {}",
+            total_bps, TOTAL_BPS
+        )));
+    }
+
+    let effective_after = env.block.time.plus_seconds(REVENUE_SPLIT_TIMELOCK_SECONDS);
+    PENDING_REVENUE_SPLIT.save(
+        deps.storage,
+        &PendingRevenueSplit {
+            recipients: validated.clone(),
+            effective_after,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_revenue_split")
+        .add_attribute("creator", info.sender.to_string())
+        .add_attribute("recipients", validated.len().to_string())
+        .add_attribute("effective_after", effective_after.seconds().to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
+pub fn execute_apply_revenue_split(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_creator(deps.storage, &info.sender)?;
+    let pending = PENDING_REVENUE_SPLIT
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingRevenueSplit {})?;
+    if env.block.time < pending.effective_after {
+        return Err(ContractError::RevenueSplitTimelocked {
+            effective_after: pending.effective_after,
+        });
+    }
+    PENDING_REVENUE_SPLIT.remove(deps.storage);
+    if pending.recipients.is_empty() {
+        REVENUE_SPLIT.remove(deps.storage);
+    } else {
+        REVENUE_SPLIT.save(deps.storage, &pending.recipients)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "apply_revenue_split")
+        .add_attribute("creator", info.sender.to_string())
+        .add_attribute("recipients", pending.recipients.len().to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
+pub fn execute_cancel_revenue_split(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_creator(deps.storage, &info.sender)?;
+    if !PENDING_REVENUE_SPLIT.exists(deps.storage) {
+        return Err(ContractError::NoPendingRevenueSplit {});
+    }
+    PENDING_REVENUE_SPLIT.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_revenue_split")
+        .add_attribute("creator", info.sender.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
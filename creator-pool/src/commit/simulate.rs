@@ -0,0 +1,239 @@
+//! Read-only commit preview for `QueryMsg::SimulateCommit`.
+//!
+//! Runs the same steps as `execute_commit_logic` against current state
+//! without writing anything: the oracle conversion, the minimum-commit
+//! and wallet-cap checks, the `calculate_commit_fees` split and the
+//! pre-threshold / crossing / post-threshold branch. The projection then
+//! depends on the branch:
+//! - pre-threshold: the commit's share of `commit_return_amount`,
+//!   assuming the rest of the raise lands at today's multiplier;
+//! - crossing: the exact share of the threshold portion, plus the 3%
+//!   capped excess swap against the reserves the crossing would seed;
+//! - post-threshold: the AMM swap at the live commission rate.
+//!
+//! Referral carve-outs, sponsor matches and slippage limits are not
+//! modelled. Rejections a real commit would hit surface as query errors.
+
+use cosmwasm_std::{Addr, Deps, Env, Uint128};
+
+use crate::admin::ensure_not_drained;
+use crate::asset::TokenInfo;
+use crate::error::ContractError;
+use crate::msg::{CommitPhase, SimulateCommitResponse};
+use crate::state::{
+    COMMITFEEINFO, COMMIT_LIMIT_INFO, EARLY_SUPPORTER_WEIGHTING, IS_THRESHOLD_HIT,
+    NATIVE_RAISED_FROM_COMMIT, POOL_INFO, POOL_PAUSED, POOL_SPECS, POOL_STATE,
+    THRESHOLD_PAYOUT_AMOUNTS, TOTAL_WEIGHTED_COMMIT_USD, USD_RAISED_FROM_COMMIT,
+};
+use crate::swap_helper::{
+    compute_swap, effective_commission_rate, get_oracle_conversion_with_staleness,
+    usd_to_bluechip_at_rate,
+};
+
+use super::early_supporter::current_multiplier;
+use super::{calculate_commit_fees, refund, wallet_cap};
+
+/// Previews a commit of `asset` credited to `wallet`. Without a wallet
+/// the commit is treated as coming from one with no ledger entry yet.
+pub(crate) fn simulate_commit(
+    deps: Deps,
+    env: &Env,
+    asset: TokenInfo,
+    wallet: Option<Addr>,
+) -> Result<SimulateCommitResponse, ContractError> {
+    ensure_not_drained(deps.storage)?;
+    if POOL_PAUSED.may_load(deps.storage)?.unwrap_or(false) {
+        return Err(ContractError::PoolPausedLowLiquidity {});
+    }
+    refund::ensure_raise_open(deps.storage, env)?;
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    if !asset.info.equal(&pool_info.pool_info.asset_infos[0]) {
+        return Err(ContractError::AssetMismatch {});
+    }
+    if asset.amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    let oracle_snapshot =
+        get_oracle_conversion_with_staleness(deps, asset.amount, env.block.time.seconds())?;
+    let oracle_rate = oracle_snapshot.rate_used;
+    if oracle_rate.is_zero() || oracle_snapshot.amount.is_zero() {
+        return Err(ContractError::InvalidOraclePrice {});
+    }
+
+    let commit_config = COMMIT_LIMIT_INFO.load(deps.storage)?;
+    let threshold_already_hit = IS_THRESHOLD_HIT.load(deps.storage)?;
+    let min_commit = if threshold_already_hit {
+        commit_config.min_commit_usd_post_threshold
+    } else {
+        commit_config.min_commit_usd_pre_threshold
+    };
+    if oracle_snapshot.amount < min_commit {
+        return Err(ContractError::CommitTooSmall {
+            got: oracle_snapshot.amount,
+            min: min_commit,
+            phase: if threshold_already_hit {
+                "post-threshold"
+            } else {
+                "pre-threshold"
+            },
+        });
+    }
+
+    // The pool's own address never commits, so it stands in for a
+    // fresh wallet when none was given.
+    let committer = wallet.unwrap_or_else(|| env.contract.address.clone());
+    let (amount, usd_value, wallet_cap_refund) = if threshold_already_hit {
+        (asset.amount, oracle_snapshot.amount, Uint128::zero())
+    } else {
+        let capped = wallet_cap::clamp_to_wallet_cap(
+            deps.storage,
+            &commit_config,
+            &committer,
+            asset,
+            oracle_snapshot.amount,
+            min_commit,
+        )?;
+        (capped.asset.amount, capped.usd_value, capped.refund)
+    };
+
+    let fee_info = COMMITFEEINFO.load(deps.storage)?;
+    let (bluechip_fee, creator_fee, _) =
+        calculate_commit_fees(amount, &fee_info, Default::default())?;
+    let amount_after_fees = amount
+        .checked_sub(bluechip_fee.checked_add(creator_fee)?)
+        .map_err(|_| ContractError::InvalidFee {})?;
+    if amount_after_fees.is_zero() {
+        return Err(ContractError::InvalidFee {});
+    }
+
+    let mut response = SimulateCommitResponse {
+        phase: CommitPhase::PostThreshold,
+        usd_value,
+        oracle_rate,
+        accepted_amount: amount,
+        wallet_cap_refund,
+        bluechip_fee,
+        creator_fee,
+        amount_after_fees,
+        ledger_usd: Uint128::zero(),
+        projected_commit_reward: Uint128::zero(),
+        swap_offer_amount: Uint128::zero(),
+        swap_return_amount: Uint128::zero(),
+        swap_spread_amount: Uint128::zero(),
+        swap_commission_amount: Uint128::zero(),
+        excess_refund: Uint128::zero(),
+    };
+
+    let pool_specs = POOL_SPECS.load(deps.storage)?;
+    if threshold_already_hit {
+        let pool_state = POOL_STATE.load(deps.storage)?;
+        let commission_rate =
+            effective_commission_rate(deps.storage, pool_specs.lp_fee, env.block.height)?;
+        let (return_amount, spread_amount, commission_amount) = compute_swap(
+            pool_state.reserve0,
+            pool_state.reserve1,
+            amount_after_fees,
+            commission_rate,
+        )?;
+        response.swap_offer_amount = amount_after_fees;
+        response.swap_return_amount = return_amount;
+        response.swap_spread_amount = spread_amount;
+        response.swap_commission_amount = commission_amount;
+        return Ok(response);
+    }
+
+    let usd_raised = USD_RAISED_FROM_COMMIT.load(deps.storage)?;
+    let usd_to_threshold = commit_config
+        .commit_amount_for_threshold_usd
+        .saturating_sub(usd_raised);
+    let payout = THRESHOLD_PAYOUT_AMOUNTS.load(deps.storage)?;
+    if usd_value < usd_to_threshold {
+        response.phase = CommitPhase::PreThreshold;
+        response.ledger_usd = usd_value;
+        response.projected_commit_reward = projected_commit_reward(
+            deps,
+            env,
+            payout.commit_return_amount,
+            usd_value,
+            usd_to_threshold.checked_sub(usd_value)?,
+        )?;
+        return Ok(response);
+    }
+
+    response.phase = CommitPhase::ThresholdCrossing;
+    response.ledger_usd = usd_to_threshold;
+    response.projected_commit_reward = projected_commit_reward(
+        deps,
+        env,
+        payout.commit_return_amount,
+        usd_to_threshold,
+        Uint128::zero(),
+    )?;
+    if usd_value == usd_to_threshold || usd_to_threshold.is_zero() {
+        return Ok(response);
+    }
+
+    // Mirrors `process_threshold_crossing_with_excess` and the reserve
+    // seeding in `trigger_threshold_payout`.
+    let bluechip_to_threshold = usd_to_bluechip_at_rate(usd_to_threshold, oracle_rate)?;
+    let threshold_portion_after_fees = amount_after_fees.multiply_ratio(bluechip_to_threshold, amount);
+    let excess_after_fees = amount_after_fees.checked_sub(threshold_portion_after_fees)?;
+    let seed_bluechip = NATIVE_RAISED_FROM_COMMIT
+        .load(deps.storage)?
+        .checked_add(threshold_portion_after_fees)?;
+    let (reserve0, reserve1) = if seed_bluechip > commit_config.max_bluechip_lock_per_pool {
+        let excess_bluechip = seed_bluechip - commit_config.max_bluechip_lock_per_pool;
+        (
+            commit_config.max_bluechip_lock_per_pool,
+            payout.pool_seed_amount.checked_sub(
+                payout
+                    .pool_seed_amount
+                    .multiply_ratio(excess_bluechip, seed_bluechip),
+            )?,
+        )
+    } else {
+        (seed_bluechip, payout.pool_seed_amount)
+    };
+    let capped_excess = excess_after_fees.min(reserve0.multiply_ratio(3u128, 100u128));
+    response.excess_refund = excess_after_fees.checked_sub(capped_excess)?;
+    if !capped_excess.is_zero() && !reserve0.is_zero() && !reserve1.is_zero() {
+        let (return_amount, spread_amount, commission_amount) =
+            compute_swap(reserve0, reserve1, capped_excess, pool_specs.lp_fee)?;
+        response.swap_offer_amount = capped_excess;
+        response.swap_return_amount = return_amount;
+        response.swap_spread_amount = spread_amount;
+        response.swap_commission_amount = commission_amount;
+    }
+    Ok(response)
+}
+
+/// The slice of `commit_return` that `ledger_usd` committed now would
+/// earn if the remaining `usd_after` of the raise also lands at the
+/// current multiplier. Exact once `usd_after` is zero.
+fn projected_commit_reward(
+    deps: Deps,
+    env: &Env,
+    commit_return: Uint128,
+    ledger_usd: Uint128,
+    usd_after: Uint128,
+) -> Result<Uint128, ContractError> {
+    if !EARLY_SUPPORTER_WEIGHTING.exists(deps.storage) {
+        let total = USD_RAISED_FROM_COMMIT
+            .load(deps.storage)?
+            .checked_add(ledger_usd)?
+            .checked_add(usd_after)?;
+        return Ok(commit_return.multiply_ratio(ledger_usd, total));
+    }
+    let multiplier = current_multiplier(deps.storage, env)?;
+    let weighted = ledger_usd.mul_floor(multiplier);
+    let total = TOTAL_WEIGHTED_COMMIT_USD
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(weighted)?
+        .checked_add(usd_after.mul_floor(multiplier))?;
+    if total.is_zero() {
+        return Ok(Uint128::zero());
+    }
+    Ok(commit_return.multiply_ratio(weighted, total))
+}
@@ -0,0 +1,277 @@
+//! Sponsor matching escrows.
+//!
+//! `Sponsor` escrows bluechip in the pool with a match ratio, a
+//! per-commit cap, an expiry and a `SponsorCredit` choice. Every
+//! pre-threshold commit that stays below the threshold is then matched
+//! out of each live escrow in turn (ascending sponsor address): the
+//! matched bluechip and its USD value at the commit's oracle rate are
+//! booked exactly like a fee-free commit by the credited wallet, so they
+//! count toward `USD_RAISED_FROM_COMMIT`, the seed and the refund pool.
+//!
+//! A match never carries the pool across the threshold (the crossing
+//! split is the committer's commit alone) and never pushes the credited
+//! wallet over its wallet cap; both clip the match instead. A match is
+//! final: on a failed raise it is refunded to the credited wallet along
+//! with the rest of the raise. `ReclaimSponsorship` returns the
+//! unmatched remainder once the sponsorship has expired, the threshold
+//! was crossed, or the raise deadline passed.
+
+use cosmwasm_std::{
+    Addr, Attribute, Decimal, DepsMut, Env, MessageInfo, Order, Response, Storage, Timestamp,
+    Uint128,
+};
+
+use crate::asset::get_native_denom;
+use crate::admin::ensure_not_drained;
+use crate::error::ContractError;
+use crate::generic_helpers::{get_bank_transfer_to_msg, update_commit_info};
+use crate::state::{
+    CommitLimitInfo, SponsorCredit, Sponsorship, COMMIT_LEDGER, GROSS_RAISED_FROM_COMMIT,
+    IS_THRESHOLD_HIT, MAX_SPONSORSHIPS, MAX_SPONSORSHIP_SECONDS, MAX_SPONSOR_MATCH_RATIO,
+    MIN_SPONSORSHIP_USD, NATIVE_RAISED_FROM_COMMIT, POOL_INFO, RAISE_DEADLINE, SPONSORSHIPS,
+    USD_RAISED_FROM_COMMIT,
+};
+use crate::swap_helper::get_oracle_conversion_with_staleness;
+
+use super::early_supporter::credit_commit_ledger;
+use super::refund;
+
+/// Opens a sponsorship, or tops up the sender's existing one and
+/// replaces its terms.
+pub fn execute_sponsor(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    match_ratio: Decimal,
+    max_match_per_commit: Uint128,
+    credit: SponsorCredit,
+    expires_at: Timestamp,
+) -> Result<Response, ContractError> {
+    ensure_not_drained(deps.storage)?;
+    refund::ensure_raise_open(deps.storage, &env)?;
+    let invalid = |reason: String| ContractError::InvalidSponsorship { reason };
+    if IS_THRESHOLD_HIT.load(deps.storage)? {
+        return Err(invalid("threshold already crossed".to_string()));
+    }
+    if match_ratio.is_zero() || match_ratio > MAX_SPONSOR_MATCH_RATIO {
+        return Err(invalid(format!(
+            "match_ratio must be above 0 and at most {}",
+            MAX_SPONSOR_MATCH_RATIO
+        )));
+    }
+    if max_match_per_commit.is_zero() {
+        return Err(invalid("max_match_per_commit must be non-zero".to_string()));
+    }
+    if expires_at <= env.block.time
+        || expires_at > env.block.time.plus_seconds(MAX_SPONSORSHIP_SECONDS)
+    {
+        return Err(invalid(format!(
+            "expires_at must be in the future and at most {} seconds away",
+            MAX_SPONSORSHIP_SECONDS
+        )));
+    }
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
+    let deposit = cw_utils::must_pay(&info, &bluechip_denom).map_err(|e| invalid(e.to_string()))?;
+
+    let existing = SPONSORSHIPS.may_load(deps.storage, &info.sender)?;
+    let sponsorship = match existing {
+        Some(existing) => Sponsorship {
+            match_ratio,
+            max_match_per_commit,
+            credit,
+            expires_at,
+            escrow_deposited: existing.escrow_deposited.checked_add(deposit)?,
+            escrow_remaining: existing.escrow_remaining.checked_add(deposit)?,
+            ..existing
+        },
+        None => {
+            if SPONSORSHIPS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .count()
+                >= MAX_SPONSORSHIPS
+            {
+                return Err(invalid(format!(
+                    "pool already has {} sponsorships",
+                    MAX_SPONSORSHIPS
+                )));
+            }
+            // Slots are scarce, so a new sponsorship must be worth
+            // matching against.
+            let deposit_usd = get_oracle_conversion_with_staleness(
+                deps.as_ref(),
+                deposit,
+                env.block.time.seconds(),
+            )?
+            .amount;
+            if deposit_usd < MIN_SPONSORSHIP_USD {
+                return Err(invalid(format!(
+                    "deposit is worth {} USD, below the {} minimum",
+                    deposit_usd, MIN_SPONSORSHIP_USD
+                )));
+            }
+            Sponsorship {
+                match_ratio,
+                max_match_per_commit,
+                credit,
+                expires_at,
+                escrow_deposited: deposit,
+                escrow_remaining: deposit,
+                matched_bluechip: Uint128::zero(),
+                matched_usd: Uint128::zero(),
+                match_count: 0,
+            }
+        }
+    };
+    SPONSORSHIPS.save(deps.storage, &info.sender, &sponsorship)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "sponsor")
+        .add_attribute("sponsor", info.sender.to_string())
+        .add_attribute("deposit", deposit.to_string())
+        .add_attribute("escrow_remaining", sponsorship.escrow_remaining.to_string())
+        .add_attribute("match_ratio", match_ratio.to_string())
+        .add_attribute("max_match_per_commit", max_match_per_commit.to_string())
+        .add_attribute("expires_at", expires_at.seconds().to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
+/// Matches a pre-threshold commit of `bluechip_amount` (worth
+/// `usd_value`) by `committer` from every live sponsorship. Returns the
+/// response attributes; none when nothing was matched.
+pub(crate) fn match_commit(
+    storage: &mut dyn Storage,
+    env: &Env,
+    committer: &Addr,
+    commit_config: &CommitLimitInfo,
+    pool_contract_address: &Addr,
+    bluechip_amount: Uint128,
+    usd_value: Uint128,
+) -> Result<Vec<Attribute>, ContractError> {
+    let sponsors: Vec<(Addr, Sponsorship)> = SPONSORSHIPS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<Result<_, _>>()?;
+    let mut matched_bluechip = Uint128::zero();
+    let mut matched_usd = Uint128::zero();
+    for (sponsor, mut sponsorship) in sponsors {
+        if sponsorship.escrow_remaining.is_zero() || env.block.time >= sponsorship.expires_at {
+            continue;
+        }
+        // Leave at least one USD unit for the next commit to cross with.
+        let headroom = commit_config
+            .commit_amount_for_threshold_usd
+            .saturating_sub(USD_RAISED_FROM_COMMIT.load(storage)?)
+            .saturating_sub(Uint128::one());
+        if headroom.is_zero() {
+            break;
+        }
+        let credited = match sponsorship.credit {
+            SponsorCredit::Sponsor => sponsor.clone(),
+            SponsorCredit::Committer => committer.clone(),
+        };
+        let limit_usd = match commit_config.wallet_commit_cap() {
+            Some(cap) => headroom.min(
+                cap.saturating_sub(COMMIT_LEDGER.may_load(storage, &credited)?.unwrap_or_default()),
+            ),
+            None => headroom,
+        };
+
+        let mut bluechip = bluechip_amount
+            .mul_floor(sponsorship.match_ratio)
+            .min(sponsorship.max_match_per_commit)
+            .min(sponsorship.escrow_remaining);
+        let mut usd = usd_value.multiply_ratio(bluechip, bluechip_amount);
+        if usd > limit_usd {
+            bluechip = bluechip.multiply_ratio(limit_usd, usd);
+            usd = usd_value.multiply_ratio(bluechip, bluechip_amount);
+        }
+        if bluechip.is_zero() || usd.is_zero() {
+            continue;
+        }
+
+        credit_commit_ledger(storage, env, &credited, usd)?;
+        USD_RAISED_FROM_COMMIT
+            .update::<_, ContractError>(storage, |r| Ok(r.checked_add(usd)?))?;
+        NATIVE_RAISED_FROM_COMMIT
+            .update::<_, ContractError>(storage, |r| Ok(r.checked_add(bluechip)?))?;
+        let gross = GROSS_RAISED_FROM_COMMIT
+            .may_load(storage)?
+            .unwrap_or_default()
+            .checked_add(bluechip)?;
+        GROSS_RAISED_FROM_COMMIT.save(storage, &gross)?;
+        update_commit_info(
+            storage,
+            &credited,
+            pool_contract_address,
+            bluechip,
+            usd,
+            env.block.time,
+        )?;
+
+        sponsorship.escrow_remaining = sponsorship.escrow_remaining.checked_sub(bluechip)?;
+        sponsorship.matched_bluechip = sponsorship.matched_bluechip.checked_add(bluechip)?;
+        sponsorship.matched_usd = sponsorship.matched_usd.checked_add(usd)?;
+        sponsorship.match_count += 1;
+        SPONSORSHIPS.save(storage, &sponsor, &sponsorship)?;
+        matched_bluechip = matched_bluechip.checked_add(bluechip)?;
+        matched_usd = matched_usd.checked_add(usd)?;
+    }
+
+    if matched_bluechip.is_zero() {
+        return Ok(vec![]);
+    }
+    Ok(vec![
+        Attribute::new("sponsor_matched_bluechip", matched_bluechip.to_string()),
+        Attribute::new("sponsor_matched_usd", matched_usd.to_string()),
+    ])
+}
+
+/// Whether `sponsorship`'s unmatched escrow can be taken back now.
+pub(crate) fn is_reclaimable(
+    storage: &dyn Storage,
+    env: &Env,
+    sponsorship: &Sponsorship,
+) -> Result<bool, ContractError> {
+    Ok(env.block.time >= sponsorship.expires_at
+        || IS_THRESHOLD_HIT.load(storage)?
+        || RAISE_DEADLINE
+            .may_load(storage)?
+            .is_some_and(|deadline| env.block.time >= deadline))
+}
+
+/// Returns the sender's unmatched escrow and frees their slot.
+pub fn execute_reclaim_sponsorship(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let sponsorship = SPONSORSHIPS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NoSponsorship {})?;
+    if !is_reclaimable(deps.storage, &env, &sponsorship)? {
+        return Err(ContractError::SponsorshipNotReclaimable {
+            expires_at: sponsorship.expires_at,
+        });
+    }
+    SPONSORSHIPS.remove(deps.storage, &info.sender);
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
+    let mut response = Response::new();
+    if !sponsorship.escrow_remaining.is_zero() {
+        response = response.add_message(get_bank_transfer_to_msg(
+            &info.sender,
+            &bluechip_denom,
+            sponsorship.escrow_remaining,
+        )?);
+    }
+    Ok(response
+        .add_attribute("action", "reclaim_sponsorship")
+        .add_attribute("sponsor", info.sender.to_string())
+        .add_attribute("reclaimed", sponsorship.escrow_remaining.to_string())
+        .add_attribute("matched_bluechip", sponsorship.matched_bluechip.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
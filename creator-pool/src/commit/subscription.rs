@@ -0,0 +1,255 @@
+//! Recurring subscriptions.
+//!
+//! `Subscribe` escrows bluechip in the pool and records a
+//! `Subscription` (USD per period, period length, period count). Once a
+//! period is due, anyone may call `RenewSubscription { wallet }`: the
+//! period's USD price is converted to bluechip at the current oracle
+//! rate and run through the normal `commit` path as if the subscriber
+//! had sent it, so fees, the raise ledger, threshold crossing and the
+//! post-threshold swap all behave exactly as for a manual commit. A
+//! caller other than the subscriber is paid `SUBSCRIPTION_KEEPER_FEE`
+//! of the period's bluechip out of the same escrow. The subscriber funds
+//! their own keeper, so renewing from a second wallet only moves the
+//! subscriber's money around and there is no factory bounty to farm.
+//! A subscription may name a membership tier, in which case every
+//! renewal is a tier-tagged commit. `CancelSubscription` returns
+//! whatever escrow is left.
+//!
+//! Bluechip is a bank denom with no allowance primitive, so
+//! subscriptions are escrow-funded only.
+
+use cosmwasm_std::{Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+
+use crate::admin::ensure_not_drained;
+use crate::asset::{get_native_denom, TokenInfo};
+use crate::error::ContractError;
+use crate::generic_helpers::get_bank_transfer_to_msg;
+use crate::state::{
+    Subscription, MAX_SUBSCRIPTION_PERIODS, MAX_SUBSCRIPTION_PERIOD_SECONDS,
+    MIN_SUBSCRIPTION_PERIOD_SECONDS, POOL_INFO, SUBSCRIPTIONS, SUBSCRIPTION_KEEPER_FEE,
+};
+use crate::swap_helper::{get_oracle_conversion_with_staleness, usd_to_bluechip_at_rate_ceil};
+
+use super::{commit, membership, refund, CommitOptions};
+
+pub fn execute_subscribe(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    usd_per_period: Uint128,
+    period_seconds: u64,
+    periods: u32,
+    tier: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure_not_drained(deps.storage)?;
+    refund::ensure_raise_open(deps.storage, &env)?;
+    if SUBSCRIPTIONS.has(deps.storage, &info.sender) {
+        return Err(ContractError::SubscriptionExists {});
+    }
+    if usd_per_period.is_zero() {
+        return Err(ContractError::InvalidSubscription {
+            reason: "usd_per_period must be non-zero".to_string(),
+        });
+    }
+    if !(MIN_SUBSCRIPTION_PERIOD_SECONDS..=MAX_SUBSCRIPTION_PERIOD_SECONDS)
+        .contains(&period_seconds)
+    {
+        return Err(ContractError::InvalidSubscription {
+            reason: format!(
+                "period_seconds must be between {} and {}",
+                MIN_SUBSCRIPTION_PERIOD_SECONDS, MAX_SUBSCRIPTION_PERIOD_SECONDS
+            ),
+        });
+    }
+    if periods == 0 || periods > MAX_SUBSCRIPTION_PERIODS {
+        return Err(ContractError::InvalidSubscription {
+            reason: format!("periods must be between 1 and {}", MAX_SUBSCRIPTION_PERIODS),
+        });
+    }
+    if let Some(tier) = &tier {
+        let config = membership::load_tier(deps.storage, tier)?;
+        if usd_per_period < config.usd_price {
+            return Err(ContractError::TierUnderpaid {
+                tier: config.name,
+                got: usd_per_period,
+                price: config.usd_price,
+            });
+        }
+    }
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
+    let escrow = cw_utils::must_pay(&info, &bluechip_denom).map_err(|e| {
+        ContractError::InvalidSubscription {
+            reason: e.to_string(),
+        }
+    })?;
+
+    // The first period is due immediately; a keeper (or the subscriber)
+    // runs it with `RenewSubscription` like every later one.
+    let subscription = Subscription {
+        usd_per_period,
+        period_seconds,
+        periods_remaining: periods,
+        escrow_bluechip: escrow,
+        next_due: env.block.time,
+        created_at: env.block.time,
+        renewals: 0,
+        tier,
+    };
+    SUBSCRIPTIONS.save(deps.storage, &info.sender, &subscription)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "subscribe")
+        .add_attribute("subscriber", info.sender.to_string())
+        .add_attribute("usd_per_period", usd_per_period.to_string())
+        .add_attribute("period_seconds", period_seconds.to_string())
+        .add_attribute("periods", periods.to_string())
+        .add_attribute("escrow_bluechip", escrow.to_string())
+        .add_attribute("tier", subscription.tier.clone().unwrap_or_default())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
+pub fn execute_renew_subscription(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    wallet: String,
+) -> Result<Response, ContractError> {
+    let subscriber = deps.api.addr_validate(&wallet)?;
+    let mut subscription = SUBSCRIPTIONS
+        .may_load(deps.storage, &subscriber)?
+        .ok_or(ContractError::NoSubscription {})?;
+    if env.block.time < subscription.next_due {
+        return Err(ContractError::SubscriptionNotDue {
+            next_due: subscription.next_due,
+        });
+    }
+
+    let rate = get_oracle_conversion_with_staleness(
+        deps.as_ref(),
+        subscription.escrow_bluechip,
+        env.block.time.seconds(),
+    )?
+    .rate_used;
+    if rate.is_zero() {
+        return Err(ContractError::InvalidOraclePrice {});
+    }
+    // Rounded up: the commit converts this back to USD rounding down,
+    // and a floor here would land a tier-priced period one micro-USD
+    // short of the tier.
+    let amount = usd_to_bluechip_at_rate_ceil(subscription.usd_per_period, rate)?;
+    if amount > subscription.escrow_bluechip {
+        return Err(ContractError::SubscriptionUnderfunded {
+            needed: amount,
+            escrow: subscription.escrow_bluechip,
+        });
+    }
+
+    subscription.escrow_bluechip = subscription.escrow_bluechip.checked_sub(amount)?;
+    // Paid from whatever escrow the period leaves, so a keeper never
+    // blocks a renewal the escrow can otherwise cover.
+    let keeper_fee = if info.sender == subscriber {
+        Uint128::zero()
+    } else {
+        amount
+            .mul_floor(SUBSCRIPTION_KEEPER_FEE)
+            .min(subscription.escrow_bluechip)
+    };
+    subscription.escrow_bluechip = subscription.escrow_bluechip.checked_sub(keeper_fee)?;
+    subscription.periods_remaining -= 1;
+    subscription.renewals += 1;
+    // Anchored on the schedule, not the renewal block, so a late keeper
+    // doesn't push every later period back.
+    subscription.next_due = subscription.next_due.plus_seconds(subscription.period_seconds);
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
+    let mut msgs: Vec<CosmosMsg> = Vec::new();
+    if !keeper_fee.is_zero() {
+        msgs.push(get_bank_transfer_to_msg(&info.sender, &bluechip_denom, keeper_fee)?);
+    }
+    let completed = subscription.periods_remaining == 0;
+    if completed {
+        SUBSCRIPTIONS.remove(deps.storage, &subscriber);
+        if !subscription.escrow_bluechip.is_zero() {
+            msgs.push(get_bank_transfer_to_msg(
+                &subscriber,
+                &bluechip_denom,
+                subscription.escrow_bluechip,
+            )?);
+        }
+    } else {
+        SUBSCRIPTIONS.save(deps.storage, &subscriber, &subscription)?;
+    }
+
+    // The escrow is already in the pool's bank balance, so the commit
+    // runs with the subscriber as sender and the period's bluechip as
+    // its attached funds. Pause, deadline, minimum-commit and rate-limit
+    // gates all apply exactly as for a manual commit.
+    let commit_info = MessageInfo {
+        sender: subscriber.clone(),
+        funds: vec![Coin {
+            denom: bluechip_denom,
+            amount,
+        }],
+    };
+    let asset = TokenInfo {
+        info: pool_info.pool_info.asset_infos[0].clone(),
+        amount,
+    };
+    let commit_response = commit(
+        deps,
+        env.clone(),
+        commit_info,
+        asset,
+        CommitOptions {
+            tier: subscription.tier.clone(),
+            ..Default::default()
+        },
+    )?;
+
+    Ok(commit_response
+        .add_messages(msgs)
+        .add_attribute("subscription_action", "renew_subscription")
+        .add_attribute("keeper", info.sender.to_string())
+        .add_attribute("keeper_fee", keeper_fee.to_string())
+        .add_attribute("renewal_bluechip", amount.to_string())
+        .add_attribute("renewals", subscription.renewals.to_string())
+        .add_attribute("periods_remaining", subscription.periods_remaining.to_string())
+        .add_attribute("escrow_remaining", subscription.escrow_bluechip.to_string())
+        .add_attribute("subscription_complete", completed.to_string()))
+}
+
+/// Not gated on pause or drain: the escrow never joins the reserves, so
+/// handing it back is always safe.
+pub fn execute_cancel_subscription(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let subscription = SUBSCRIPTIONS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NoSubscription {})?;
+    SUBSCRIPTIONS.remove(deps.storage, &info.sender);
+
+    let mut response = Response::new();
+    if !subscription.escrow_bluechip.is_zero() {
+        let pool_info = POOL_INFO.load(deps.storage)?;
+        let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
+        response = response.add_message(get_bank_transfer_to_msg(
+            &info.sender,
+            &bluechip_denom,
+            subscription.escrow_bluechip,
+        )?);
+    }
+
+    Ok(response
+        .add_attribute("action", "cancel_subscription")
+        .add_attribute("subscriber", info.sender.to_string())
+        .add_attribute("refunded_bluechip", subscription.escrow_bluechip.to_string())
+        .add_attribute("renewals", subscription.renewals.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
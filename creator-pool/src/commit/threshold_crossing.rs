@@ -14,12 +14,15 @@
 //! 4. Update commit analytics and clear `THRESHOLD_PROCESSING` so the
 //! next commit can proceed.
 //!
+//! Both crossing handlers also arm the post-threshold cooldown and, when
+//! the factory configured one, the launch window that follows it.
+//!
 //! The factory-notify message is attached as a SubMsg (not a plain
 //! CosmosMsg) so a failure on the factory side is recoverable via
 //! `RetryFactoryNotify` rather than reverting the whole crossing tx.
 
 use cosmwasm_std::{
-    to_json_binary, Addr, CosmosMsg, Decimal, DepsMut, Env, Response, Uint128, WasmMsg,
+    to_json_binary, Addr, CosmosMsg, Decimal, DepsMut, Env, Response, Storage, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
 
@@ -30,14 +33,18 @@ use crate::generic_helpers::{
 };
 use crate::msg::CommitFeeInfo;
 use crate::state::{
-    CommitLimitInfo, PoolAnalytics, PoolFeeState, PoolInfo, PoolSpecs, PoolState,
-    ThresholdPayoutAmounts, COMMIT_LEDGER, IS_THRESHOLD_HIT, NATIVE_RAISED_FROM_COMMIT,
-    POOL_FEE_STATE, POOL_STATE, POST_THRESHOLD_COOLDOWN_BLOCKS,
-    POST_THRESHOLD_COOLDOWN_UNTIL_BLOCK, THRESHOLD_PROCESSING, USD_RAISED_FROM_COMMIT,
+    CommitLimitInfo, LaunchFeeSchedule, LaunchWindow, PoolAnalytics, PoolFeeState, PoolInfo,
+    PoolSpecs, PoolState, ThresholdPayoutAmounts, IS_THRESHOLD_HIT, LAUNCH_FEE,
+    LAUNCH_PROTECTION_CONFIG, LAUNCH_WINDOW, NATIVE_RAISED_FROM_COMMIT, POOL_FEE_STATE, POOL_STATE,
+    POST_THRESHOLD_COOLDOWN_BLOCKS, POST_THRESHOLD_COOLDOWN_UNTIL_BLOCK, THRESHOLD_PROCESSING,
+    USD_RAISED_FROM_COMMIT,
 };
 use crate::swap_helper::{
-    assert_max_spread, compute_swap, update_price_accumulator, usd_to_bluechip_at_rate,
+    accrue_protocol_fee, assert_max_spread, candle_price, compute_swap, record_candles,
+    update_price_accumulator, usd_to_bluechip_at_rate,
 };
+use super::early_supporter::credit_commit_ledger;
+use pool_core::history::{record_trade, TradeRecord};
 
 use super::commit_base_attributes;
 
@@ -46,6 +53,7 @@ pub(crate) fn process_threshold_crossing_with_excess(
     deps: &mut DepsMut,
     env: Env,
     sender: Addr,
+    payer: &Addr,
     asset: &TokenInfo,
     amount: Uint128,
     amount_after_fees: Uint128,
@@ -92,9 +100,7 @@ pub(crate) fn process_threshold_crossing_with_excess(
     let usd_excess = usd_value.checked_sub(usd_to_threshold)?;
 
     // Update commit ledger with only the threshold portion
-    COMMIT_LEDGER.update::<_, ContractError>(deps.storage, &sender, |v| {
-        Ok(v.unwrap_or_default().checked_add(usd_to_threshold)?)
-    })?;
+    credit_commit_ledger(deps.storage, &env, &sender, usd_to_threshold)?;
     USD_RAISED_FROM_COMMIT.save(deps.storage, &commit_config.commit_amount_for_threshold_usd)?;
     // Audit fix: NATIVE_RAISED_FROM_COMMIT now stores the *net* bluechip
     // entering the threshold-pool side of the contract's bank balance —
@@ -145,6 +151,10 @@ pub(crate) fn process_threshold_crossing_with_excess(
     )?;
     messages.extend(payout_msgs.other_msgs);
     let factory_notify = payout_msgs.factory_notify;
+    // Armed after the payout so the wallet cap resolves against the
+    // seeded reserve. The crosser's excess swap below is bounded by its
+    // own 3% cap and doesn't pass through the launch gate.
+    arm_launch_window(deps.storage, env.block.height, pool_state.reserve1)?;
 
     // `update_commit_info` is deferred to a single call at the bottom of
     // this handler so `Committing.last_payment_bluechip` /
@@ -168,6 +178,9 @@ pub(crate) fn process_threshold_crossing_with_excess(
     let mut return_amt = Uint128::zero();
     let mut spread_amt = Uint128::zero();
     let mut commission_amt = Uint128::zero();
+    // `commission_amt` net of the protocol share; what LP fee growth
+    // and the trade history see.
+    let mut lp_commission = Uint128::zero();
     let mut refunded_excess = Uint128::zero();
     let mut capped_excess = Uint128::zero();
 
@@ -180,7 +193,7 @@ pub(crate) fn process_threshold_crossing_with_excess(
         let ask_pool = pool_state.reserve1;
 
         // Cap the excess swap at 3% of the freshly seeded bluechip reserve.
-        // Any remainder is refunded to the sender — they can swap it in
+        // Any remainder is refunded to the payer — they can swap it in
         // subsequent transactions where other participants can also trade.
         let max_excess_swap = offer_pool.multiply_ratio(3u128, 100u128);
         capped_excess = effective_bluechip_excess.min(max_excess_swap);
@@ -238,10 +251,25 @@ pub(crate) fn process_threshold_crossing_with_excess(
         pool_state.reserve0 = offer_pool.checked_add(capped_excess)?;
         pool_state.reserve1 = ask_pool.checked_sub(return_amt.checked_add(commission_amt)?)?;
 
-        update_pool_fee_growth(pool_fee_state, pool_state, 0, commission_amt)?;
+        let protocol_fee_amt = accrue_protocol_fee(deps.storage, 0, commission_amt)?;
+        lp_commission = commission_amt.checked_sub(protocol_fee_amt)?;
+        update_pool_fee_growth(pool_fee_state, pool_state, 0, lp_commission)?;
         POOL_FEE_STATE.save(deps.storage, pool_fee_state)?;
         POOL_STATE.save(deps.storage, pool_state)?;
 
+        // The seed price opens the pool's first candle; the excess swap
+        // moves it from there.
+        if !capped_excess.is_zero() && !return_amt.is_zero() {
+            record_candles(
+                deps.storage,
+                env.block.time.seconds(),
+                candle_price(offer_pool, ask_pool),
+                candle_price(pool_state.reserve0, pool_state.reserve1),
+                capped_excess,
+                return_amt,
+            )?;
+        }
+
         if !return_amt.is_zero() {
             messages.push(
                 WasmMsg::Execute {
@@ -256,11 +284,12 @@ pub(crate) fn process_threshold_crossing_with_excess(
             );
         }
 
-        // Refund the capped portion back to the sender
+        // Refund the capped portion back to whoever paid for the commit
+        // (not a gift beneficiary).
         if !refunded_excess.is_zero() {
             let bluechip_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
             messages.push(get_bank_transfer_to_msg(
-                &sender,
+                payer,
                 &bluechip_denom,
                 refunded_excess,
             )?);
@@ -296,6 +325,11 @@ pub(crate) fn process_threshold_crossing_with_excess(
         analytics.total_volume_1 = analytics.total_volume_1.saturating_add(return_amt);
         analytics.last_trade_block = env.block.height;
         analytics.last_trade_timestamp = env.block.time.seconds();
+        record_trade(
+            deps.storage,
+            env.block.time.seconds(),
+            &TradeRecord::from_swap(0, capped_excess, return_amt, lp_commission),
+        )?;
     }
 
     // `pool_state` (outer &mut ref) already reflects the committed on-chain
@@ -370,9 +404,7 @@ pub(crate) fn process_threshold_hit_exact(
         return Err(ContractError::StuckThresholdProcessing);
     }
 
-    COMMIT_LEDGER.update::<_, ContractError>(deps.storage, &sender, |v| {
-        Ok(v.unwrap_or_default().checked_add(usd_value)?)
-    })?;
+    credit_commit_ledger(deps.storage, &env, &sender, usd_value)?;
     let final_usd = new_total.min(commit_config.commit_amount_for_threshold_usd);
     USD_RAISED_FROM_COMMIT.save(deps.storage, &final_usd)?;
     // Store the net-of-fees bluechip that actually enters the contract's
@@ -405,6 +437,7 @@ pub(crate) fn process_threshold_hit_exact(
         &env,
     )?;
     messages.extend(payout.other_msgs);
+    arm_launch_window(deps.storage, env.block.height, pool_state.reserve1)?;
     update_commit_info(
         deps.storage,
         &sender,
@@ -433,3 +466,43 @@ pub(crate) fn process_threshold_hit_exact(
         .add_attribute("commit_amount_usd", usd_value.to_string())
         .add_attribute("total_usd_raised_after", new_total.to_string()))
 }
+
+/// Turn the pool's `LAUNCH_PROTECTION_CONFIG` into an active
+/// `LAUNCH_WINDOW` and `LAUNCH_FEE` schedule. Both start when the
+/// post-threshold cooldown lifts: the window runs for `window_blocks`
+/// blocks with the per-wallet buy cap fixed against the freshly-seeded
+/// creator-token reserve, and the launch fee decays to the LP fee over
+/// `launch_fee_decay_blocks`. Each is skipped when the factory disabled
+/// it; the whole call is a no-op when the pool predates the config.
+fn arm_launch_window(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    seeded_reserve1: Uint128,
+) -> Result<(), ContractError> {
+    let Some(config) = LAUNCH_PROTECTION_CONFIG.may_load(storage)? else {
+        return Ok(());
+    };
+    let trading_resumes_at = block_height + POST_THRESHOLD_COOLDOWN_BLOCKS + 1;
+    if config.window_blocks > 0 {
+        LAUNCH_WINDOW.save(
+            storage,
+            &LaunchWindow {
+                ends_at_block: trading_resumes_at + config.window_blocks,
+                max_swap_bps: config.max_swap_bps,
+                wallet_buy_cap: seeded_reserve1
+                    .multiply_ratio(config.max_wallet_buy_bps, 10_000u128),
+            },
+        )?;
+    }
+    if !config.launch_fee.is_zero() && config.launch_fee_decay_blocks > 0 {
+        LAUNCH_FEE.save(
+            storage,
+            &LaunchFeeSchedule {
+                initial_fee: config.launch_fee,
+                start_block: trading_resumes_at,
+                end_block: trading_resumes_at + config.launch_fee_decay_blocks,
+            },
+        )?;
+    }
+    Ok(())
+}
@@ -5,8 +5,10 @@
 //! splits (`creator_reward_amount`, `bluechip_reward_amount`,
 //! `pool_seed_amount`, `commit_return_amount`), seeds the LP reserves
 //! from `NATIVE_RAISED_FROM_COMMIT`, parks any creator excess (when
-//! raised bluechip exceeds `max_bluechip_lock_per_pool`), schedules the
-//! post-threshold distribution batch loop, and emits the factory's
+//! raised bluechip exceeds `max_bluechip_lock_per_pool`), starts the
+//! creator-reward vesting schedule when one is configured, schedules the
+//! post-threshold distribution batch loop (or, for `Pull` pools, freezes
+//! the totals `ClaimCommitReward` pays against), and emits the factory's
 //! `NotifyThresholdCrossed` SubMsg.
 //!
 //! The factory-notify SubMsg is held aside as `factory_notify` and
@@ -24,15 +26,19 @@ use cw20::Cw20ExecuteMsg;
 use crate::error::ContractError;
 use crate::msg::CommitFeeInfo;
 use crate::state::{
-    CommitLimitInfo, CreatorExcessLiquidity, DistributionState, PoolFeeState, PoolInfo, PoolState,
-    ThresholdPayoutAmounts, COMMIT_LEDGER, CREATOR_EXCESS_POSITION,
-    DEFAULT_ESTIMATED_GAS_PER_DISTRIBUTION, DEFAULT_MAX_GAS_PER_TX, DISTRIBUTION_STATE,
-    IS_THRESHOLD_HIT, POOL_FEE_STATE, POOL_STATE, SECONDS_PER_DAY,
-    THRESHOLD_PAYOUT_BLUECHIP_BASE_UNITS, THRESHOLD_PAYOUT_COMMIT_RETURN_BASE_UNITS,
-    THRESHOLD_PAYOUT_CREATOR_BASE_UNITS, THRESHOLD_PAYOUT_POOL_BASE_UNITS,
-    THRESHOLD_PAYOUT_TOTAL_BASE_UNITS,
+    CommitLimitInfo, CreatorExcessLiquidity, CreatorVesting, DistributionState, PoolFeeState,
+    PoolInfo, PoolState, PullDistribution, ThresholdPayoutAmounts, COMMIT_LEDGER,
+    CREATOR_EXCESS_POSITION, CREATOR_VESTING, CREATOR_VESTING_CONFIG,
+    DEFAULT_ESTIMATED_GAS_PER_DISTRIBUTION, DEFAULT_MAX_GAS_PER_TX, DISTRIBUTION_MODE,
+    DISTRIBUTION_STATE, IS_THRESHOLD_HIT, POOL_FEE_STATE, POOL_STATE, PULL_DISTRIBUTION,
+    SECONDS_PER_DAY, THRESHOLD_PAYOUT_BLUECHIP_BASE_UNITS,
+    THRESHOLD_PAYOUT_COMMIT_RETURN_BASE_UNITS, THRESHOLD_PAYOUT_CREATOR_BASE_UNITS,
+    THRESHOLD_PAYOUT_POOL_BASE_UNITS, THRESHOLD_PAYOUT_TOTAL_BASE_UNITS,
 };
+use super::early_supporter::total_distribution_weight;
+use super::revenue_split::split_creator_revenue;
 use pool_core::liquidity_helpers::integer_sqrt;
+use pool_factory_interfaces::DistributionMode;
 
 /// Validate that the four threshold-payout components match the canonical
 /// per-pool split (325B + 25B + 350B + 500B = 1.2T base units) and sum
@@ -203,11 +209,38 @@ pub fn trigger_threshold_payout(
         return Err(ContractError::ThresholdPayoutCorruption);
     }
 
-    other_msgs.push(mint_tokens(
-        &pool_info.token_address,
-        &fee_info.creator_wallet_address,
-        payout.creator_reward_amount,
-    )?);
+    // With vesting configured the creator reward is minted to the pool
+    // and released through `ClaimVestedCreatorReward` instead; otherwise
+    // it is minted straight along the creator's revenue split.
+    match CREATOR_VESTING_CONFIG.may_load(storage)? {
+        Some(vesting) => {
+            CREATOR_VESTING.save(
+                storage,
+                &CreatorVesting {
+                    creator: fee_info.creator_wallet_address.clone(),
+                    total: payout.creator_reward_amount,
+                    claimed: Uint128::zero(),
+                    start: env.block.time,
+                    cliff_end: env.block.time.plus_seconds(vesting.cliff_seconds),
+                    end: env.block.time.plus_seconds(vesting.duration_seconds),
+                },
+            )?;
+            other_msgs.push(mint_tokens(
+                &pool_info.token_address,
+                &env.contract.address,
+                payout.creator_reward_amount,
+            )?);
+        }
+        None => {
+            for (recipient, share) in split_creator_revenue(
+                storage,
+                &fee_info.creator_wallet_address,
+                payout.creator_reward_amount,
+            )? {
+                other_msgs.push(mint_tokens(&pool_info.token_address, &recipient, share)?);
+            }
+        }
+    }
 
     other_msgs.push(mint_tokens(
         &pool_info.token_address,
@@ -231,11 +264,30 @@ pub fn trigger_threshold_payout(
         .count();
     let committer_count = u32::try_from(committer_count_usize).unwrap_or(u32::MAX);
 
-    if committer_count > 0 {
+    // Weighted pools share by early-supporter weight instead of raw USD;
+    // see `early_supporter`.
+    let total_committed_usd =
+        total_distribution_weight(storage, commit_config.commit_amount_for_threshold_usd)?;
+    let pull_mode =
+        DISTRIBUTION_MODE.may_load(storage)?.unwrap_or_default() == DistributionMode::Pull;
+    if committer_count > 0 && pull_mode {
+        // Pull pools skip the keeper loop entirely; committers mint
+        // their own share against these frozen totals.
+        PULL_DISTRIBUTION.save(
+            storage,
+            &PullDistribution {
+                total_to_distribute: payout.commit_return_amount,
+                total_committed_usd,
+                frozen_at: env.block.time,
+                claimed: Uint128::zero(),
+                claim_count: 0,
+            },
+        )?;
+    } else if committer_count > 0 {
         let dist_state = DistributionState {
             is_distributing: true,
             total_to_distribute: payout.commit_return_amount,
-            total_committed_usd: commit_config.commit_amount_for_threshold_usd,
+            total_committed_usd,
             last_processed_key: None,
             // Real count, not u32::MAX. Termination is now driven by ledger
             // emptiness in process_distribution_batch (the source of truth),
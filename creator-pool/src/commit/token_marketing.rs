@@ -0,0 +1,83 @@
+//! Creator-token marketing metadata.
+//!
+//! The factory makes itself the CW20's marketing admin at instantiate
+//! and hands the role to the pool together with the minter. The pool
+//! then forwards the creator's `UpdateTokenMarketing` as cw20-base
+//! `UpdateMarketing` / `UploadLogo`, which validate the logo themselves.
+//! Tokens created before the factory did this have no marketing admin,
+//! so the forward fails on them.
+
+use cosmwasm_std::{to_json_binary, CosmosMsg, DepsMut, Env, MessageInfo, Response, WasmMsg};
+use cw20::{Cw20ExecuteMsg, Logo};
+
+use crate::error::ContractError;
+use crate::state::{
+    COMMITFEEINFO, MAX_TOKEN_DESCRIPTION_LEN, MAX_TOKEN_PROJECT_LEN, POOL_INFO,
+};
+
+/// Creator-only. `None` leaves a field unchanged; an empty string
+/// clears it.
+pub fn execute_update_token_marketing(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    project: Option<String>,
+    description: Option<String>,
+    logo: Option<Logo>,
+) -> Result<Response, ContractError> {
+    let fee_info = COMMITFEEINFO.load(deps.storage)?;
+    if info.sender != fee_info.creator_wallet_address {
+        return Err(ContractError::Unauthorized {});
+    }
+    let invalid = |reason: String| ContractError::InvalidTokenMarketing { reason };
+    if project.is_none() && description.is_none() && logo.is_none() {
+        return Err(invalid("nothing to update".to_string()));
+    }
+    if project.as_ref().is_some_and(|p| p.len() > MAX_TOKEN_PROJECT_LEN) {
+        return Err(invalid(format!(
+            "project is longer than {} bytes",
+            MAX_TOKEN_PROJECT_LEN
+        )));
+    }
+    if description
+        .as_ref()
+        .is_some_and(|d| d.len() > MAX_TOKEN_DESCRIPTION_LEN)
+    {
+        return Err(invalid(format!(
+            "description is longer than {} bytes",
+            MAX_TOKEN_DESCRIPTION_LEN
+        )));
+    }
+
+    let token_address = POOL_INFO.load(deps.storage)?.token_address;
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if project.is_some() || description.is_some() {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: token_address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::UpdateMarketing {
+                project: project.clone(),
+                description: description.clone(),
+                marketing: None,
+            })?,
+            funds: vec![],
+        }));
+    }
+    if let Some(logo) = &logo {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: token_address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::UploadLogo(logo.clone()))?,
+            funds: vec![],
+        }));
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "update_token_marketing")
+        .add_attribute("creator", info.sender.to_string())
+        .add_attribute("token_address", token_address.to_string())
+        .add_attribute("project_updated", project.is_some().to_string())
+        .add_attribute("description_updated", description.is_some().to_string())
+        .add_attribute("logo_updated", logo.is_some().to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
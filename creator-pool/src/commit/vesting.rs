@@ -0,0 +1,60 @@
+//! Creator-reward vesting.
+//!
+//! When the factory's `creator_vesting` was enabled at pool creation,
+//! `trigger_threshold_payout` mints `creator_reward_amount` to the pool
+//! itself and records a `CreatorVesting` schedule starting at the
+//! crossing. The creator pulls whatever has vested so far with
+//! `ClaimVestedCreatorReward`: nothing before the cliff, then linearly
+//! up to the full amount at `end`. Claims follow the creator's revenue
+//! split, and any split recipient may trigger one.
+
+use cosmwasm_std::{to_json_binary, CosmosMsg, DepsMut, Env, MessageInfo, Response, WasmMsg};
+
+use crate::error::ContractError;
+use crate::state::{CREATOR_VESTING, POOL_INFO};
+
+use super::revenue_split::{is_revenue_claimer, split_creator_revenue};
+
+/// Not gated on pause or drain: the vesting balance never joins the
+/// reserves, so an emergency drain leaves it claimable.
+pub fn execute_claim_vested_creator_reward(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut vesting = CREATOR_VESTING
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoCreatorVesting {})?;
+    if !is_revenue_claimer(deps.storage, &vesting.creator, &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    let amount = vesting.claimable_at(env.block.time);
+    if amount.is_zero() {
+        return Err(ContractError::NoVestedCreatorReward {});
+    }
+    vesting.claimed = vesting.claimed.checked_add(amount)?;
+    CREATOR_VESTING.save(deps.storage, &vesting)?;
+
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    let mut messages: Vec<CosmosMsg> = vec![];
+    for (recipient, share) in split_creator_revenue(deps.storage, &vesting.creator, amount)? {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: pool_info.token_address.to_string(),
+            msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount: share,
+            })?,
+            funds: vec![],
+        }));
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "claim_vested_creator_reward")
+        .add_attribute("creator", vesting.creator.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("claimed_total", vesting.claimed.to_string())
+        .add_attribute("vesting_total", vesting.total.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
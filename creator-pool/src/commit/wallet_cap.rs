@@ -0,0 +1,73 @@
+//! Per-wallet pre-threshold commit caps.
+//!
+//! `CommitLimitInfo.max_commit_usd_per_wallet` and
+//! `max_commit_share_per_wallet` bound how much of the raise one
+//! wallet's `COMMIT_LEDGER` entry can hold, so a single whale can't
+//! fill the threshold and take most of `commit_return_amount`. Only
+//! the ledger-bound part of a commit counts: the post-threshold excess
+//! of a crossing commit is swapped like any post-threshold commit.
+//!
+//! An over-cap commit is not rejected outright. It is scaled down to the
+//! wallet's remaining allowance before fees are taken, and the rest of
+//! the attached bluechip is refunded to the payer. A scaled commit can
+//! never carry the pool across the threshold, because the allowance is
+//! below the USD still needed. A commit that would scale below the
+//! phase's minimum commit is rejected instead, so the cap can't leave
+//! dust entries in the ledger.
+
+use cosmwasm_std::{Addr, Storage, Uint128};
+
+use crate::asset::TokenInfo;
+use crate::error::ContractError;
+use crate::state::{CommitLimitInfo, COMMIT_LEDGER, USD_RAISED_FROM_COMMIT};
+
+/// The part of a pre-threshold commit that fits under `committer`'s cap.
+pub(crate) struct CappedCommit {
+    pub asset: TokenInfo,
+    pub usd_value: Uint128,
+    /// Bluechip returned to the payer; zero when the commit fit.
+    pub refund: Uint128,
+}
+
+pub(crate) fn clamp_to_wallet_cap(
+    storage: &dyn Storage,
+    commit_config: &CommitLimitInfo,
+    committer: &Addr,
+    asset: TokenInfo,
+    usd_value: Uint128,
+    min_usd: Uint128,
+) -> Result<CappedCommit, ContractError> {
+    let uncapped = |asset, usd_value| CappedCommit {
+        asset,
+        usd_value,
+        refund: Uint128::zero(),
+    };
+    let Some(cap) = commit_config.wallet_commit_cap() else {
+        return Ok(uncapped(asset, usd_value));
+    };
+    let committed = COMMIT_LEDGER
+        .may_load(storage, committer)?
+        .unwrap_or_default();
+    let allowance = cap.saturating_sub(committed);
+    let usd_to_threshold = commit_config
+        .commit_amount_for_threshold_usd
+        .saturating_sub(USD_RAISED_FROM_COMMIT.load(storage)?);
+    if usd_value.min(usd_to_threshold) <= allowance {
+        return Ok(uncapped(asset, usd_value));
+    }
+
+    let accepted = asset.amount.multiply_ratio(allowance, usd_value);
+    let accepted_usd = usd_value.multiply_ratio(accepted, asset.amount);
+    if accepted.is_zero() || accepted_usd.is_zero() || accepted_usd < min_usd {
+        return Err(ContractError::CommitCapReached { cap });
+    }
+    let refund = asset.amount.checked_sub(accepted)?;
+    Ok(CappedCommit {
+        asset: TokenInfo {
+            info: asset.info,
+            amount: accepted,
+        },
+        usd_value: accepted_usd,
+        refund,
+    })
+}
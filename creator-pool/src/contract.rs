@@ -7,10 +7,20 @@ use crate::admin::{
     execute_claim_failed_distribution, execute_emergency_withdraw, execute_pause,
     execute_recover_stuck_states, execute_self_recover_distribution,
     execute_sweep_unclaimed_emergency_shares, execute_unpause,
-    execute_update_config_from_factory,
+    execute_update_config_from_factory, execute_collect_protocol_fees,
+    execute_set_protocol_fee_share, save_protocol_fee_share,
 };
 use crate::asset::{PoolPairType, TokenInfoPoolExt, TokenType};
-use crate::commit::{commit, execute_continue_distribution};
+use crate::commit::{
+    commit, execute_accept_creator_wallet, execute_apply_revenue_split,
+    execute_cancel_revenue_split, execute_cancel_subscription, execute_claim_commit_refund,
+    execute_claim_commit_reward, execute_claim_referral_rewards,
+    execute_claim_vested_creator_reward, execute_continue_distribution,
+    execute_propose_creator_wallet, execute_propose_revenue_split, execute_reclaim_sponsorship,
+    execute_renew_subscription, execute_set_commit_allowlist, execute_set_subscription_tiers,
+    execute_sponsor, execute_subscribe, execute_update_token_marketing, execute_veto_creator_wallet,
+    CommitOptions,
+};
 use crate::error::ContractError;
 use crate::generic_helpers::validate_pool_threshold_payments;
 use crate::liquidity::{
@@ -22,15 +32,17 @@ use crate::liquidity_helpers::{execute_claim_creator_excess, execute_claim_creat
 use crate::msg::{ExecuteMsg, MigrateMsg, PoolInstantiateMsg};
 use crate::query::query_check_commit;
 use crate::state::{
-    CommitLimitInfo, DEFAULT_LP_FEE, DEFAULT_SWAP_RATE_LIMIT_SECS, ExpectedFactory, MAX_LP_FEE,
-    MIN_LP_FEE, OracleInfo, PoolAnalytics,
+    CommitLimitInfo, EarlySupporterWeighting, ExpectedFactory, OracleInfo, PoolAnalytics,
     PoolDetails, PoolFeeState, PoolInfo, PoolSpecs, PoolState, Position, ThresholdPayoutAmounts,
-    COMMITFEEINFO, COMMIT_LIMIT_INFO, EXPECTED_FACTORY, IS_THRESHOLD_HIT, LIQUIDITY_POSITIONS,
-    DEPOSIT_VERIFY_REPLY_ID, FAILED_MINTS, NATIVE_RAISED_FROM_COMMIT, NEXT_POSITION_ID,
-    ORACLE_INFO, OWNER_POSITIONS, PENDING_FACTORY_NOTIFY, PENDING_MINT_REPLIES, POOL_ANALYTICS,
-    POOL_FEE_STATE, POOL_INFO, POOL_PAUSED, POOL_SPECS, POOL_STATE,
-    REPLY_ID_DISTRIBUTION_MINT_BASE, REPLY_ID_FACTORY_NOTIFY_INITIAL,
-    REPLY_ID_FACTORY_NOTIFY_RETRY, THRESHOLD_PAYOUT_AMOUNTS, USD_RAISED_FROM_COMMIT,
+    COMMITFEEINFO, COMMIT_LIMIT_INFO, CREATOR_VESTING_CONFIG, CREATOR_WALLET_VETO_SECONDS,
+    DEFAULT_LP_FEE, DEFAULT_SWAP_RATE_LIMIT_SECS, DEPOSIT_VERIFY_REPLY_ID, DISTRIBUTION_MODE,
+    EARLY_SUPPORTER_WEIGHTING, EXPECTED_FACTORY, FAILED_MINTS, GROSS_RAISED_FROM_COMMIT,
+    IS_THRESHOLD_HIT, LAUNCH_PROTECTION_CONFIG, LIQUIDITY_POSITIONS, MAX_LP_FEE, MIN_LP_FEE,
+    NATIVE_RAISED_FROM_COMMIT, NEXT_POSITION_ID, ORACLE_INFO, OWNER_POSITIONS,
+    PENDING_FACTORY_NOTIFY, PENDING_MINT_REPLIES, POOL_ANALYTICS, POOL_FEE_STATE, POOL_INFO,
+    POOL_PAUSED, POOL_SPECS, POOL_STATE, RAISE_DEADLINE, REPLY_ID_DISTRIBUTION_MINT_BASE,
+    REPLY_ID_FACTORY_NOTIFY_INITIAL, REPLY_ID_FACTORY_NOTIFY_RETRY, THRESHOLD_PAYOUT_AMOUNTS,
+    USD_RAISED_FROM_COMMIT,
 };
 // Swap orchestration moved to pool_core::swap; re-exported via swap_helper.
 use crate::swap_helper::{execute_swap_cw20, simple_swap};
@@ -164,6 +176,8 @@ pub fn instantiate(
         creator_excess_liquidity_lock_days: msg.creator_excess_liquidity_lock_days,
         min_commit_usd_pre_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_PRE_THRESHOLD,
         min_commit_usd_post_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_POST_THRESHOLD,
+        max_commit_usd_per_wallet: None,
+        max_commit_share_per_wallet: None,
     };
 
     let oracle_info = OracleInfo {
@@ -193,6 +207,10 @@ pub fn instantiate(
     USD_RAISED_FROM_COMMIT.save(deps.storage, &Uint128::zero())?;
     COMMITFEEINFO.save(deps.storage, &msg.commit_fee_info)?;
     NATIVE_RAISED_FROM_COMMIT.save(deps.storage, &Uint128::zero())?;
+    GROSS_RAISED_FROM_COMMIT.save(deps.storage, &Uint128::zero())?;
+    if let Some(deadline) = msg.raise_deadline {
+        RAISE_DEADLINE.save(deps.storage, &deadline)?;
+    }
     // Creator pools start pre-threshold — swap / liquidity entry points
     // gate on this until `process_threshold_crossing_with_excess` flips
     // it to `true` during the threshold-crossing commit.
@@ -210,6 +228,27 @@ pub fn instantiate(
     POOL_SPECS.save(deps.storage, &pool_specs)?;
     THRESHOLD_PAYOUT_AMOUNTS.save(deps.storage, &threshold_payout_amounts)?;
     COMMIT_LIMIT_INFO.save(deps.storage, &commit_config)?;
+    LAUNCH_PROTECTION_CONFIG.save(deps.storage, &msg.launch_protection)?;
+    if msg.creator_vesting.is_enabled() {
+        CREATOR_VESTING_CONFIG.save(deps.storage, &msg.creator_vesting)?;
+    }
+    if msg.creator_wallet_veto_seconds > 0 {
+        CREATOR_WALLET_VETO_SECONDS.save(deps.storage, &msg.creator_wallet_veto_seconds)?;
+    }
+    DISTRIBUTION_MODE.save(deps.storage, &msg.distribution_mode)?;
+    if let Some(allowlist) = &msg.commit_allowlist {
+        crate::commit::allowlist::save_commit_allowlist(deps.storage, deps.api, allowlist)?;
+    }
+    if let Some(curve) = msg.early_supporter_curve.clone() {
+        EARLY_SUPPORTER_WEIGHTING.save(
+            deps.storage,
+            &EarlySupporterWeighting {
+                curve,
+                starts_at: env.block.time,
+            },
+        )?;
+    }
+    save_protocol_fee_share(deps.storage, msg.protocol_fee_share)?;
     LIQUIDITY_POSITIONS.save(deps.storage, "0", &liquidity_position)?;
     OWNER_POSITIONS.save(deps.storage, (&env.contract.address, "0"), &true)?;
     ORACLE_INFO.save(deps.storage, &oracle_info)?;
@@ -328,6 +367,10 @@ pub fn execute(
         ExecuteMsg::RecoverStuckStates { recovery_type } => {
             execute_recover_stuck_states(deps, env, info, recovery_type)
         }
+        ExecuteMsg::SetProtocolFeeShare { share } => {
+            execute_set_protocol_fee_share(deps, env, info, share)
+        }
+        ExecuteMsg::CollectProtocolFees {} => execute_collect_protocol_fees(deps, env, info),
 
         // --- Commit & Distribution (commit-pool only) ---
         ExecuteMsg::Commit {
@@ -335,6 +378,10 @@ pub fn execute(
             transaction_deadline,
             belief_price,
             max_spread,
+            tier,
+            beneficiary,
+            referrer,
+            allowlist_proof,
         } => {
             // Block ALL commits while paused — pre-threshold AND post-threshold.
             // Previously only process_post_threshold_commit checked POOL_PAUSED,
@@ -346,14 +393,81 @@ pub fn execute(
                 env,
                 info,
                 asset,
-                transaction_deadline,
-                belief_price,
-                max_spread,
+                CommitOptions {
+                    transaction_deadline,
+                    belief_price,
+                    max_spread,
+                    tier,
+                    beneficiary,
+                    referrer,
+                    allowlist_proof,
+                },
             )
         }
         ExecuteMsg::ContinueDistribution {} => {
             execute_continue_distribution(deps, env, info)
         }
+        // Not pause-gated: refunds are the committers' only exit from a
+        // failed raise, same reasoning as letting LPs remove during
+        // EmergencyPending.
+        ExecuteMsg::ClaimCommitRefund {} => execute_claim_commit_refund(deps, env, info),
+        // Not pause-gated either: referral balances sit outside reserves.
+        ExecuteMsg::ClaimReferralRewards {} => execute_claim_referral_rewards(deps, env, info),
+        ExecuteMsg::Subscribe {
+            usd_per_period,
+            period_seconds,
+            periods,
+            tier,
+        } => {
+            check_pool_not_paused(deps.storage)?;
+            execute_subscribe(deps, env, info, usd_per_period, period_seconds, periods, tier)
+        }
+        // Pause is enforced inside the commit path the renewal runs.
+        ExecuteMsg::RenewSubscription { wallet } => {
+            execute_renew_subscription(deps, env, info, wallet)
+        }
+        // Not pause-gated: returning escrow never touches the reserves.
+        ExecuteMsg::CancelSubscription {} => execute_cancel_subscription(deps, env, info),
+        ExecuteMsg::Sponsor {
+            match_ratio,
+            max_match_per_commit,
+            credit,
+            expires_at,
+        } => {
+            check_pool_not_paused(deps.storage)?;
+            execute_sponsor(
+                deps,
+                env,
+                info,
+                match_ratio,
+                max_match_per_commit,
+                credit,
+                expires_at,
+            )
+        }
+        // Not pause-gated: the escrow sits outside the reserves.
+        ExecuteMsg::ReclaimSponsorship {} => execute_reclaim_sponsorship(deps, env, info),
+        ExecuteMsg::ProposeRevenueSplit { recipients } => {
+            execute_propose_revenue_split(deps, env, info, recipients)
+        }
+        ExecuteMsg::ApplyRevenueSplit {} => execute_apply_revenue_split(deps, env, info),
+        ExecuteMsg::CancelRevenueSplit {} => execute_cancel_revenue_split(deps, env, info),
+        ExecuteMsg::ProposeCreatorWallet { new_wallet } => {
+            execute_propose_creator_wallet(deps, env, info, new_wallet)
+        }
+        ExecuteMsg::AcceptCreatorWallet {} => execute_accept_creator_wallet(deps, env, info),
+        ExecuteMsg::VetoCreatorWallet {} => execute_veto_creator_wallet(deps, env, info),
+        ExecuteMsg::UpdateTokenMarketing {
+            project,
+            description,
+            logo,
+        } => execute_update_token_marketing(deps, env, info, project, description, logo),
+        ExecuteMsg::SetSubscriptionTiers { creator, tiers } => {
+            execute_set_subscription_tiers(deps, env, info, creator, tiers)
+        }
+        ExecuteMsg::SetCommitAllowlist { creator, allowlist } => {
+            execute_set_commit_allowlist(deps, env, info, creator, allowlist)
+        }
 
         // --- Swap ---
         ExecuteMsg::SimpleSwap {
@@ -467,12 +581,22 @@ pub fn execute(
         ExecuteMsg::CollectFees {
             position_id,
             transaction_deadline,
+            output_asset,
+            min_output,
         } => {
             // Permitted during EmergencyPending so an LP about to remove
             // can sweep their share of fee_reserve before the drain
             //.
             check_pool_writable_for_remove(deps.storage)?;
-            execute_collect_fees(deps, env, info, position_id, transaction_deadline)
+            execute_collect_fees(
+                deps,
+                env,
+                info,
+                position_id,
+                transaction_deadline,
+                output_asset,
+                min_output,
+            )
         }
         ExecuteMsg::RemovePartialLiquidity {
             position_id,
@@ -560,6 +684,11 @@ pub fn execute(
             check_pool_writable(deps.storage)?;
             execute_claim_creator_fees(deps, env, info, transaction_deadline)
         }
+        ExecuteMsg::ClaimCommitReward {} => execute_claim_commit_reward(deps, env, info),
+        // Not pause-gated: the vesting balance sits outside reserves.
+        ExecuteMsg::ClaimVestedCreatorReward {} => {
+            execute_claim_vested_creator_reward(deps, env, info)
+        }
         ExecuteMsg::RetryFactoryNotify {} => {
             // Retries NotifyThresholdCrossed to the factory. Standard
             // pools never cross a threshold, so there's nothing to retry.
@@ -601,8 +730,10 @@ pub fn execute(
 /// min_commit_interval) but has no compile-time access to creator-pool
 /// state and so leaves `update.min_commit_usd_pre_threshold` and
 /// `update.min_commit_usd_post_threshold` untouched. This wrapper
-/// applies those two creator-pool-only floors to `COMMIT_LIMIT_INFO`
-/// first, then delegates the shared knobs to the inner handler.
+/// applies those two creator-pool-only floors and the per-wallet commit
+/// caps to `COMMIT_LIMIT_INFO` (and `referral_fee_share` to
+/// `REFERRAL_FEE_SHARE`) first, then
+/// delegates the shared knobs to the inner handler.
 ///
 /// Bounds re-enforced here (defense-in-depth — factory's
 /// `PoolConfigUpdate::validate()` already rejects out-of-range values
@@ -617,7 +748,7 @@ fn execute_update_creator_config_from_factory(
     info: MessageInfo,
     update: crate::msg::PoolConfigUpdate,
 ) -> Result<Response, ContractError> {
-    use crate::state::MAX_MIN_COMMIT_USD;
+    use crate::state::{MAX_MIN_COMMIT_USD, MAX_REFERRAL_FEE_SHARE, REFERRAL_FEE_SHARE};
 
     // Auth gate is duplicated from pool-core's handler so we don't load
     // and write COMMIT_LIMIT_INFO under an unauthorised caller. The
@@ -629,8 +760,10 @@ fn execute_update_creator_config_from_factory(
 
     let pre = update.min_commit_usd_pre_threshold;
     let post = update.min_commit_usd_post_threshold;
+    let wallet_usd_cap = update.max_commit_usd_per_wallet;
+    let wallet_share_cap = update.max_commit_share_per_wallet;
 
-    if pre.is_some() || post.is_some() {
+    if pre.is_some() || post.is_some() || wallet_usd_cap.is_some() || wallet_share_cap.is_some() {
         let mut commit_config = COMMIT_LIMIT_INFO.load(deps.storage)?;
         if let Some(v) = pre {
             if v.is_zero() || v > MAX_MIN_COMMIT_USD {
@@ -652,8 +785,40 @@ fn execute_update_creator_config_from_factory(
             }
             commit_config.min_commit_usd_post_threshold = v;
         }
+        // Zero lifts a cap. Checked after the floors so a combined
+        // update is validated against the new pre-threshold floor.
+        if let Some(v) = wallet_usd_cap {
+            if !v.is_zero() && v < commit_config.min_commit_usd_pre_threshold {
+                return Err(ContractError::InvalidCommitWalletCap {
+                    field: "max_commit_usd_per_wallet",
+                    reason: format!(
+                        "{} is below the pre-threshold minimum commit {}",
+                        v, commit_config.min_commit_usd_pre_threshold
+                    ),
+                });
+            }
+            commit_config.max_commit_usd_per_wallet = (!v.is_zero()).then_some(v);
+        }
+        if let Some(share) = wallet_share_cap {
+            if share > Decimal::one() {
+                return Err(ContractError::InvalidCommitWalletCap {
+                    field: "max_commit_share_per_wallet",
+                    reason: format!("{} exceeds 1", share),
+                });
+            }
+            commit_config.max_commit_share_per_wallet = (!share.is_zero()).then_some(share);
+        }
         COMMIT_LIMIT_INFO.save(deps.storage, &commit_config)?;
     }
+    if let Some(share) = update.referral_fee_share {
+        if share > MAX_REFERRAL_FEE_SHARE {
+            return Err(ContractError::InvalidReferralFeeShare {
+                got: share,
+                max: MAX_REFERRAL_FEE_SHARE,
+            });
+        }
+        REFERRAL_FEE_SHARE.save(deps.storage, &share)?;
+    }
 
     // Delegate shared knobs to the pool-core handler (which builds the
     // canonical response attributes).
@@ -717,7 +882,7 @@ fn execute_accept_nft_ownership(
 /// Re-sends `NotifyThresholdCrossed` to the factory when the initial
 /// notification (dispatched via `reply_on_error` during threshold-crossing
 /// commit) failed. This entrypoint is callable by ANYONE; the factory's
-/// POOL_THRESHOLD_MINTED idempotency check prevents a successful mint from
+/// threshold_crossed idempotency check prevents a successful mint from
 /// firing twice. Reply handling clears PENDING_FACTORY_NOTIFY on success.
 ///
 /// Why permissionless: recovery-path tx. If a factory misconfiguration or
@@ -4,6 +4,7 @@
 pub use pool_core::liquidity_helpers::*;
 
 use crate::asset::get_native_denom;
+use crate::commit::revenue_split::{is_revenue_claimer, split_creator_revenue};
 use crate::error::ContractError;
 use crate::state::{
     CreatorFeePot, COMMITFEEINFO, CREATOR_EXCESS_POSITION, CREATOR_FEE_POT, POOL_INFO,
@@ -13,7 +14,8 @@ use cosmwasm_std::{
 };
 
 /// Empties the CREATOR_FEE_POT to the creator wallet configured at pool
-/// instantiation. Only the creator wallet can call this. Clip-slice fees
+/// instantiation, or across its revenue split. Only the creator wallet
+/// or a split recipient can call this. Clip-slice fees
 /// accumulate in the pot via `execute_collect_fees`, `add_to_position`,
 /// `remove_all_liquidity`, and `remove_partial_liquidity`.
 pub fn execute_claim_creator_fees(
@@ -34,7 +36,7 @@ fn execute_claim_creator_fees_inner(
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
     let fee_info = COMMITFEEINFO.load(deps.storage)?;
-    if info.sender != fee_info.creator_wallet_address {
+    if !is_revenue_claimer(deps.storage, &fee_info.creator_wallet_address, &info.sender)? {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -46,25 +48,34 @@ fn execute_claim_creator_fees_inner(
     let pool_info = POOL_INFO.load(deps.storage)?;
     let mut messages: Vec<CosmosMsg> = vec![];
 
+    // Both sides are paid out along the creator's revenue split.
     if !pot.amount_0.is_zero() {
         let native_denom = get_native_denom(&pool_info.pool_info.asset_infos)?;
-        messages.push(CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
-            to_address: fee_info.creator_wallet_address.to_string(),
-            amount: vec![cosmwasm_std::Coin {
-                denom: native_denom,
-                amount: pot.amount_0,
-            }],
-        }));
+        for (recipient, share) in
+            split_creator_revenue(deps.storage, &fee_info.creator_wallet_address, pot.amount_0)?
+        {
+            messages.push(CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![cosmwasm_std::Coin {
+                    denom: native_denom.clone(),
+                    amount: share,
+                }],
+            }));
+        }
     }
     if !pot.amount_1.is_zero() {
-        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: pool_info.token_address.to_string(),
-            msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
-                recipient: fee_info.creator_wallet_address.to_string(),
-                amount: pot.amount_1,
-            })?,
-            funds: vec![],
-        }));
+        for (recipient, share) in
+            split_creator_revenue(deps.storage, &fee_info.creator_wallet_address, pot.amount_1)?
+        {
+            messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: pool_info.token_address.to_string(),
+                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount: share,
+                })?,
+                funds: vec![],
+            }));
+        }
     }
 
     // Reset the pot AFTER building the messages so a serialization error
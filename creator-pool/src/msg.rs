@@ -8,12 +8,16 @@
 //! Per-contract types — the ExecuteMsg / QueryMsg / MigrateMsg /
 //! PoolInstantiateMsg enums and the commit-only response types
 //! (FactoryNotifyStatusResponse, PoolCommitResponse, CommitterInfo,
-//! LastCommittedResponse) — stay here. Standard-pool (Step 4b) defines
+//! LastCommittedResponse, LaunchProtectionResponse) — stay here. Standard-pool (Step 4b) defines
 //! its own slimmer versions in its own `msg.rs`.
 pub use pool_core::msg::*;
 
 use crate::asset::{TokenInfo, TokenType};
-use crate::state::RecoveryType;
+use crate::state::{
+    CandleInterval, CommitRefundState, CreatorVesting, LaunchWindow, PendingCreatorWallet,
+    PendingRevenueSplit, RecoveryType, ReferralStats, RevenueRecipient, SponsorCredit, Sponsorship,
+    Subscription, VolumeGranularity,
+};
 // Schema-only refs: cited only by `#[returns(...)]` on QueryMsg
 // variants. The QueryResponses derive consumes them but rustc still
 // flags them as unused without this allow. Grouping them under one
@@ -27,8 +31,12 @@ use {
     pool_factory_interfaces::{AllPoolsResponse, PoolStateResponseForFactory},
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
-use cw20::Cw20ReceiveMsg;
+use cosmwasm_std::{Addr, Binary, Decimal, HexBinary, Timestamp, Uint128};
+use cw20::{Cw20ReceiveMsg, Logo};
+use pool_factory_interfaces::{
+    CommitAllowlist, CreatorVestingConfig, DistributionMode, EarlySupporterCurve,
+    LaunchProtectionConfig, SubscriptionTier,
+};
 
 #[cw_serde]
 pub enum ExecuteMsg {
@@ -49,6 +57,88 @@ pub enum ExecuteMsg {
         recovery_type: RecoveryType,
     },
     ContinueDistribution {},
+    /// Reclaims the sender's pro-rata net-of-fee bluechip from a raise
+    /// that missed its `raise_deadline` without crossing the threshold.
+    ClaimCommitRefund {},
+    /// Pays out the sender's unclaimed referral rewards.
+    ClaimReferralRewards {},
+    /// Escrows the attached bluechip and schedules `periods` commits of
+    /// `usd_per_period` each, `period_seconds` apart. The first period
+    /// is due immediately.
+    Subscribe {
+        usd_per_period: Uint128,
+        period_seconds: u64,
+        periods: u32,
+        /// Tier every renewal is tagged with; `usd_per_period` must
+        /// cover its price.
+        #[serde(default)]
+        tier: Option<String>,
+    },
+    /// Permissionless: runs `wallet`'s due renewal as a commit from its
+    /// escrow. A caller other than the subscriber is paid 0.5% of the
+    /// period's bluechip from the same escrow.
+    RenewSubscription {
+        wallet: String,
+    },
+    /// Ends the sender's subscription and returns the unspent escrow.
+    CancelSubscription {},
+    /// Escrows the attached bluechip to match pre-threshold commits at
+    /// `match_ratio`, at most `max_match_per_commit` each, until
+    /// `expires_at`. Calling again tops up the escrow and replaces the
+    /// terms.
+    Sponsor {
+        match_ratio: Decimal,
+        max_match_per_commit: Uint128,
+        credit: SponsorCredit,
+        expires_at: Timestamp,
+    },
+    /// Returns the sender's unmatched sponsorship escrow once it has
+    /// expired, the threshold was crossed, or the raise deadline passed.
+    ReclaimSponsorship {},
+    /// Creator-only. Stages a new revenue split (bps summing to 10,000;
+    /// empty restores the creator-only default), appliable after
+    /// `REVENUE_SPLIT_TIMELOCK_SECONDS`. Replaces any pending proposal.
+    ProposeRevenueSplit {
+        recipients: Vec<RevenueRecipient>,
+    },
+    /// Creator-only. Installs the pending revenue split once its
+    /// timelock has passed.
+    ApplyRevenueSplit {},
+    /// Creator-only. Drops the pending revenue split.
+    CancelRevenueSplit {},
+    /// Creator-only. Proposes handing the creator role to `new_wallet`,
+    /// which accepts after the factory's veto window.
+    ProposeCreatorWallet {
+        new_wallet: String,
+    },
+    /// Called by the proposed wallet to take over the creator role.
+    AcceptCreatorWallet {},
+    /// Factory-only. Drops the pending creator wallet change during its
+    /// veto window.
+    VetoCreatorWallet {},
+    /// Creator-only. Sets the creator token's project URL, description
+    /// and logo through the pool's CW20 marketing role. `None` leaves a
+    /// field as it is.
+    UpdateTokenMarketing {
+        #[serde(default)]
+        project: Option<String>,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(default)]
+        logo: Option<Logo>,
+    },
+    /// Factory-only. Replaces the pool's membership tiers; `creator` is
+    /// the factory caller and must match the pool's creator wallet.
+    SetSubscriptionTiers {
+        creator: String,
+        tiers: Vec<SubscriptionTier>,
+    },
+    /// Factory-only. Replaces the pool's allowlist phase; `creator` is
+    /// the factory caller and must match the pool's creator wallet.
+    SetCommitAllowlist {
+        creator: String,
+        allowlist: CommitAllowlist,
+    },
     Pause {},
     Unpause {},
     EmergencyWithdraw {},
@@ -57,6 +147,23 @@ pub enum ExecuteMsg {
         transaction_deadline: Option<Timestamp>,
         belief_price: Option<Decimal>,
         max_spread: Option<Decimal>,
+        /// Membership tier this commit pays for; see `SubscriptionTiers`.
+        #[serde(default)]
+        tier: Option<String>,
+        /// Wallet credited with the commit (ledger, distribution,
+        /// post-threshold swap output, membership). Defaults to the
+        /// sender, who pays and is rate-limited either way.
+        #[serde(default)]
+        beneficiary: Option<String>,
+        /// Promoter credited with the pool's referral share of the
+        /// creator fee. Must differ from the payer and beneficiary.
+        #[serde(default)]
+        referrer: Option<String>,
+        /// Merkle proof that the credited wallet is allowlisted, needed
+        /// only during the pool's allowlist phase when the wallet is
+        /// not listed on-chain. See `CommitAllowlist`.
+        #[serde(default)]
+        allowlist_proof: Option<Vec<HexBinary>>,
     },
     DepositLiquidity {
         amount0: Uint128,
@@ -78,6 +185,15 @@ pub enum ExecuteMsg {
         /// wire-compatible (the field deserializes as `None` when absent).
         #[serde(default)]
         transaction_deadline: Option<Timestamp>,
+        /// Pay all fees in this pair asset: the other side's fees are
+        /// swapped through the pool's reserves at the normal swap
+        /// commission before transfer. `None` pays both sides as-is.
+        #[serde(default)]
+        output_asset: Option<TokenType>,
+        /// Minimum total `output_asset` amount to receive; ignored
+        /// without `output_asset`.
+        #[serde(default)]
+        min_output: Option<Uint128>,
     },
     AddToPosition {
         position_id: String,
@@ -125,9 +241,16 @@ pub enum ExecuteMsg {
         #[serde(default)]
         transaction_deadline: Option<Timestamp>,
     },
+    // Mints the sender's share of the commit-return tranche on a pool
+    // created with `DistributionMode::Pull`. Once per committer.
+    ClaimCommitReward {},
+    // Transfers the vested-but-unclaimed part of the creator's threshold
+    // reward to the creator wallet. Creator-only; only pools created
+    // with factory `creator_vesting` enabled hold a schedule.
+    ClaimVestedCreatorReward {},
     // Re-sends NotifyThresholdCrossed to the factory when the initial
     // notification during threshold-crossing failed and PENDING_FACTORY_NOTIFY
-    // is set. Anyone can call: factory's POOL_THRESHOLD_MINTED idempotency
+    // is set. Anyone can call: factory's threshold_crossed idempotency
     // check gates double-mints, so at worst a stray caller burns gas on a
     // no-op. Clears the pending flag on successful reply.
     RetryFactoryNotify {},
@@ -202,6 +325,15 @@ pub enum ExecuteMsg {
     // Idempotent: returns Ok with no NFT message if the flag is already
     // set (e.g. the deposit-side fallback fired first in a test fixture).
     AcceptNftOwnership {},
+
+    // Factory-only protocol fee switch. `SetProtocolFeeShare` re-syncs
+    // the share of swap fees diverted to the protocol after a factory
+    // config update; `CollectProtocolFees` pays everything owed to the
+    // factory's bluechip wallet.
+    SetProtocolFeeShare {
+        share: Decimal,
+    },
+    CollectProtocolFees {},
 }
 
 #[cw_serde]
@@ -270,6 +402,24 @@ pub enum QueryMsg {
     PoolInfo {},
     #[returns(PoolAnalyticsResponse)]
     Analytics {},
+    // Recent hourly or daily volume + fee buckets, newest first.
+    // `limit` defaults to (and is capped at) the ring's capacity.
+    #[returns(VolumeHistoryResponse)]
+    VolumeHistory {
+        granularity: VolumeGranularity,
+        limit: Option<u32>,
+    },
+    // Protocol fee share plus owed / lifetime protocol fee totals.
+    #[returns(ProtocolFeesResponse)]
+    ProtocolFees {},
+    // OHLC candles for `interval`, oldest first. `start_after` is an
+    // exclusive candle start time for pagination.
+    #[returns(CandlesResponse)]
+    Candles {
+        interval: CandleInterval,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     #[returns(PoolStateResponseForFactory)]
     GetPoolState {},
     #[returns(AllPoolsResponse)]
@@ -289,6 +439,228 @@ pub enum QueryMsg {
     // elapsed since the last batch advanced).
     #[returns(Option<DistributionStateResponse>)]
     DistributionState {},
+    // Post-threshold launch-window limits: the factory-supplied config,
+    // the armed window (once the threshold has crossed), the largest
+    // offer currently accepted on each side and, when `wallet` is set,
+    // how much of the per-wallet buy cap that wallet has used.
+    #[returns(LaunchProtectionResponse)]
+    LaunchProtection { wallet: Option<String> },
+    // Failed-raise refund status: the raise deadline, whether refunds
+    // are open, the refund totals and, when `wallet` is set, what that
+    // wallet can still claim.
+    #[returns(CommitRefundResponse)]
+    CommitRefund { wallet: Option<String> },
+    // `wallet`'s subscription, if any, and when its next renewal is due.
+    #[returns(SubscriptionStatusResponse)]
+    SubscriptionStatus { wallet: String },
+    // The creator's registered membership tiers.
+    #[returns(SubscriptionTiersResponse)]
+    SubscriptionTiers {},
+    // `wallet`'s membership tier and expiry.
+    #[returns(ActiveMembershipResponse)]
+    ActiveMembership { wallet: String },
+    // `referrer`'s referral totals and the pool's current referral share.
+    #[returns(ReferralStatsResponse)]
+    ReferralStats { referrer: String },
+    // The creator-reward vesting settings and, once the threshold has
+    // crossed, the live schedule.
+    #[returns(CreatorVestingResponse)]
+    CreatorVesting {},
+    // The pool's distribution mode and `wallet`'s unclaimed pull-mode
+    // commit reward.
+    #[returns(CommitRewardResponse)]
+    CommitReward { wallet: String },
+    // The early-supporter curve, if any, and the multiplier a commit
+    // landing now would receive.
+    #[returns(EarlySupporterWeightingResponse)]
+    EarlySupporterWeighting {},
+    // The allowlist phase, if any, and whether `wallet` is listed
+    // on-chain. Merkle membership can't be checked without a proof.
+    #[returns(CommitAllowlistResponse)]
+    CommitAllowlist { wallet: Option<String> },
+    // Every sponsorship, live or not, in sponsor address order.
+    #[returns(SponsorshipsResponse)]
+    Sponsorships {},
+    // The active creator revenue split and any pending proposal.
+    #[returns(RevenueSplitResponse)]
+    RevenueSplit {},
+    // The current creator wallet and any pending change.
+    #[returns(CreatorWalletResponse)]
+    CreatorWallet {},
+    // Previews a commit of `asset` credited to `wallet` (a wallet with
+    // no commits yet when unset) without changing state.
+    #[returns(SimulateCommitResponse)]
+    SimulateCommit {
+        asset: TokenInfo,
+        #[serde(default)]
+        wallet: Option<String>,
+    },
+}
+
+#[cw_serde]
+pub enum CommitPhase {
+    PreThreshold,
+    ThresholdCrossing,
+    PostThreshold,
+}
+
+#[cw_serde]
+pub struct SimulateCommitResponse {
+    pub phase: CommitPhase,
+    /// USD value of the accepted amount.
+    pub usd_value: Uint128,
+    pub oracle_rate: Uint128,
+    /// Bluechip the commit would use after the wallet cap.
+    pub accepted_amount: Uint128,
+    /// Bluechip over the wallet cap, returned to the payer.
+    pub wallet_cap_refund: Uint128,
+    pub bluechip_fee: Uint128,
+    pub creator_fee: Uint128,
+    pub amount_after_fees: Uint128,
+    /// USD credited to the commit ledger; zero post-threshold.
+    pub ledger_usd: Uint128,
+    /// Projected share of `commit_return_amount`. Exact for a crossing
+    /// commit; pre-threshold it assumes the rest of the raise lands at
+    /// the current early-supporter multiplier.
+    pub projected_commit_reward: Uint128,
+    /// Bluechip swapped: the whole commit post-threshold, the capped
+    /// excess on a crossing.
+    pub swap_offer_amount: Uint128,
+    pub swap_return_amount: Uint128,
+    pub swap_spread_amount: Uint128,
+    pub swap_commission_amount: Uint128,
+    /// Crossing excess above the 3% swap cap, returned to the payer.
+    pub excess_refund: Uint128,
+}
+
+#[cw_serde]
+pub struct CreatorWalletResponse {
+    pub creator_wallet: Addr,
+    pub pending: Option<PendingCreatorWallet>,
+    pub veto_seconds: u64,
+}
+
+#[cw_serde]
+pub struct RevenueSplitResponse {
+    /// Empty when every creator payout goes to the creator wallet.
+    pub recipients: Vec<RevenueRecipient>,
+    pub pending: Option<PendingRevenueSplit>,
+}
+
+#[cw_serde]
+pub struct SponsorshipEntry {
+    pub sponsor: Addr,
+    pub sponsorship: Sponsorship,
+    /// Whether `ReclaimSponsorship` would succeed now.
+    pub reclaimable: bool,
+}
+
+#[cw_serde]
+pub struct SponsorshipsResponse {
+    pub sponsorships: Vec<SponsorshipEntry>,
+}
+
+#[cw_serde]
+pub struct CommitAllowlistResponse {
+    pub merkle_root: Option<HexBinary>,
+    pub public_at: Option<Timestamp>,
+    pub onchain_wallet_count: u32,
+    /// True once `public_at` has passed, or when there is no phase.
+    pub is_public: bool,
+    pub wallet_listed: Option<bool>,
+}
+
+#[cw_serde]
+pub struct EarlySupporterWeightingResponse {
+    /// `None` on pools created without a curve; every commit then
+    /// weighs 1x.
+    pub curve: Option<EarlySupporterCurve>,
+    pub starts_at: Option<Timestamp>,
+    pub current_multiplier: Decimal,
+    /// Sum of weighted committed USD so far; the share denominator
+    /// once the threshold crosses.
+    pub total_weighted_commit_usd: Uint128,
+}
+
+#[cw_serde]
+pub struct CommitRewardResponse {
+    pub wallet: Addr,
+    pub distribution_mode: DistributionMode,
+    /// Zero for push pools, before the threshold crosses, and once
+    /// claimed.
+    pub claimable: Uint128,
+}
+
+#[cw_serde]
+pub struct CreatorVestingResponse {
+    /// `None` when the pool was created with vesting disabled.
+    pub config: Option<CreatorVestingConfig>,
+    /// `None` until the threshold crosses.
+    pub schedule: Option<CreatorVesting>,
+    pub vested: Uint128,
+    pub claimable: Uint128,
+}
+
+#[cw_serde]
+pub struct ReferralStatsResponse {
+    pub referrer: Addr,
+    pub stats: ReferralStats,
+    /// Share of the creator fee referrers currently earn.
+    pub referral_fee_share: Decimal,
+}
+
+#[cw_serde]
+pub struct SubscriptionTiersResponse {
+    pub tiers: Vec<SubscriptionTier>,
+}
+
+#[cw_serde]
+pub struct ActiveMembershipResponse {
+    pub wallet: Addr,
+    /// Last tier bought, even if expired. `None` if never a member.
+    pub tier: Option<String>,
+    pub expires_at: Option<Timestamp>,
+    /// True while `expires_at` is in the future.
+    pub active: bool,
+}
+
+#[cw_serde]
+pub struct SubscriptionStatusResponse {
+    pub wallet: Addr,
+    pub subscription: Option<Subscription>,
+    /// Earliest time the next renewal can run. `None` without a
+    /// subscription.
+    pub next_due: Option<Timestamp>,
+    /// True when a keeper could renew right now.
+    pub is_due: bool,
+}
+
+#[cw_serde]
+pub struct CommitRefundResponse {
+    pub raise_deadline: Option<Timestamp>,
+    /// True once the deadline passed without the threshold crossing.
+    pub refunds_open: bool,
+    /// Refund totals; before the first claim this is the snapshot the
+    /// first claim will take. `None` while refunds are closed.
+    pub state: Option<CommitRefundState>,
+    /// Bluechip `wallet` can claim now. `None` when no wallet was
+    /// supplied.
+    pub wallet_refund: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct LaunchProtectionResponse {
+    pub config: LaunchProtectionConfig,
+    pub window: Option<LaunchWindow>,
+    /// True while `window` is armed and `ends_at_block` hasn't passed.
+    pub active: bool,
+    /// Largest offer accepted right now on each side; `None` when no
+    /// size cap applies.
+    pub max_offer_0: Option<Uint128>,
+    pub max_offer_1: Option<Uint128>,
+    /// Creator tokens `wallet` has bought inside the window. `None`
+    /// when no wallet was supplied.
+    pub wallet_bought: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -345,6 +717,34 @@ pub struct PoolInstantiateMsg {
     pub token_address: Addr,
     pub max_bluechip_lock_per_pool: Uint128,
     pub creator_excess_liquidity_lock_days: u64,
+    /// Post-threshold launch limits from the factory config. Defaults
+    /// to disabled when an older factory omits the field.
+    #[serde(default)]
+    pub launch_protection: LaunchProtectionConfig,
+    /// Protocol share of swap fees (`ProtocolFeeConfig.commit_pool_share`).
+    #[serde(default)]
+    pub protocol_fee_share: Decimal,
+    /// Optional commit-phase deadline (`CreatePool.raise_deadline`);
+    /// past it an uncrossed pool refunds committers instead.
+    #[serde(default)]
+    pub raise_deadline: Option<Timestamp>,
+    /// Creator-reward vesting from the factory config. Defaults to
+    /// disabled (reward minted at crossing) for older factories.
+    #[serde(default)]
+    pub creator_vesting: CreatorVestingConfig,
+    /// Factory `creator_wallet_veto_seconds`; zero (no veto window) for
+    /// older factories.
+    #[serde(default)]
+    pub creator_wallet_veto_seconds: u64,
+    /// `CreatePool.distribution_mode`; older factories get `Push`.
+    #[serde(default)]
+    pub distribution_mode: DistributionMode,
+    /// `CreatePool.early_supporter_curve`, validated by the factory.
+    #[serde(default)]
+    pub early_supporter_curve: Option<EarlySupporterCurve>,
+    /// `CreatePool.commit_allowlist`, validated by the factory.
+    #[serde(default)]
+    pub commit_allowlist: Option<CommitAllowlist>,
 }
 
 #[cw_serde]
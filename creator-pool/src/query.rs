@@ -9,17 +9,32 @@
 //! zeroes directly into `query_analytics_core`.
 pub use pool_core::query::*;
 
+use crate::commit::refund::{pending_refund_state, refund_owed};
+use crate::commit::pull_distribution::commit_reward_owed;
+use crate::commit::early_supporter::current_multiplier;
+use crate::commit::simulate::simulate_commit;
+use crate::commit::sponsorship::is_reclaimable;
 use crate::msg::{
-    CommitStatus, CommitterInfo, DistributionStateResponse, FactoryNotifyStatusResponse,
-    LastCommittedResponse, PoolAnalyticsResponse, PoolCommitResponse, QueryMsg,
+    ActiveMembershipResponse, CommitAllowlistResponse, CommitRefundResponse, CommitRewardResponse,
+    CommitStatus, CommitterInfo, CreatorVestingResponse, CreatorWalletResponse,
+    DistributionStateResponse, EarlySupporterWeightingResponse, FactoryNotifyStatusResponse,
+    LastCommittedResponse, LaunchProtectionResponse, PoolAnalyticsResponse, PoolCommitResponse,
+    PoolInfoResponse, QueryMsg, ReferralStatsResponse, RevenueSplitResponse, SponsorshipEntry,
+    SponsorshipsResponse, SubscriptionStatusResponse, SubscriptionTiersResponse,
 };
 use crate::state::{
-    COMMIT_INFO, COMMIT_LIMIT_INFO, DISTRIBUTION_STALL_TIMEOUT_SECONDS, DISTRIBUTION_STATE,
-    IS_THRESHOLD_HIT, NATIVE_RAISED_FROM_COMMIT, PENDING_FACTORY_NOTIFY,
-    POOL_COMMITS_QUERY_DEFAULT_LIMIT, POOL_COMMITS_QUERY_MAX_LIMIT, USD_RAISED_FROM_COMMIT,
+    ALLOWLISTED_WALLETS, COMMITFEEINFO, COMMIT_ALLOWLIST, COMMIT_INFO, COMMIT_LIMIT_INFO,
+    CREATOR_VESTING, CREATOR_VESTING_CONFIG, CREATOR_WALLET_VETO_SECONDS, DISTRIBUTION_MODE,
+    DISTRIBUTION_STALL_TIMEOUT_SECONDS, DISTRIBUTION_STATE, EARLY_SUPPORTER_WEIGHTING,
+    IS_THRESHOLD_HIT, LAUNCH_PROTECTION_CONFIG, LAUNCH_WALLET_BUYS, LAUNCH_WINDOW, MEMBERSHIPS,
+    NATIVE_RAISED_FROM_COMMIT, PENDING_CREATOR_WALLET, PENDING_FACTORY_NOTIFY,
+    PENDING_REVENUE_SPLIT, POOL_COMMITS_QUERY_DEFAULT_LIMIT, POOL_COMMITS_QUERY_MAX_LIMIT,
+    POOL_STATE, PULL_DISTRIBUTION, RAISE_DEADLINE, REFERRAL_FEE_SHARE, REFERRAL_STATS,
+    REVENUE_SPLIT, SPONSORSHIPS, SUBSCRIPTIONS, SUBSCRIPTION_TIERS, TOTAL_WEIGHTED_COMMIT_USD,
+    USD_RAISED_FROM_COMMIT,
 };
 use cosmwasm_std::{
-    entry_point, to_json_binary, Addr, Binary, Deps, Env, Order, StdResult, Uint128,
+    entry_point, to_json_binary, Addr, Binary, Deps, Env, Order, StdError, StdResult, Uint128,
 };
 use cw_storage_plus::Bound;
 use pool_factory_interfaces::PoolQueryMsg;
@@ -42,10 +57,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::PoolInfo {} => to_json_binary(&query_pool_info(deps)?),
         QueryMsg::Pair {} => to_json_binary(&query_pair_info(deps)?),
         QueryMsg::Simulation { offer_asset } => {
-            to_json_binary(&query_simulation(deps, offer_asset)?)
+            to_json_binary(&query_simulation(deps, env, offer_asset)?)
         }
         QueryMsg::ReverseSimulation { ask_asset } => {
-            to_json_binary(&query_reverse_simulation(deps, ask_asset)?)
+            to_json_binary(&query_reverse_simulation(deps, env, ask_asset)?)
         }
         QueryMsg::CumulativePrices {} => to_json_binary(&query_cumulative_prices(deps, env)?),
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
@@ -97,12 +112,275 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         )?),
         QueryMsg::FactoryNotifyStatus {} => to_json_binary(&query_factory_notify_status(deps)?),
         QueryMsg::DistributionState {} => to_json_binary(&query_distribution_state(deps, &env)?),
+        QueryMsg::LaunchProtection { wallet } => {
+            to_json_binary(&query_launch_protection(deps, &env, wallet)?)
+        }
+        QueryMsg::CommitRefund { wallet } => {
+            to_json_binary(&query_commit_refund(deps, &env, wallet)?)
+        }
+        QueryMsg::SubscriptionStatus { wallet } => {
+            to_json_binary(&query_subscription_status(deps, &env, wallet)?)
+        }
+        QueryMsg::SubscriptionTiers {} => to_json_binary(&query_subscription_tiers(deps)?),
+        QueryMsg::CommitReward { wallet } => to_json_binary(&query_commit_reward(deps, wallet)?),
+        QueryMsg::CreatorVesting {} => to_json_binary(&query_creator_vesting(deps, env)?),
+        QueryMsg::CommitAllowlist { wallet } => {
+            to_json_binary(&query_commit_allowlist(deps, env, wallet)?)
+        }
+        QueryMsg::CreatorWallet {} => to_json_binary(&query_creator_wallet(deps)?),
+        QueryMsg::RevenueSplit {} => to_json_binary(&query_revenue_split(deps)?),
+        QueryMsg::SimulateCommit { asset, wallet } => {
+            let wallet = wallet.map(|w| deps.api.addr_validate(&w)).transpose()?;
+            to_json_binary(
+                &simulate_commit(deps, &env, asset, wallet)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?,
+            )
+        }
+        QueryMsg::Sponsorships {} => to_json_binary(&query_sponsorships(deps, &env)?),
+        QueryMsg::EarlySupporterWeighting {} => {
+            to_json_binary(&query_early_supporter_weighting(deps, env)?)
+        }
+        QueryMsg::ReferralStats { referrer } => {
+            to_json_binary(&query_referral_stats(deps, referrer)?)
+        }
+        QueryMsg::ActiveMembership { wallet } => {
+            to_json_binary(&query_active_membership(deps, &env, wallet)?)
+        }
+        QueryMsg::VolumeHistory { granularity, limit } => {
+            to_json_binary(&query_volume_history(deps, env, granularity, limit)?)
+        }
+        QueryMsg::Candles {
+            interval,
+            start_after,
+            limit,
+        } => to_json_binary(&query_candles(deps, interval, start_after, limit)?),
+        QueryMsg::ProtocolFees {} => to_json_binary(&query_protocol_fees(deps)?),
 
         // Hybrid — wrapper computes creator-only pieces, pool-core assembles
-        QueryMsg::Analytics {} => to_json_binary(&query_analytics(deps)?),
+        QueryMsg::Analytics {} => to_json_binary(&query_analytics(deps, env)?),
     }
 }
 
+pub fn query_commit_reward(deps: Deps, wallet: String) -> StdResult<CommitRewardResponse> {
+    let wallet = deps.api.addr_validate(&wallet)?;
+    let claimable = match PULL_DISTRIBUTION.may_load(deps.storage)? {
+        Some(state) => commit_reward_owed(deps.storage, &state, &wallet)?,
+        None => Uint128::zero(),
+    };
+    Ok(CommitRewardResponse {
+        distribution_mode: DISTRIBUTION_MODE.may_load(deps.storage)?.unwrap_or_default(),
+        wallet,
+        claimable,
+    })
+}
+
+pub fn query_commit_allowlist(
+    deps: Deps,
+    env: Env,
+    wallet: Option<String>,
+) -> StdResult<CommitAllowlistResponse> {
+    let phase = COMMIT_ALLOWLIST.may_load(deps.storage)?;
+    let wallet_listed = wallet
+        .map(|w| -> StdResult<bool> {
+            Ok(ALLOWLISTED_WALLETS.has(deps.storage, &deps.api.addr_validate(&w)?))
+        })
+        .transpose()?;
+    Ok(CommitAllowlistResponse {
+        is_public: !phase.as_ref().is_some_and(|p| p.is_active(env.block.time)),
+        onchain_wallet_count: phase.as_ref().map_or(0, |p| p.onchain_wallet_count),
+        public_at: phase.as_ref().map(|p| p.public_at),
+        merkle_root: phase.and_then(|p| p.merkle_root),
+        wallet_listed,
+    })
+}
+
+pub fn query_creator_wallet(deps: Deps) -> StdResult<CreatorWalletResponse> {
+    Ok(CreatorWalletResponse {
+        creator_wallet: COMMITFEEINFO.load(deps.storage)?.creator_wallet_address,
+        pending: PENDING_CREATOR_WALLET.may_load(deps.storage)?,
+        veto_seconds: CREATOR_WALLET_VETO_SECONDS
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    })
+}
+
+pub fn query_revenue_split(deps: Deps) -> StdResult<RevenueSplitResponse> {
+    Ok(RevenueSplitResponse {
+        recipients: REVENUE_SPLIT.may_load(deps.storage)?.unwrap_or_default(),
+        pending: PENDING_REVENUE_SPLIT.may_load(deps.storage)?,
+    })
+}
+
+pub fn query_sponsorships(deps: Deps, env: &Env) -> StdResult<SponsorshipsResponse> {
+    let sponsorships = SPONSORSHIPS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (sponsor, sponsorship) = item?;
+            let reclaimable = is_reclaimable(deps.storage, env, &sponsorship)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            Ok(SponsorshipEntry {
+                sponsor,
+                sponsorship,
+                reclaimable,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(SponsorshipsResponse { sponsorships })
+}
+
+pub fn query_early_supporter_weighting(
+    deps: Deps,
+    env: Env,
+) -> StdResult<EarlySupporterWeightingResponse> {
+    let weighting = EARLY_SUPPORTER_WEIGHTING.may_load(deps.storage)?;
+    Ok(EarlySupporterWeightingResponse {
+        current_multiplier: current_multiplier(deps.storage, &env)?,
+        starts_at: weighting.as_ref().map(|w| w.starts_at),
+        curve: weighting.map(|w| w.curve),
+        total_weighted_commit_usd: TOTAL_WEIGHTED_COMMIT_USD
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    })
+}
+
+pub fn query_creator_vesting(deps: Deps, env: Env) -> StdResult<CreatorVestingResponse> {
+    let schedule = CREATOR_VESTING.may_load(deps.storage)?;
+    let (vested, claimable) = schedule
+        .as_ref()
+        .map(|s| (s.vested_at(env.block.time), s.claimable_at(env.block.time)))
+        .unwrap_or_default();
+    Ok(CreatorVestingResponse {
+        config: CREATOR_VESTING_CONFIG.may_load(deps.storage)?,
+        schedule,
+        vested,
+        claimable,
+    })
+}
+
+pub fn query_referral_stats(deps: Deps, referrer: String) -> StdResult<ReferralStatsResponse> {
+    let referrer = deps.api.addr_validate(&referrer)?;
+    Ok(ReferralStatsResponse {
+        stats: REFERRAL_STATS
+            .may_load(deps.storage, &referrer)?
+            .unwrap_or_default(),
+        referral_fee_share: REFERRAL_FEE_SHARE
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+        referrer,
+    })
+}
+
+pub fn query_subscription_tiers(deps: Deps) -> StdResult<SubscriptionTiersResponse> {
+    let tiers = SUBSCRIPTION_TIERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, tier)| tier))
+        .collect::<StdResult<_>>()?;
+    Ok(SubscriptionTiersResponse { tiers })
+}
+
+pub fn query_active_membership(
+    deps: Deps,
+    env: &Env,
+    wallet: String,
+) -> StdResult<ActiveMembershipResponse> {
+    let wallet = deps.api.addr_validate(&wallet)?;
+    let membership = MEMBERSHIPS.may_load(deps.storage, &wallet)?;
+    let active = membership
+        .as_ref()
+        .is_some_and(|m| m.expires_at > env.block.time);
+    let (tier, expires_at) = match membership {
+        Some(m) => (Some(m.tier), Some(m.expires_at)),
+        None => (None, None),
+    };
+    Ok(ActiveMembershipResponse {
+        wallet,
+        tier,
+        expires_at,
+        active,
+    })
+}
+
+pub fn query_subscription_status(
+    deps: Deps,
+    env: &Env,
+    wallet: String,
+) -> StdResult<SubscriptionStatusResponse> {
+    let wallet = deps.api.addr_validate(&wallet)?;
+    let subscription = SUBSCRIPTIONS.may_load(deps.storage, &wallet)?;
+    let next_due = subscription.as_ref().map(|s| s.next_due);
+    Ok(SubscriptionStatusResponse {
+        wallet,
+        is_due: next_due.is_some_and(|due| env.block.time >= due),
+        next_due,
+        subscription,
+    })
+}
+
+pub fn query_commit_refund(
+    deps: Deps,
+    env: &Env,
+    wallet: Option<String>,
+) -> StdResult<CommitRefundResponse> {
+    let state = pending_refund_state(deps.storage, env)?;
+    let wallet_refund = match wallet {
+        Some(wallet) => {
+            let addr = deps.api.addr_validate(&wallet)?;
+            Some(match &state {
+                Some(state) => refund_owed(deps.storage, state, &addr)?,
+                None => Uint128::zero(),
+            })
+        }
+        None => None,
+    };
+    Ok(CommitRefundResponse {
+        raise_deadline: RAISE_DEADLINE.may_load(deps.storage)?,
+        refunds_open: state.is_some(),
+        state,
+        wallet_refund,
+    })
+}
+
+pub fn query_launch_protection(
+    deps: Deps,
+    env: &Env,
+    wallet: Option<String>,
+) -> StdResult<LaunchProtectionResponse> {
+    let config = LAUNCH_PROTECTION_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let window = LAUNCH_WINDOW.may_load(deps.storage)?;
+    let active = window
+        .as_ref()
+        .is_some_and(|w| env.block.height < w.ends_at_block);
+
+    let (max_offer_0, max_offer_1) = match &window {
+        Some(w) if active && w.max_swap_bps > 0 => {
+            let pool_state = POOL_STATE.load(deps.storage)?;
+            (
+                Some(pool_state.reserve0.multiply_ratio(w.max_swap_bps, 10_000u128)),
+                Some(pool_state.reserve1.multiply_ratio(w.max_swap_bps, 10_000u128)),
+            )
+        }
+        _ => (None, None),
+    };
+    let wallet_bought = match wallet {
+        Some(wallet) => {
+            let addr = deps.api.addr_validate(&wallet)?;
+            Some(LAUNCH_WALLET_BUYS.may_load(deps.storage, &addr)?.unwrap_or_default())
+        }
+        None => None,
+    };
+
+    Ok(LaunchProtectionResponse {
+        config,
+        window,
+        active,
+        max_offer_0,
+        max_offer_1,
+        wallet_bought,
+    })
+}
+
 pub fn query_factory_notify_status(deps: Deps) -> StdResult<FactoryNotifyStatusResponse> {
     let pending = PENDING_FACTORY_NOTIFY
         .may_load(deps.storage)?
@@ -171,14 +449,24 @@ pub fn query_check_threshold_limit(deps: Deps) -> StdResult<CommitStatus> {
     threshold_status_from(deps, usd_raised)
 }
 
+/// Pool-core's `query_pool_info` plus the commit-only per-wallet caps.
+pub fn query_pool_info(deps: Deps) -> StdResult<PoolInfoResponse> {
+    let commit_config = COMMIT_LIMIT_INFO.load(deps.storage)?;
+    Ok(PoolInfoResponse {
+        max_commit_usd_per_wallet: commit_config.max_commit_usd_per_wallet,
+        max_commit_share_per_wallet: commit_config.max_commit_share_per_wallet,
+        ..pool_core::query::query_pool_info(deps)?
+    })
+}
+
 /// Creator-pool wrapper around `query_analytics_core`. Loads commit-
 /// phase totals and derives `threshold_status`, then delegates the
 /// shared response body construction.
-pub fn query_analytics(deps: Deps) -> StdResult<PoolAnalyticsResponse> {
+pub fn query_analytics(deps: Deps, env: Env) -> StdResult<PoolAnalyticsResponse> {
     let usd_raised = USD_RAISED_FROM_COMMIT.load(deps.storage)?;
     let bluechip_raised = NATIVE_RAISED_FROM_COMMIT.load(deps.storage)?;
     let threshold_status = threshold_status_from(deps, usd_raised)?;
-    query_analytics_core(deps, threshold_status, usd_raised, bluechip_raised)
+    query_analytics_core(deps, env, threshold_status, usd_raised, bluechip_raised)
 }
 
 pub fn query_pool_committers(
@@ -17,6 +17,7 @@
 //!
 //! Threshold-cross machinery:
 //! POST_THRESHOLD_COOLDOWN_UNTIL_BLOCK, POST_THRESHOLD_COOLDOWN_BLOCKS,
+//! LAUNCH_WINDOW, LAUNCH_WALLET_BUYS,
 //! STUCK_THRESHOLD_RECOVERY_WINDOW_SECONDS,
 //! STUCK_DISTRIBUTION_RECOVERY_WINDOW_SECONDS,
 //! MAX_CONSECUTIVE_DISTRIBUTION_FAILURES,
@@ -33,8 +34,12 @@
 pub use pool_core::state::*;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Decimal, HexBinary, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
+use pool_factory_interfaces::{
+    CreatorVestingConfig, DistributionMode, EarlySupporterCurve, LaunchProtectionConfig,
+    SubscriptionTier,
+};
 
 // -- Commit-phase-only storage -------------------------------------------
 
@@ -60,6 +65,87 @@ pub const NATIVE_RAISED_FROM_COMMIT: Item<Uint128> = Item::new("bluechip_raised"
 /// Per-committer USD ledger; drained during post-threshold distribution.
 pub const COMMIT_LEDGER: cw_storage_plus::Map<&Addr, Uint128> =
     cw_storage_plus::Map::new("commit_usd");
+/// The allowlist phase's Merkle root and end time. Absent on pools
+/// that never had one.
+pub const COMMIT_ALLOWLIST: Item<CommitAllowlistPhase> = Item::new("commit_allowlist");
+/// Wallets allowlisted on-chain for the current phase.
+pub const ALLOWLISTED_WALLETS: Map<&Addr, bool> = Map::new("allowlisted_wallets");
+/// Creator-chosen early-supporter curve plus its start time (pool
+/// instantiation). Absent on unweighted pools.
+pub const EARLY_SUPPORTER_WEIGHTING: Item<EarlySupporterWeighting> =
+    Item::new("early_supporter_weighting");
+/// Per-committer time-weighted USD, written next to `COMMIT_LEDGER`
+/// when `EARLY_SUPPORTER_WEIGHTING` is set. Each commit adds its USD
+/// times the curve's multiplier at commit time; the distribution share
+/// math uses this in place of the raw ledger amount.
+pub const WEIGHTED_COMMIT_LEDGER: Map<&Addr, Uint128> = Map::new("weighted_commit_usd");
+/// Sum of `WEIGHTED_COMMIT_LEDGER`; the share denominator on weighted pools.
+pub const TOTAL_WEIGHTED_COMMIT_USD: Item<Uint128> = Item::new("total_weighted_commit_usd");
+/// Running total of GROSS bluechip committed pre-threshold — the sum of
+/// every committer's `Committing.total_paid_bluechip` while funding.
+/// Denominator for pro-rata commit refunds; see `CommitRefundState`.
+pub const GROSS_RAISED_FROM_COMMIT: Item<Uint128> = Item::new("gross_bluechip_raised");
+/// Optional end of the commit phase, set at instantiate from the
+/// factory's `CreatePool.raise_deadline`. Absent = the raise never
+/// expires (pre-refund behaviour).
+pub const RAISE_DEADLINE: Item<Timestamp> = Item::new("raise_deadline");
+/// Snapshot taken the first time refunds are touched after a missed
+/// `RAISE_DEADLINE`. Present = the pool is in refund state.
+pub const COMMIT_REFUND_STATE: Item<CommitRefundState> = Item::new("commit_refund_state");
+/// Recurring commit schedules keyed by subscriber. The escrowed
+/// bluechip sits in the pool's bank balance but outside every reserve
+/// and raise counter until a renewal commits it.
+pub const SUBSCRIPTIONS: Map<&Addr, Subscription> = Map::new("subscriptions");
+/// Bounds on `Subscription.period_seconds`. The floor keeps renewals,
+/// each a full commit, off day-trading schedules.
+pub const MIN_SUBSCRIPTION_PERIOD_SECONDS: u64 = 86_400;
+pub const MAX_SUBSCRIPTION_PERIOD_SECONDS: u64 = 86_400 * 365;
+/// Share of each renewal's bluechip paid from the escrow to a keeper
+/// other than the subscriber.
+pub const SUBSCRIPTION_KEEPER_FEE: Decimal = Decimal::permille(5);
+/// Cap on `Subscription.periods_remaining` at creation.
+pub const MAX_SUBSCRIPTION_PERIODS: u32 = 120;
+/// Creator-defined membership tiers keyed by name; replaced wholesale by
+/// the factory-forwarded `SetSubscriptionTiers`.
+pub const SUBSCRIPTION_TIERS: Map<&str, SubscriptionTier> = Map::new("subscription_tiers");
+/// Each wallet's current membership, extended by tier-tagged commits.
+/// Survives its tier being removed; it simply runs out.
+pub const MEMBERSHIPS: Map<&Addr, Membership> = Map::new("memberships");
+pub const MAX_SUBSCRIPTION_TIERS: usize = 10;
+pub const MAX_SUBSCRIPTION_TIER_NAME_LEN: usize = 32;
+/// Furthest a membership may run past the current block time.
+pub const MAX_MEMBERSHIP_SECONDS: u64 = 86_400 * 365 * 10;
+/// Sponsor matching escrows keyed by sponsor. Unmatched bluechip sits
+/// in the pool's bank balance outside every reserve and raise counter;
+/// a match moves it into the raise counters.
+pub const SPONSORSHIPS: Map<&Addr, Sponsorship> = Map::new("sponsorships");
+/// Most sponsorships a pool will hold; each commit walks all of them.
+pub const MAX_SPONSORSHIPS: usize = 10;
+/// Upper bound on `Sponsorship.match_ratio` (5 bluechip matched per
+/// bluechip committed).
+pub const MAX_SPONSOR_MATCH_RATIO: Decimal = Decimal::raw(5_000_000_000_000_000_000);
+/// Furthest a sponsorship's `expires_at` may sit past its creation.
+pub const MAX_SPONSORSHIP_SECONDS: u64 = 86_400 * 365;
+/// Smallest deposit (USD, 6 decimals) that opens a new sponsorship.
+pub const MIN_SPONSORSHIP_USD: Uint128 = Uint128::new(100_000_000);
+/// Share of each referred commit's creator fee that goes to the
+/// referrer instead. Absent = zero (referrals disabled); set through the
+/// factory's pool config update flow.
+pub const REFERRAL_FEE_SHARE: Item<Decimal> = Item::new("referral_fee_share");
+/// Per-referrer earnings. The unclaimed bluechip stays in the pool's
+/// bank balance, outside reserves, until `ClaimReferralRewards`.
+pub const REFERRAL_STATS: Map<&Addr, ReferralStats> = Map::new("referral_stats");
+/// Weighted recipients of the creator's revenue: commit fees, the
+/// `CREATOR_FEE_POT` and the threshold creator reward. Absent = all of
+/// it goes to `creator_wallet_address`.
+pub const REVENUE_SPLIT: Item<Vec<RevenueRecipient>> = Item::new("revenue_split");
+/// Creator-proposed replacement for `REVENUE_SPLIT`, appliable once
+/// `effective_after` passes.
+pub const PENDING_REVENUE_SPLIT: Item<PendingRevenueSplit> = Item::new("pending_revenue_split");
+pub const MAX_REVENUE_RECIPIENTS: usize = 10;
+/// Delay between proposing and applying a revenue split, so
+/// collaborators can see a change coming.
+pub const REVENUE_SPLIT_TIMELOCK_SECONDS: u64 = 86_400 * 2;
 /// Re-entrancy/inflight flag set while a threshold-crossing commit is mid-execution.
 pub const THRESHOLD_PROCESSING: Item<bool> = Item::new("threshold_processing");
 /// Fixed split of creator-token amounts paid out at threshold crossing.
@@ -67,19 +153,49 @@ pub const THRESHOLD_PAYOUT_AMOUNTS: Item<ThresholdPayoutAmounts> =
     Item::new("threshold_payout_amounts");
 /// Cursor + accounting for the post-threshold distribution batch loop.
 pub const DISTRIBUTION_STATE: Item<DistributionState> = Item::new("distribution_state");
+/// Push or pull payout of the commit-return tranche, fixed at
+/// instantiate. Absent on pools instantiated before pull mode existed,
+/// which are all `Push`.
+pub const DISTRIBUTION_MODE: Item<DistributionMode> = Item::new("distribution_mode");
+/// Raise totals frozen at threshold crossing for a `Pull` pool;
+/// `ClaimCommitReward` pays each `COMMIT_LEDGER` entry against them.
+pub const PULL_DISTRIBUTION: Item<PullDistribution> = Item::new("pull_distribution");
 /// Threshold target, max bluechip lock, and excess-lock duration.
 pub const COMMIT_LIMIT_INFO: Item<CommitLimitInfo> = Item::new("commit_config");
 /// Creator-side excess liquidity position created when raised bluechip exceeds the per-pool cap.
 pub const CREATOR_EXCESS_POSITION: Item<CreatorExcessLiquidity> = Item::new("creator_excess");
 /// Timestamp of the most recent threshold-crossing attempt; used by stuck-state recovery.
 pub const LAST_THRESHOLD_ATTEMPT: Item<Timestamp> = Item::new("last_threshold_attempt");
+/// Factory launch-window settings snapshotted at instantiate. Turned
+/// into the shared `LAUNCH_WINDOW` when the threshold crosses; absent
+/// on pools instantiated before launch protection existed.
+pub const LAUNCH_PROTECTION_CONFIG: Item<LaunchProtectionConfig> =
+    Item::new("launch_protection_config");
+/// Factory creator-reward vesting settings snapshotted at instantiate.
+/// Absent when vesting was disabled at create time.
+pub const CREATOR_VESTING_CONFIG: Item<CreatorVestingConfig> =
+    Item::new("creator_vesting_config");
+/// The creator reward's vesting schedule, set at threshold crossing
+/// when `CREATOR_VESTING_CONFIG` is present. The unclaimed balance sits
+/// in the pool's CW20 balance, outside the reserves.
+pub const CREATOR_VESTING: Item<CreatorVesting> = Item::new("creator_vesting");
+/// Factory `creator_wallet_veto_seconds` snapshotted at instantiate.
+/// Absent (zero) means a proposed creator wallet can be accepted at once.
+pub const CREATOR_WALLET_VETO_SECONDS: Item<u64> = Item::new("creator_wallet_veto_seconds");
+/// Creator wallet change awaiting `AcceptCreatorWallet` from the new
+/// wallet.
+pub const PENDING_CREATOR_WALLET: Item<PendingCreatorWallet> =
+    Item::new("pending_creator_wallet");
+/// Length caps on the creator token's marketing fields.
+pub const MAX_TOKEN_PROJECT_LEN: usize = 256;
+pub const MAX_TOKEN_DESCRIPTION_LEN: usize = 1024;
 
 /// Set to `true` when `NotifyThresholdCrossed` to the factory failed
 /// via the `reply_on_error` path during a threshold-crossing commit.
 ///
 /// All pool-side threshold state (`IS_THRESHOLD_HIT`, reserves,
 /// committer distribution) still succeeds — only the factory's
-/// `POOL_THRESHOLD_MINTED` flag and the per-pool Bluechip mint reward
+/// `threshold_crossed` registry flag and the per-pool Bluechip mint reward
 /// are pending. Any caller can invoke
 /// [`crate::msg::ExecuteMsg::RetryFactoryNotify`] to re-send the
 /// notification; on success this flag is cleared by the reply
@@ -260,6 +376,19 @@ pub struct DistributionState {
     pub distributed_so_far: Uint128,
 }
 
+#[cw_serde]
+pub struct PullDistribution {
+    pub total_to_distribute: Uint128,
+    pub total_committed_usd: Uint128,
+    /// Threshold-crossing time.
+    pub frozen_at: Timestamp,
+    /// Running sum of rewards minted by `ClaimCommitReward`. The last
+    /// claim mints `total_to_distribute - claimed` floor dust to the
+    /// creator, as the push loop's final batch does.
+    pub claimed: Uint128,
+    pub claim_count: u64,
+}
+
 #[cw_serde]
 pub enum RecoveryType {
     StuckThreshold,
@@ -309,6 +438,115 @@ pub struct Committing {
     pub last_payment_usd: Uint128,
 }
 
+/// Failed-raise refund bookkeeping. Each committer's refund is
+/// `refundable_bluechip * total_paid_bluechip / total_gross_bluechip`,
+/// so refunds are split by what each committer actually paid and the
+/// sum never exceeds the net bluechip the pool holds. A committer's
+/// `COMMIT_LEDGER` entry is removed when they claim.
+#[cw_serde]
+pub struct CommitRefundState {
+    /// Block time refund state was entered (first touch after the deadline).
+    pub opened_at: Timestamp,
+    /// `NATIVE_RAISED_FROM_COMMIT` at entry: net-of-fee bluechip to hand back.
+    pub refundable_bluechip: Uint128,
+    /// `GROSS_RAISED_FROM_COMMIT` at entry.
+    pub total_gross_bluechip: Uint128,
+    /// Bluechip paid out by `ClaimCommitRefund` so far.
+    pub refunded_bluechip: Uint128,
+    /// Number of committers who have claimed.
+    pub claim_count: u64,
+}
+
+#[cw_serde]
+pub struct PendingCreatorWallet {
+    pub new_wallet: Addr,
+    pub proposed_at: Timestamp,
+    /// End of the factory admin's veto window; the new wallet can
+    /// accept from here on.
+    pub accept_after: Timestamp,
+}
+
+#[cw_serde]
+pub struct RevenueRecipient {
+    pub address: Addr,
+    /// Share in basis points; a split's shares sum to 10,000.
+    pub bps: u16,
+}
+
+#[cw_serde]
+pub struct PendingRevenueSplit {
+    /// Empty restores the creator-only default.
+    pub recipients: Vec<RevenueRecipient>,
+    pub effective_after: Timestamp,
+}
+
+/// Who a sponsor's matched contribution is credited to.
+#[cw_serde]
+pub enum SponsorCredit {
+    /// The sponsor holds the ledger entry and earns the creator tokens.
+    Sponsor,
+    /// The matched committer's own ledger entry grows.
+    Committer,
+}
+
+/// A sponsor's matching offer. Each eligible pre-threshold commit is
+/// matched with `match_ratio` times its bluechip, at most
+/// `max_match_per_commit`, out of `escrow_remaining`.
+#[cw_serde]
+pub struct Sponsorship {
+    pub match_ratio: Decimal,
+    pub max_match_per_commit: Uint128,
+    pub credit: SponsorCredit,
+    /// No matches at or after this time; the rest is reclaimable.
+    pub expires_at: Timestamp,
+    pub escrow_deposited: Uint128,
+    pub escrow_remaining: Uint128,
+    pub matched_bluechip: Uint128,
+    pub matched_usd: Uint128,
+    pub match_count: u32,
+}
+
+/// A subscriber's recurring commit. Each renewal converts
+/// `usd_per_period` to bluechip at the oracle rate of the renewal block
+/// and commits it out of `escrow_bluechip` on the subscriber's behalf.
+#[cw_serde]
+pub struct Subscription {
+    /// USD (6 decimals) committed per period.
+    pub usd_per_period: Uint128,
+    pub period_seconds: u64,
+    /// Renewals still to run; the subscription closes at zero.
+    pub periods_remaining: u32,
+    /// Unspent bluechip held for future renewals.
+    pub escrow_bluechip: Uint128,
+    /// Earliest block time the next renewal may run.
+    pub next_due: Timestamp,
+    pub created_at: Timestamp,
+    /// Renewals run so far.
+    pub renewals: u32,
+    /// Membership tier each renewal's commit is tagged with.
+    #[serde(default)]
+    pub tier: Option<String>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ReferralStats {
+    /// Commits that named this referrer.
+    pub referred_commits: u64,
+    /// USD value of those commits.
+    pub referred_usd: Uint128,
+    /// Bluechip earned over all time.
+    pub total_earned: Uint128,
+    /// Bluechip earned and not yet claimed.
+    pub unclaimed: Uint128,
+}
+
+#[cw_serde]
+pub struct Membership {
+    pub tier: String,
+    pub expires_at: Timestamp,
+}
+
 #[cw_serde]
 pub struct ThresholdPayoutAmounts {
     /// Creator-token amount minted to the creator wallet at threshold-cross.
@@ -338,6 +576,9 @@ pub const DEFAULT_MIN_COMMIT_USD_POST_THRESHOLD: Uint128 = Uint128::new(1_000_00
 /// `PoolConfigUpdate::validate()` and the pool's apply path both
 /// enforce this.
 pub const MAX_MIN_COMMIT_USD: Uint128 = Uint128::new(1_000_000_000);
+/// Inclusive upper bound on `REFERRAL_FEE_SHARE`. Mirrors the factory's
+/// `POOL_CONFIG_MAX_REFERRAL_FEE_SHARE`.
+pub const MAX_REFERRAL_FEE_SHARE: Decimal = Decimal::percent(50);
 
 fn default_min_commit_usd_pre_threshold() -> Uint128 {
     DEFAULT_MIN_COMMIT_USD_PRE_THRESHOLD
@@ -366,6 +607,82 @@ pub struct CommitLimitInfo {
     /// Same shape as `min_commit_usd_pre_threshold`.
     #[serde(default = "default_min_commit_usd_post_threshold")]
     pub min_commit_usd_post_threshold: Uint128,
+    /// Optional cap on one wallet's `COMMIT_LEDGER` total (USD, 6
+    /// decimals). Set through `PoolConfigUpdate.max_commit_usd_per_wallet`.
+    #[serde(default)]
+    pub max_commit_usd_per_wallet: Option<Uint128>,
+    /// Optional cap on one wallet's `COMMIT_LEDGER` total as a share of
+    /// `commit_amount_for_threshold_usd`.
+    #[serde(default)]
+    pub max_commit_share_per_wallet: Option<Decimal>,
+}
+
+impl CommitLimitInfo {
+    /// The tighter of the two per-wallet caps, in USD. `None` when
+    /// neither is set.
+    pub fn wallet_commit_cap(&self) -> Option<Uint128> {
+        let share_cap = self
+            .max_commit_share_per_wallet
+            .map(|share| self.commit_amount_for_threshold_usd.mul_floor(share));
+        match (self.max_commit_usd_per_wallet, share_cap) {
+            (Some(usd), Some(share)) => Some(usd.min(share)),
+            (usd, share) => usd.or(share),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct CommitAllowlistPhase {
+    pub merkle_root: Option<HexBinary>,
+    pub public_at: Timestamp,
+    pub onchain_wallet_count: u32,
+}
+
+impl CommitAllowlistPhase {
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        now < self.public_at
+    }
+}
+
+#[cw_serde]
+pub struct EarlySupporterWeighting {
+    pub curve: EarlySupporterCurve,
+    /// Pool instantiation time; the curve's `t = 0`.
+    pub starts_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct CreatorVesting {
+    /// Creator wallet entitled to claim the vested reward.
+    pub creator: Addr,
+    /// Full `creator_reward_amount` minted into the pool at crossing.
+    pub total: Uint128,
+    pub claimed: Uint128,
+    /// Threshold-crossing time; vesting accrues linearly from here.
+    pub start: Timestamp,
+    /// Nothing is claimable before this.
+    pub cliff_end: Timestamp,
+    /// Fully vested from this time on.
+    pub end: Timestamp,
+}
+
+impl CreatorVesting {
+    /// Amount vested by `now`, claimed or not.
+    pub fn vested_at(&self, now: Timestamp) -> Uint128 {
+        if now < self.cliff_end {
+            return Uint128::zero();
+        }
+        if now >= self.end {
+            return self.total;
+        }
+        let elapsed = now.seconds() - self.start.seconds();
+        let duration = self.end.seconds() - self.start.seconds();
+        self.total.multiply_ratio(elapsed, duration)
+    }
+
+    pub fn claimable_at(&self, now: Timestamp) -> Uint128 {
+        self.vested_at(now).saturating_sub(self.claimed)
+    }
 }
 
 #[cw_serde]
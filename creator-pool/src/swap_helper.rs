@@ -110,3 +110,16 @@ pub fn usd_to_bluechip_at_rate(usd_amount: Uint128, rate: Uint128) -> StdResult<
         })
 }
 
+/// `usd_to_bluechip_at_rate`, rounded up. For amounts that must still be
+/// worth at least `usd_amount` after the floor-rounded bluechip → USD
+/// conversion a commit applies to them.
+pub fn usd_to_bluechip_at_rate_ceil(usd_amount: Uint128, rate: Uint128) -> StdResult<Uint128> {
+    let floor = usd_to_bluechip_at_rate(usd_amount, rate)?;
+    let scaled = usd_amount.checked_mul(Uint128::from(ORACLE_PRICE_PRECISION))?;
+    if scaled.checked_rem(rate)?.is_zero() {
+        Ok(floor)
+    } else {
+        Ok(floor.checked_add(Uint128::one())?)
+    }
+}
+
@@ -84,6 +84,14 @@ fn mock_instantiate_msg() -> PoolInstantiateMsg {
         token_address: token_addr,
         max_bluechip_lock_per_pool: Uint128::new(10000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
+        protocol_fee_share: Default::default(),
+        raise_deadline: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
+        distribution_mode: Default::default(),
+        early_supporter_curve: None,
+        commit_allowlist: None,
     }
 }
 
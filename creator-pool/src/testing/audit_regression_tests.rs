@@ -1040,6 +1040,8 @@ fn test_h1_commit_rejects_multi_denom_funds() {
                 creator_excess_liquidity_lock_days: 14,
                 min_commit_usd_pre_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_PRE_THRESHOLD,
                 min_commit_usd_post_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_POST_THRESHOLD,
+                max_commit_usd_per_wallet: None,
+                max_commit_share_per_wallet: None,
             },
         )
         .unwrap();
@@ -1088,6 +1090,10 @@ fn test_h1_commit_rejects_multi_denom_funds() {
             transaction_deadline: None,
             belief_price: None,
             max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
         },
     );
 
@@ -1531,6 +1537,8 @@ fn test_m7_threshold_payout_emits_accept_ownership() {
         creator_excess_liquidity_lock_days: 14,
         min_commit_usd_pre_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_PRE_THRESHOLD,
         min_commit_usd_post_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_POST_THRESHOLD,
+        max_commit_usd_per_wallet: None,
+        max_commit_share_per_wallet: None,
     };
     let payout = ThresholdPayoutAmounts {
         creator_reward_amount: Uint128::new(325_000_000_000),
@@ -2972,6 +2980,8 @@ mod empty_position_persistence_tests {
             message_info(&user, &[]),
             "9".to_string(),
             None,
+            None,
+            None,
         )
         .expect("collect_fees on empty position must succeed");
 
@@ -176,6 +176,245 @@ fn claim_creator_fees_with_only_native_side() {
     assert_eq!(cw20_count, 0);
 }
 
+#[test]
+fn claim_creator_fees_follows_timelocked_revenue_split() {
+    use crate::state::{RevenueRecipient, REVENUE_SPLIT_TIMELOCK_SECONDS};
+
+    let mut deps = mock_dependencies();
+    setup_pool_storage(&mut deps);
+    CREATOR_FEE_POT
+        .save(
+            &mut deps.storage,
+            &CreatorFeePot {
+                amount_0: Uint128::new(10_001),
+                amount_1: Uint128::zero(),
+            },
+        )
+        .unwrap();
+    let alice = deps.api.addr_make("alice");
+    let bob = deps.api.addr_make("bob");
+    let creator = message_info(&Addr::unchecked("creator_wallet"), &[]);
+    let split = |alice_bps: u16| ExecuteMsg::ProposeRevenueSplit {
+        recipients: vec![
+            RevenueRecipient {
+                address: alice.clone(),
+                bps: alice_bps,
+            },
+            RevenueRecipient {
+                address: bob.clone(),
+                bps: 3_000,
+            },
+        ],
+    };
+
+    let err = execute(deps.as_mut(), mock_env(), creator.clone(), split(6_000)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidRevenueSplit { .. }));
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[]),
+        split(7_000),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(deps.as_mut(), mock_env(), creator.clone(), split(7_000)).unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        creator.clone(),
+        ExecuteMsg::ApplyRevenueSplit {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::RevenueSplitTimelocked { .. }));
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(REVENUE_SPLIT_TIMELOCK_SECONDS);
+    execute(
+        deps.as_mut(),
+        later.clone(),
+        creator,
+        ExecuteMsg::ApplyRevenueSplit {},
+    )
+    .unwrap();
+
+    // A recipient may claim; the floor dust goes to the first recipient.
+    let res = execute(
+        deps.as_mut(),
+        later,
+        message_info(&bob, &[]),
+        ExecuteMsg::ClaimCreatorFees {
+            transaction_deadline: None,
+        },
+    )
+    .unwrap();
+    let sends: Vec<(String, Uint128)> = res
+        .messages
+        .iter()
+        .filter_map(|sub| match &sub.msg {
+            CosmosMsg::Bank(cosmwasm_std::BankMsg::Send { to_address, amount }) => {
+                Some((to_address.clone(), amount[0].amount))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        sends,
+        vec![
+            (alice.to_string(), Uint128::new(7_001)),
+            (bob.to_string(), Uint128::new(3_000)),
+        ]
+    );
+}
+
+#[test]
+fn creator_wallet_rotation_respects_veto_window_and_moves_fee_claims() {
+    use crate::state::{CREATOR_WALLET_VETO_SECONDS, COMMITFEEINFO};
+
+    let mut deps = mock_dependencies();
+    setup_pool_storage(&mut deps);
+    CREATOR_WALLET_VETO_SECONDS
+        .save(&mut deps.storage, &86_400)
+        .unwrap();
+    let old = message_info(&Addr::unchecked("creator_wallet"), &[]);
+    let multisig = deps.api.addr_make("multisig");
+    let propose = ExecuteMsg::ProposeCreatorWallet {
+        new_wallet: multisig.to_string(),
+    };
+
+    // The admin can veto inside the window.
+    execute(deps.as_mut(), mock_env(), old.clone(), propose.clone()).unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        ExecuteMsg::VetoCreatorWallet {},
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&multisig, &[]),
+        ExecuteMsg::AcceptCreatorWallet {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NoPendingCreatorWallet {}));
+
+    execute(deps.as_mut(), mock_env(), old.clone(), propose).unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&multisig, &[]),
+        ExecuteMsg::AcceptCreatorWallet {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CreatorWalletVetoWindow { .. }));
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(86_400);
+    let res = execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&multisig, &[]),
+        ExecuteMsg::AcceptCreatorWallet {},
+    )
+    .unwrap();
+    // The factory is told so the creator's registry entry and profile
+    // follow the new wallet.
+    assert_eq!(res.messages.len(), 1);
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, "factory_contract");
+            assert_eq!(
+                cosmwasm_std::from_json::<pool_factory_interfaces::FactoryExecuteMsg>(msg)
+                    .unwrap(),
+                pool_factory_interfaces::FactoryExecuteMsg::NotifyCreatorWalletRotated {
+                    new_wallet: multisig.to_string(),
+                }
+            );
+        }
+        other => panic!("expected factory notify, got {:?}", other),
+    }
+    assert_eq!(
+        COMMITFEEINFO
+            .load(&deps.storage)
+            .unwrap()
+            .creator_wallet_address,
+        multisig
+    );
+
+    CREATOR_FEE_POT
+        .save(
+            &mut deps.storage,
+            &CreatorFeePot {
+                amount_0: Uint128::new(10_000),
+                amount_1: Uint128::zero(),
+            },
+        )
+        .unwrap();
+    let claim = ExecuteMsg::ClaimCreatorFees {
+        transaction_deadline: None,
+    };
+    let err = execute(deps.as_mut(), later.clone(), old, claim.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(deps.as_mut(), later, message_info(&multisig, &[]), claim).unwrap();
+}
+
+#[test]
+fn update_token_marketing_forwards_to_cw20_for_creator_only() {
+    use cosmwasm_std::from_json;
+    use cw20::{Cw20ExecuteMsg, Logo};
+
+    let mut deps = mock_dependencies();
+    setup_pool_storage(&mut deps);
+    let update = ExecuteMsg::UpdateTokenMarketing {
+        project: Some("https://example.com".to_string()),
+        description: None,
+        logo: Some(Logo::Url("https://example.com/logo.png".to_string())),
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&Addr::unchecked("attacker"), &[]),
+        update.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&Addr::unchecked("creator_wallet"), &[]),
+        update,
+    )
+    .unwrap();
+    let forwarded: Vec<Cw20ExecuteMsg> = res
+        .messages
+        .iter()
+        .map(|sub| match &sub.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, "token_contract");
+                from_json(msg).unwrap()
+            }
+            other => panic!("unexpected message {:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        forwarded,
+        vec![
+            Cw20ExecuteMsg::UpdateMarketing {
+                project: Some("https://example.com".to_string()),
+                description: None,
+                marketing: None,
+            },
+            Cw20ExecuteMsg::UploadLogo(Logo::Url("https://example.com/logo.png".to_string())),
+        ]
+    );
+}
+
 // -- execute_retry_factory_notify ---------------------------------------
 
 #[test]
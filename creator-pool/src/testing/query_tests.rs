@@ -13,8 +13,8 @@ use crate::msg::{
 };
 use crate::query::query;
 use crate::state::{
-    Committing, COMMIT_INFO, NEXT_POSITION_ID, OWNER_POSITIONS, POOL_FEE_STATE,
-    USD_RAISED_FROM_COMMIT,
+    Committing, LaunchFeeSchedule, COMMIT_INFO, LAUNCH_FEE, NEXT_POSITION_ID, OWNER_POSITIONS,
+    POOL_FEE_STATE, USD_RAISED_FROM_COMMIT,
 };
 use crate::testing::liquidity_tests::{
     create_test_position, setup_pool_post_threshold, setup_pool_storage,
@@ -89,6 +89,8 @@ fn setup_pool_with_querier() -> OwnedDeps<MockStorage, MockApi, mock_querier::Wa
         creator_excess_liquidity_lock_days: 7,
         min_commit_usd_pre_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_PRE_THRESHOLD,
         min_commit_usd_post_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_POST_THRESHOLD,
+        max_commit_usd_per_wallet: None,
+        max_commit_share_per_wallet: None,
     };
     COMMIT_LIMIT_INFO
         .save(&mut deps.storage, &commit_config)
@@ -202,6 +204,50 @@ fn test_query_simulation_wrong_asset() {
     assert!(err.to_string().contains("does not belong"));
 }
 
+#[test]
+fn test_query_simulation_reports_launch_fee() {
+    let mut deps = setup_pool_with_querier();
+    let env = mock_env();
+    let offer = TokenInfo {
+        info: TokenType::Native {
+            denom: "ubluechip".to_string(),
+        },
+        amount: Uint128::new(1_000_000_000),
+    };
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Simulation {
+            offer_asset: offer.clone(),
+        },
+    )
+    .unwrap();
+    let base: SimulationResponse = from_json(res).unwrap();
+    assert_eq!(base.commission_rate, Decimal::permille(3));
+    assert!(base.launch_fee_amount.is_zero());
+
+    LAUNCH_FEE
+        .save(
+            &mut deps.storage,
+            &LaunchFeeSchedule {
+                initial_fee: Decimal::percent(3),
+                start_block: env.block.height,
+                end_block: env.block.height + 100,
+            },
+        )
+        .unwrap();
+    let res = query(deps.as_ref(), env, QueryMsg::Simulation { offer_asset: offer }).unwrap();
+    let sim: SimulationResponse = from_json(res).unwrap();
+    assert_eq!(sim.commission_rate, Decimal::percent(3));
+    assert!(sim.commission_amount > base.commission_amount);
+    assert!(sim.return_amount < base.return_amount);
+    assert_eq!(
+        sim.launch_fee_amount,
+        sim.commission_amount - sim.commission_amount.multiply_ratio(1u128, 10u128)
+    );
+}
+
 #[test]
 fn test_query_reverse_simulation() {
     let deps = setup_pool_with_querier();
@@ -9,8 +9,11 @@ use crate::state::{
     ThresholdPayoutAmounts, COMMIT_INFO, COMMIT_LEDGER, DEFAULT_ESTIMATED_GAS_PER_DISTRIBUTION,
     DEFAULT_MAX_GAS_PER_TX, IS_THRESHOLD_HIT, NATIVE_RAISED_FROM_COMMIT, NEXT_POSITION_ID,
     ORACLE_INFO, POOL_FEE_STATE, POOL_PAUSED, POOL_SPECS, POOL_STATE, REENTRANCY_LOCK,
-    USD_RAISED_FROM_COMMIT,
+    USD_RAISED_FROM_COMMIT, LAUNCH_PROTECTION_CONFIG, LAUNCH_WALLET_BUYS, LAUNCH_WINDOW,
+    LaunchFeeSchedule, LaunchWindow, CREATOR_FEE_POT, LAUNCH_FEE, POST_THRESHOLD_COOLDOWN_BLOCKS,
 };
+use crate::msg::{LaunchProtectionResponse, QueryMsg};
+use crate::query::query;
 use crate::{
     contract::{execute, instantiate},
     swap_helper::execute_swap_cw20,
@@ -33,7 +36,7 @@ use cosmwasm_std::{
     OwnedDeps, SystemError, SystemResult, Timestamp, Uint128, WasmQuery,
 };
 use cw20::Cw20ReceiveMsg;
-use pool_factory_interfaces::{ConversionResponse, FactoryQueryMsg};
+use pool_factory_interfaces::{ConversionResponse, FactoryQueryMsg, LaunchProtectionConfig};
 
 #[cw_serde]
 enum FactoryQueryWrapper {
@@ -170,6 +173,10 @@ fn test_commit_pre_threshold_basic() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -190,6 +197,409 @@ fn test_commit_pre_threshold_basic() {
     assert_eq!(committing.total_paid_usd, Uint128::new(1_000_000_000));
 }
 
+#[test]
+fn test_commit_over_wallet_cap_is_scaled_down_and_refunded() {
+    use crate::msg::PoolConfigUpdate;
+    use pool_core::msg::PoolInfoResponse;
+
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(10_000_000_000),
+    }]);
+    setup_pool_storage(&mut deps);
+    with_factory_oracle(&mut deps, Uint128::new(1_000_000)); // $1 per bluechip
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        ExecuteMsg::UpdateConfigFromFactory {
+            update: PoolConfigUpdate {
+                max_commit_share_per_wallet: Some(Decimal::percent(20)),
+                ..Default::default()
+            },
+        },
+    )
+    .unwrap();
+
+    let whale = deps.api.addr_make("whale");
+    let commit = |amount: u128| ExecuteMsg::Commit {
+        asset: TokenInfo {
+            info: TokenType::Native {
+                denom: "ubluechip".to_string(),
+            },
+            amount: Uint128::new(amount),
+        },
+        transaction_deadline: None,
+        belief_price: None,
+        max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
+    };
+    let funds = |amount: u128| {
+        [Coin {
+            denom: "ubluechip".to_string(),
+            amount: Uint128::new(amount),
+        }]
+    };
+
+    // $6k against a 20% x $25k = $5k cap: $5k is taken, $1k comes back.
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&whale, &funds(6_000_000_000)),
+        commit(6_000_000_000),
+    )
+    .unwrap();
+    assert!(res.messages.iter().any(|m| m.msg
+        == CosmosMsg::Bank(BankMsg::Send {
+            to_address: whale.to_string(),
+            amount: vec![Coin {
+                denom: "ubluechip".to_string(),
+                amount: Uint128::new(1_000_000_000),
+            }],
+        })));
+    assert_eq!(
+        COMMIT_LEDGER.load(&deps.storage, &whale).unwrap(),
+        Uint128::new(5_000_000_000)
+    );
+    assert_eq!(
+        USD_RAISED_FROM_COMMIT.load(&deps.storage).unwrap(),
+        Uint128::new(5_000_000_000)
+    );
+    let committing = COMMIT_INFO.load(&deps.storage, &whale).unwrap();
+    assert_eq!(committing.total_paid_bluechip, Uint128::new(5_000_000_000));
+
+    // At the cap: nothing more is accepted.
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(3_600);
+    let err = execute(
+        deps.as_mut(),
+        later,
+        message_info(&whale, &funds(1_000_000_000)),
+        commit(1_000_000_000),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CommitCapReached { .. }));
+
+    // $2 of allowance left: a $10 commit would scale below the $5
+    // minimum, so it's rejected rather than leaving a dust entry.
+    let minnow = deps.api.addr_make("minnow");
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minnow, &funds(4_998_000_000)),
+        commit(4_998_000_000),
+    )
+    .unwrap();
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(3_600);
+    let err = execute(
+        deps.as_mut(),
+        later,
+        message_info(&minnow, &funds(10_000_000)),
+        commit(10_000_000),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CommitCapReached { .. }));
+    assert_eq!(
+        COMMIT_LEDGER.load(&deps.storage, &minnow).unwrap(),
+        Uint128::new(4_998_000_000)
+    );
+
+    let info: PoolInfoResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::PoolInfo {}).unwrap()).unwrap();
+    assert_eq!(info.max_commit_share_per_wallet, Some(Decimal::percent(20)));
+    assert_eq!(info.max_commit_usd_per_wallet, None);
+}
+
+#[test]
+fn test_allowlist_phase_gates_pre_threshold_commits() {
+    use crate::commit::allowlist::save_commit_allowlist;
+    use cosmwasm_std::HexBinary;
+    use pool_factory_interfaces::CommitAllowlist;
+    use sha2::{Digest, Sha256};
+
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(10_000_000_000),
+    }]);
+    setup_pool_storage(&mut deps);
+    with_factory_oracle(&mut deps, Uint128::new(1_000_000));
+
+    // Two-leaf tree: alice's proof is bob's leaf and vice versa.
+    let alice = deps.api.addr_make("alice");
+    let bob = deps.api.addr_make("bob");
+    let listed = deps.api.addr_make("listed");
+    let outsider = deps.api.addr_make("outsider");
+    let leaf = |a: &Addr| -> [u8; 32] { Sha256::digest(a.as_bytes()).into() };
+    let (lo, hi) = if leaf(&alice) <= leaf(&bob) {
+        (leaf(&alice), leaf(&bob))
+    } else {
+        (leaf(&bob), leaf(&alice))
+    };
+    let root: [u8; 32] = Sha256::new().chain_update(lo).chain_update(hi).finalize().into();
+    let public_at = mock_env().block.time.plus_seconds(86_400);
+    save_commit_allowlist(
+        &mut deps.storage,
+        &deps.api,
+        &CommitAllowlist {
+            merkle_root: Some(HexBinary::from(root)),
+            wallets: vec![listed.to_string()],
+            public_at,
+        },
+    )
+    .unwrap();
+
+    let commit = |proof: Option<Vec<HexBinary>>| ExecuteMsg::Commit {
+        asset: TokenInfo {
+            info: TokenType::Native {
+                denom: "ubluechip".to_string(),
+            },
+            amount: Uint128::new(1_000_000_000),
+        },
+        transaction_deadline: None,
+        belief_price: None,
+        max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: proof,
+    };
+    let funds = [Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(1_000_000_000),
+    }];
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&outsider, &funds),
+        commit(None),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotAllowlisted { .. }));
+    // Someone else's proof doesn't lead to the root from alice's leaf.
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &funds),
+        commit(Some(vec![HexBinary::from(leaf(&alice))])),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotAllowlisted { .. }));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&listed, &funds),
+        commit(None),
+    )
+    .unwrap();
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(60);
+    execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&alice, &funds),
+        commit(Some(vec![HexBinary::from(leaf(&bob))])),
+    )
+    .unwrap();
+
+    // Once commits have landed, the running phase can be shortened
+    // but not pushed back.
+    let creator = COMMITFEEINFO
+        .load(&deps.storage)
+        .unwrap()
+        .creator_wallet_address;
+    let set_allowlist = |public_at| ExecuteMsg::SetCommitAllowlist {
+        creator: creator.to_string(),
+        allowlist: CommitAllowlist {
+            merkle_root: Some(HexBinary::from(root)),
+            wallets: vec![listed.to_string()],
+            public_at,
+        },
+    };
+    let err = execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        set_allowlist(public_at.plus_seconds(1)),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CommitAllowlistLocked { .. }));
+    execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        set_allowlist(public_at),
+    )
+    .unwrap();
+    let public_at = public_at.minus_seconds(3_600);
+    execute(
+        deps.as_mut(),
+        later,
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        set_allowlist(public_at),
+    )
+    .unwrap();
+
+    // Once the phase is over, an open pool can't be closed again.
+    let mut after_phase = mock_env();
+    after_phase.block.time = public_at;
+    let err = execute(
+        deps.as_mut(),
+        after_phase.clone(),
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        ExecuteMsg::SetCommitAllowlist {
+            creator: creator.to_string(),
+            allowlist: CommitAllowlist {
+                merkle_root: None,
+                wallets: vec![listed.to_string()],
+                public_at: public_at.plus_seconds(86_400),
+            },
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CommitAllowlistLocked { .. }));
+
+    execute(
+        deps.as_mut(),
+        after_phase,
+        message_info(&outsider, &funds),
+        commit(None),
+    )
+    .unwrap();
+    assert_eq!(
+        USD_RAISED_FROM_COMMIT.load(&deps.storage).unwrap(),
+        Uint128::new(3_000_000_000)
+    );
+}
+
+#[test]
+fn test_sponsor_matches_pre_threshold_commits_and_reclaims_after_expiry() {
+    use crate::msg::SponsorshipsResponse;
+    use crate::state::SponsorCredit;
+
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(10_000_000_000),
+    }]);
+    setup_pool_storage(&mut deps);
+    with_factory_oracle(&mut deps, Uint128::new(1_000_000));
+
+    let sponsor = deps.api.addr_make("sponsor");
+    let alice = deps.api.addr_make("alice");
+    let expires_at = mock_env().block.time.plus_seconds(86_400);
+    let sponsor_msg = ExecuteMsg::Sponsor {
+        match_ratio: Decimal::one(),
+        max_match_per_commit: Uint128::new(300_000_000),
+        credit: SponsorCredit::Sponsor,
+        expires_at,
+    };
+    let bluechip = |amount: u128| {
+        vec![Coin {
+            denom: "ubluechip".to_string(),
+            amount: Uint128::new(amount),
+        }]
+    };
+
+    // $50 is below the minimum for a new sponsorship.
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&sponsor, &bluechip(50_000_000)),
+        sponsor_msg.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidSponsorship { .. }));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&sponsor, &bluechip(500_000_000)),
+        sponsor_msg,
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &bluechip(1_000_000_000)),
+        ExecuteMsg::Commit {
+            asset: TokenInfo {
+                info: TokenType::Native {
+                    denom: "ubluechip".to_string(),
+                },
+                amount: Uint128::new(1_000_000_000),
+            },
+            transaction_deadline: None,
+            belief_price: None,
+            max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
+        },
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "sponsor_matched_bluechip" && a.value == "300000000"));
+
+    // The match is capped per commit and credited to the sponsor.
+    assert_eq!(
+        COMMIT_LEDGER.load(&deps.storage, &alice).unwrap(),
+        Uint128::new(1_000_000_000)
+    );
+    assert_eq!(
+        COMMIT_LEDGER.load(&deps.storage, &sponsor).unwrap(),
+        Uint128::new(300_000_000)
+    );
+    assert_eq!(
+        USD_RAISED_FROM_COMMIT.load(&deps.storage).unwrap(),
+        Uint128::new(1_300_000_000)
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&sponsor, &[]),
+        ExecuteMsg::ReclaimSponsorship {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::SponsorshipNotReclaimable { .. }));
+
+    let mut expired = mock_env();
+    expired.block.time = expires_at;
+    let resp: SponsorshipsResponse = from_json(
+        query(deps.as_ref(), expired.clone(), QueryMsg::Sponsorships {}).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(resp.sponsorships.len(), 1);
+    assert!(resp.sponsorships[0].reclaimable);
+    assert_eq!(
+        resp.sponsorships[0].sponsorship.escrow_remaining,
+        Uint128::new(200_000_000)
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        expired,
+        message_info(&sponsor, &[]),
+        ExecuteMsg::ReclaimSponsorship {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: sponsor.to_string(),
+            amount: bluechip(200_000_000),
+        })
+    );
+}
+
 #[test]
 fn test_race_condition_commits_crossing_threshold() {
     let mut deps = mock_dependencies_with_balance(&[Coin {
@@ -228,6 +638,10 @@ fn test_race_condition_commits_crossing_threshold() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res1 = execute(deps.as_mut(), env.clone(), info1, msg1).unwrap();
@@ -268,6 +682,10 @@ fn test_race_condition_commits_crossing_threshold() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: Some(Decimal::percent(10)),
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
     // After the H-cooldown change, a follower commit landing in the same
     // block as the threshold-crossing tx is rejected outright with
@@ -337,6 +755,10 @@ fn test_commit_crosses_threshold() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -400,6 +822,10 @@ fn test_commit_post_threshold_swap() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -415,6 +841,199 @@ fn test_commit_post_threshold_swap() {
     assert!(fee_state.total_fees_collected_1 > Uint128::zero());
 }
 
+#[test]
+fn test_gifted_commit_credits_beneficiary_and_rate_limits_payer() {
+    use crate::state::USER_LAST_COMMIT;
+
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(1_000_000_000),
+    }]);
+    setup_pool_post_threshold(&mut deps);
+    with_factory_oracle(&mut deps, Uint128::new(1_000_000));
+
+    let payer = deps.api.addr_make("checkout");
+    let fan = deps.api.addr_make("fan");
+    let commit_amount = Uint128::new(100_000_000);
+    let msg = ExecuteMsg::Commit {
+        asset: TokenInfo {
+            info: TokenType::Native {
+                denom: "ubluechip".to_string(),
+            },
+            amount: commit_amount,
+        },
+        transaction_deadline: None,
+        belief_price: None,
+        max_spread: None,
+        tier: None,
+        beneficiary: Some(fan.to_string()),
+        referrer: None,
+        allowlist_proof: None,
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(
+            &payer,
+            &[Coin {
+                denom: "ubluechip".to_string(),
+                amount: commit_amount,
+            }],
+        ),
+        msg,
+    )
+    .unwrap();
+
+    // Swap output goes to the beneficiary.
+    let recipient = res
+        .messages
+        .iter()
+        .find_map(|m| match &m.msg {
+            CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute { msg, .. }) => {
+                match from_json::<cw20::Cw20ExecuteMsg>(msg) {
+                    Ok(cw20::Cw20ExecuteMsg::Transfer { recipient, .. }) => Some(recipient),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(recipient, fan.to_string());
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "payer" && a.value == payer.to_string()));
+
+    assert!(COMMIT_INFO.has(&deps.storage, &fan));
+    assert!(!COMMIT_INFO.has(&deps.storage, &payer));
+    assert!(USER_LAST_COMMIT.has(&deps.storage, &payer));
+    assert!(!USER_LAST_COMMIT.has(&deps.storage, &fan));
+}
+
+#[test]
+fn test_referred_commit_splits_creator_fee_and_claims() {
+    use crate::msg::ReferralStatsResponse;
+    use crate::query::query;
+    use crate::state::{REFERRAL_FEE_SHARE, REFERRAL_STATS};
+
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(1_000_000_000),
+    }]);
+    setup_pool_post_threshold(&mut deps);
+    with_factory_oracle(&mut deps, Uint128::new(1_000_000));
+    REFERRAL_FEE_SHARE
+        .save(&mut deps.storage, &Decimal::percent(50))
+        .unwrap();
+
+    let committer = deps.api.addr_make("committer");
+    let promoter = deps.api.addr_make("promoter");
+    let commit_amount = Uint128::new(100_000_000);
+    let commit_msg = |referrer: &Addr| ExecuteMsg::Commit {
+        asset: TokenInfo {
+            info: TokenType::Native {
+                denom: "ubluechip".to_string(),
+            },
+            amount: commit_amount,
+        },
+        transaction_deadline: None,
+        belief_price: None,
+        max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: Some(referrer.to_string()),
+        allowlist_proof: None,
+    };
+    let funds = [Coin {
+        denom: "ubluechip".to_string(),
+        amount: commit_amount,
+    }];
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&committer, &funds),
+        commit_msg(&promoter),
+    )
+    .unwrap();
+
+    // Half of the creator's commit fee is withheld for the referrer.
+    let fee_info = COMMITFEEINFO.load(&deps.storage).unwrap();
+    let full_creator_fee = commit_amount.mul_floor(fee_info.commit_fee_creator);
+    let referral_fee = full_creator_fee.mul_floor(Decimal::percent(50));
+    let creator_paid = res
+        .messages
+        .iter()
+        .find_map(|m| match &m.msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount })
+                if *to_address == fee_info.creator_wallet_address.to_string() =>
+            {
+                Some(amount[0].amount)
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(creator_paid, full_creator_fee - referral_fee);
+
+    let stats = REFERRAL_STATS.load(&deps.storage, &promoter).unwrap();
+    assert_eq!(stats.referred_commits, 1);
+    assert_eq!(stats.unclaimed, referral_fee);
+    assert_eq!(stats.total_earned, referral_fee);
+    let resp: ReferralStatsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ReferralStats {
+                referrer: promoter.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(resp.referral_fee_share, Decimal::percent(50));
+    assert_eq!(resp.stats.unclaimed, referral_fee);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&promoter, &[]),
+        ExecuteMsg::ClaimReferralRewards {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: promoter.to_string(),
+            amount: vec![Coin {
+                denom: "ubluechip".to_string(),
+                amount: referral_fee,
+            }],
+        })
+    );
+    let stats = REFERRAL_STATS.load(&deps.storage, &promoter).unwrap();
+    assert!(stats.unclaimed.is_zero());
+    assert_eq!(stats.total_earned, referral_fee);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&promoter, &[]),
+        ExecuteMsg::ClaimReferralRewards {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NoReferralRewards {}));
+
+    // Referring yourself is rejected.
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&promoter, &funds),
+        commit_msg(&promoter),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::SelfReferral {}));
+}
+
 #[test]
 fn test_threshold_payout_integrity_check() {
     let mut deps = mock_dependencies();
@@ -917,6 +1536,10 @@ fn test_commit_reentrancy_protection() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -959,6 +1582,10 @@ fn test_commit_rate_limiting() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
@@ -1003,6 +1630,10 @@ fn test_commit_with_deadline() {
         transaction_deadline: Some(Timestamp::from_seconds(999_999)),
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -1325,9 +1956,17 @@ fn test_factory_impersonation_prevented() {
         },
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         commit_threshold_limit_usd: Uint128::new(350_000_000_000),
         position_nft_address: Addr::unchecked("NFT_contract"),
         token_address: Addr::unchecked("token_contract"),
+        protocol_fee_share: Default::default(),
+        raise_deadline: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
+        distribution_mode: Default::default(),
+        early_supporter_curve: None,
+        commit_allowlist: None,
     };
     let info = message_info(&Addr::unchecked("fake_factory"), &[]); // Wrong sender!
     let err = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap_err();
@@ -1367,6 +2006,10 @@ fn test_commit_with_changing_oracle_prices() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(deps.as_mut(), env.clone(), info1, msg1).unwrap();
@@ -1394,6 +2037,10 @@ fn test_commit_with_changing_oracle_prices() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(deps.as_mut(), env, info2, msg2).unwrap();
@@ -1442,6 +2089,10 @@ fn test_threshold_crossing_depends_on_oracle_price() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(deps1.as_mut(), env.clone(), info1, msg1).unwrap();
@@ -1479,6 +2130,10 @@ fn test_threshold_crossing_depends_on_oracle_price() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(deps2.as_mut(), env, info2, msg2).unwrap();
@@ -1562,6 +2217,10 @@ fn test_oracle_conversion_precision_various_prices() {
             transaction_deadline: None,
             belief_price: None,
             max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
         };
 
         execute(deps.as_mut(), env, info, msg).unwrap();
@@ -1613,6 +2272,10 @@ fn test_extreme_oracle_prices() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res_low = execute(deps_low.as_mut(), env.clone(), info_low, msg_low);
@@ -1647,6 +2310,10 @@ fn test_extreme_oracle_prices() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res_high = execute(deps_high.as_mut(), env, info_high, msg_high);
@@ -1696,6 +2363,10 @@ fn test_usd_tracking_consistency_across_commits() {
             transaction_deadline: None,
             belief_price: None,
             max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
         };
 
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -1751,6 +2422,10 @@ fn test_commit_with_zero_oracle_price() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let result = execute(deps.as_mut(), env, info, msg);
@@ -1791,6 +2466,10 @@ fn test_usd_calculation_overflow() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let result = execute(deps.as_mut(), env, info, msg);
@@ -1855,6 +2534,10 @@ fn test_rounding_error_accumulation() {
             transaction_deadline: None,
             belief_price: None,
             max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
         };
 
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -2233,6 +2916,10 @@ fn test_race_condition_not_manually_set() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let alice_res = execute(deps.as_mut(), env.clone(), alice_info, alice_msg).unwrap();
@@ -2271,6 +2958,10 @@ fn test_race_condition_not_manually_set() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: Some(Decimal::percent(10)),
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     // Same-block follower commit is now blocked by the post-threshold
@@ -2356,6 +3047,10 @@ fn test_concurrent_commits_both_recorded() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(deps.as_mut(), env.clone(), alice_info.clone(), alice_msg).unwrap();
@@ -2406,6 +3101,10 @@ fn test_concurrent_commits_both_recorded() {
         // so the hard cap on Bob's max_spread is 5% (the default-cap
         // ceiling). 10% is now rejected.
         max_spread: Some(Decimal::percent(5)),
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     // Same-block follower: rejected by post-threshold cooldown.
@@ -2516,6 +3215,8 @@ pub fn setup_pool_with_reserves(
         creator_excess_liquidity_lock_days: 7,
         min_commit_usd_pre_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_PRE_THRESHOLD,
         min_commit_usd_post_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_POST_THRESHOLD,
+        max_commit_usd_per_wallet: None,
+        max_commit_share_per_wallet: None,
     };
     COMMIT_LIMIT_INFO
         .save(&mut deps.storage, &commit_config)
@@ -3077,6 +3778,10 @@ fn test_commit_and_swap_with_price_change() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(
@@ -3112,6 +3817,10 @@ fn test_commit_and_swap_with_price_change() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(
@@ -3151,6 +3860,10 @@ fn test_commit_and_swap_with_price_change() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(
@@ -3213,6 +3926,10 @@ fn post_threshold_commit_rejects_when_pre_state_reserve_below_min() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
@@ -3222,3 +3939,359 @@ fn post_threshold_commit_rejects_when_pre_state_reserve_below_min() {
         err
     );
 }
+
+fn launch_swap(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    env: cosmwasm_std::Env,
+    trader: &str,
+    amount: u128,
+) -> Result<cosmwasm_std::Response, ContractError> {
+    let amount = Uint128::new(amount);
+    execute(
+        deps.as_mut(),
+        env,
+        message_info(
+            &Addr::unchecked(trader),
+            &[Coin {
+                denom: "ubluechip".to_string(),
+                amount,
+            }],
+        ),
+        ExecuteMsg::SimpleSwap {
+            offer_asset: TokenInfo {
+                info: TokenType::Native {
+                    denom: "ubluechip".to_string(),
+                },
+                amount,
+            },
+            belief_price: None,
+            max_spread: Some(Decimal::percent(10)),
+            allow_high_max_spread: Some(true),
+            to: None,
+            transaction_deadline: None,
+        },
+    )
+}
+
+#[test]
+fn test_launch_window_caps_swap_size_and_wallet_buys() {
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(1_000_000_000),
+    }]);
+    setup_pool_post_threshold(&mut deps);
+
+    let mut env = mock_env();
+    let ends_at_block = env.block.height + 100;
+    LAUNCH_WINDOW
+        .save(
+            &mut deps.storage,
+            &LaunchWindow {
+                ends_at_block,
+                // 1% of the 23.5k bluechip reserve = 235 bluechip per swap.
+                max_swap_bps: 100,
+                wallet_buy_cap: Uint128::new(3_000_000_000),
+            },
+        )
+        .unwrap();
+
+    match launch_swap(&mut deps, env.clone(), "sniper", 300_000_000) {
+        Err(ContractError::LaunchSwapTooLarge {
+            max_offer,
+            until_block,
+        }) => {
+            assert_eq!(max_offer, Uint128::new(235_000_000));
+            assert_eq!(until_block, ends_at_block);
+        }
+        other => panic!("expected LaunchSwapTooLarge, got {:?}", other),
+    }
+
+    // Within the size cap: accepted and counted against the wallet. The
+    // clock advances between calls because unit tests don't revert the
+    // rate-limit write of a rejected swap.
+    env.block.time = env.block.time.plus_seconds(60);
+    launch_swap(&mut deps, env.clone(), "sniper", 200_000_000).unwrap();
+    let bought = LAUNCH_WALLET_BUYS
+        .load(&deps.storage, &Addr::unchecked("sniper"))
+        .unwrap();
+    assert!(bought > Uint128::zero() && bought < Uint128::new(3_000_000_000));
+
+    // A second buy would push the wallet past its cap.
+    env.block.time = env.block.time.plus_seconds(60);
+    match launch_swap(&mut deps, env.clone(), "sniper", 100_000_000) {
+        Err(ContractError::LaunchWalletCapExceeded { remaining, .. }) => {
+            assert_eq!(remaining, Uint128::new(3_000_000_000) - bought);
+        }
+        other => panic!("expected LaunchWalletCapExceeded, got {:?}", other),
+    }
+
+    // Other wallets have their own allowance.
+    launch_swap(&mut deps, env.clone(), "another_trader", 100_000_000).unwrap();
+
+    // Once the window ends the limits fall away.
+    env.block.height = ends_at_block;
+    env.block.time = env.block.time.plus_seconds(60);
+    launch_swap(&mut deps, env, "sniper", 300_000_000).unwrap();
+}
+
+#[test]
+fn test_threshold_crossing_arms_launch_window() {
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(10_000_000_000),
+    }]);
+    setup_pool_storage(&mut deps);
+    LAUNCH_PROTECTION_CONFIG
+        .save(
+            &mut deps.storage,
+            &LaunchProtectionConfig {
+                window_blocks: 600,
+                max_swap_bps: 100,
+                max_wallet_buy_bps: 50,
+                launch_fee: Decimal::percent(5),
+                launch_fee_decay_blocks: 300,
+            },
+        )
+        .unwrap();
+    THRESHOLD_PROCESSING.save(&mut deps.storage, &false).unwrap();
+    USD_RAISED_FROM_COMMIT
+        .save(&mut deps.storage, &Uint128::new(24_900_000_000))
+        .unwrap();
+    with_factory_oracle(&mut deps, Uint128::new(1_000_000));
+
+    let env = mock_env();
+    let commit_amount = Uint128::new(200_000_000);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(
+            &Addr::unchecked("whale"),
+            &[Coin {
+                denom: "ubluechip".to_string(),
+                amount: commit_amount,
+            }],
+        ),
+        ExecuteMsg::Commit {
+            asset: TokenInfo {
+                info: TokenType::Native {
+                    denom: "ubluechip".to_string(),
+                },
+                amount: commit_amount,
+            },
+            transaction_deadline: None,
+            belief_price: None,
+            max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
+        },
+    )
+    .unwrap();
+
+    // The window opens when the cooldown lifts; the wallet cap is 0.5%
+    // of the 350k creator-token pool seed.
+    let window = LAUNCH_WINDOW.load(&deps.storage).unwrap();
+    assert_eq!(
+        window.ends_at_block,
+        env.block.height + POST_THRESHOLD_COOLDOWN_BLOCKS + 1 + 600
+    );
+    assert_eq!(window.max_swap_bps, 100);
+    assert_eq!(window.wallet_buy_cap, Uint128::new(1_750_000_000));
+    let trading_resumes_at = env.block.height + POST_THRESHOLD_COOLDOWN_BLOCKS + 1;
+    assert_eq!(
+        LAUNCH_FEE.load(&deps.storage).unwrap(),
+        LaunchFeeSchedule {
+            initial_fee: Decimal::percent(5),
+            start_block: trading_resumes_at,
+            end_block: trading_resumes_at + 300,
+        }
+    );
+
+    let bin = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::LaunchProtection {
+            wallet: Some(deps.api.addr_make("nobody").to_string()),
+        },
+    )
+    .unwrap();
+    let resp: LaunchProtectionResponse = from_json(bin).unwrap();
+    assert!(resp.active);
+    assert_eq!(resp.window, Some(window));
+    assert_eq!(resp.config.window_blocks, 600);
+    assert!(resp.max_offer_0.is_some());
+    assert_eq!(resp.wallet_bought, Some(Uint128::zero()));
+}
+
+#[test]
+fn test_launch_fee_surcharge_goes_to_creator_pot() {
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(1_000_000_000),
+    }]);
+    setup_pool_post_threshold(&mut deps);
+
+    let mut env = mock_env();
+    LAUNCH_FEE
+        .save(
+            &mut deps.storage,
+            &LaunchFeeSchedule {
+                initial_fee: Decimal::percent(3),
+                start_block: env.block.height,
+                end_block: env.block.height + 100,
+            },
+        )
+        .unwrap();
+    let fees_before = POOL_FEE_STATE.load(&deps.storage).unwrap();
+
+    // At the start of the schedule the swap pays 3% instead of the 0.3% LP
+    // fee; the LP keeps a tenth of the commission and the rest is surcharge.
+    let res = launch_swap(&mut deps, env.clone(), "sniper", 100_000_000).unwrap();
+    let attr = |key: &str| {
+        res.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .unwrap()
+            .value
+            .parse::<u128>()
+            .unwrap()
+    };
+    let commission = attr("commission_amount");
+    let surcharge = attr("launch_fee_amount");
+    assert!(surcharge > 0);
+    assert_eq!(surcharge, commission - commission / 10);
+
+    let pot = CREATOR_FEE_POT.load(&deps.storage).unwrap();
+    assert_eq!(pot.amount_1, Uint128::new(surcharge));
+    assert!(pot.amount_0.is_zero());
+    let fees_after = POOL_FEE_STATE.load(&deps.storage).unwrap();
+    assert_eq!(
+        fees_after.fee_reserve_1 - fees_before.fee_reserve_1,
+        Uint128::new(commission - surcharge)
+    );
+
+    // Once the schedule runs out the swap is back to the plain LP fee.
+    env.block.height += 100;
+    env.block.time = env.block.time.plus_seconds(60);
+    let res = launch_swap(&mut deps, env, "sniper", 100_000_000).unwrap();
+    let launch_fee = res
+        .attributes
+        .iter()
+        .find(|a| a.key == "launch_fee_amount")
+        .unwrap();
+    assert_eq!(launch_fee.value, "0");
+    assert_eq!(
+        CREATOR_FEE_POT.load(&deps.storage).unwrap().amount_1,
+        Uint128::new(surcharge)
+    );
+}
+
+#[test]
+fn test_simulate_commit_previews_each_phase_without_state_changes() {
+    use crate::msg::{CommitPhase, SimulateCommitResponse};
+
+    let mut deps = mock_dependencies_with_balance(&[Coin {
+        denom: "ubluechip".to_string(),
+        amount: Uint128::new(10_000_000_000),
+    }]);
+    setup_pool_storage(&mut deps);
+    with_factory_oracle(&mut deps, Uint128::new(1_000_000));
+
+    let alice = deps.api.addr_make("alice");
+    let bluechip = |amount: u128| TokenInfo {
+        info: TokenType::Native {
+            denom: "ubluechip".to_string(),
+        },
+        amount: Uint128::new(amount),
+    };
+    let simulate = |deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, amount: u128| {
+        from_json::<SimulateCommitResponse>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::SimulateCommit {
+                    asset: bluechip(amount),
+                    wallet: Some(alice.to_string()),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    let commit_return = THRESHOLD_PAYOUT_AMOUNTS
+        .load(&deps.storage)
+        .unwrap()
+        .commit_return_amount;
+
+    // $1k of a $25k raise: a pre-threshold commit worth 1/25 of the
+    // commit-return tranche, with fees matching the real commit.
+    let preview = simulate(&deps, 1_000_000_000);
+    assert_eq!(preview.phase, CommitPhase::PreThreshold);
+    assert_eq!(preview.usd_value, Uint128::new(1_000_000_000));
+    assert_eq!(preview.ledger_usd, Uint128::new(1_000_000_000));
+    assert_eq!(
+        preview.projected_commit_reward,
+        commit_return.multiply_ratio(1u128, 25u128)
+    );
+    assert!(preview.swap_offer_amount.is_zero());
+    assert!(USD_RAISED_FROM_COMMIT.load(&deps.storage).unwrap().is_zero());
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(
+            &alice,
+            &[Coin {
+                denom: "ubluechip".to_string(),
+                amount: Uint128::new(1_000_000_000),
+            }],
+        ),
+        ExecuteMsg::Commit {
+            asset: bluechip(1_000_000_000),
+            transaction_deadline: None,
+            belief_price: None,
+            max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        NATIVE_RAISED_FROM_COMMIT.load(&deps.storage).unwrap(),
+        preview.amount_after_fees
+    );
+
+    // $30k with $24k left: the crossing credits exactly what is left and
+    // swaps the excess, up to 3% of the seeded reserve.
+    let preview = simulate(&deps, 30_000_000_000);
+    assert_eq!(preview.phase, CommitPhase::ThresholdCrossing);
+    assert_eq!(preview.ledger_usd, Uint128::new(24_000_000_000));
+    assert_eq!(
+        preview.projected_commit_reward,
+        commit_return.multiply_ratio(24u128, 25u128)
+    );
+    assert!(!preview.swap_offer_amount.is_zero());
+    assert!(!preview.swap_return_amount.is_zero());
+    assert!(!preview.excess_refund.is_zero());
+    assert!(!IS_THRESHOLD_HIT.load(&deps.storage).unwrap());
+
+    // Only bluechip can be committed.
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::SimulateCommit {
+            asset: TokenInfo {
+                info: TokenType::CreatorToken {
+                    contract_addr: Addr::unchecked("token_contract"),
+                },
+                amount: Uint128::new(1_000_000),
+            },
+            wallet: None,
+        },
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("Asset"));
+}
@@ -32,6 +32,8 @@ pub fn setup_pool_with_excess_config(deps: &mut OwnedDeps<MockStorage, MockApi,
         creator_excess_liquidity_lock_days: 14,
         min_commit_usd_pre_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_PRE_THRESHOLD,
         min_commit_usd_post_threshold: crate::state::DEFAULT_MIN_COMMIT_USD_POST_THRESHOLD,
+        max_commit_usd_per_wallet: None,
+        max_commit_share_per_wallet: None,
     };
 
     COMMIT_LIMIT_INFO
@@ -107,6 +109,10 @@ fn test_threshold_with_excess_creates_position() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -325,6 +331,10 @@ fn test_no_excess_when_under_cap() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(deps.as_mut(), env, info, msg).unwrap();
@@ -394,6 +404,10 @@ fn test_commit_threshold_overshoot_split() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
@@ -581,6 +595,10 @@ fn test_commit_exact_threshold() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
@@ -685,6 +703,10 @@ fn test_concurrent_threshold_crossing_attempts() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let err = execute(deps.as_mut(), env.clone(), info2, msg).unwrap_err();
@@ -926,6 +948,10 @@ fn test_accumulated_bluechips_respected() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -1003,6 +1029,10 @@ fn test_concurrent_threshold_crossing_race_condition() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     // User 2 commits enough to cross (simulating same block execution)
@@ -1074,6 +1104,10 @@ fn test_paused_pool_rejects_pre_threshold_commit() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -1122,6 +1156,10 @@ fn test_paused_pool_rejects_post_threshold_commit() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -1159,6 +1197,10 @@ fn test_unpaused_pool_accepts_commit_after_previously_paused() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     // Confirm paused rejects.
@@ -1234,6 +1276,10 @@ fn test_commit_rejects_below_pre_threshold_floor() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
@@ -1279,6 +1325,10 @@ fn test_commit_rejects_below_post_threshold_floor() {
         transaction_deadline: None,
         belief_price: None,
         max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
     };
 
     let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
@@ -1353,6 +1403,10 @@ mod native_raised_net_semantics_tests {
             transaction_deadline: None,
             belief_price: None,
             max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
         };
 
         execute(deps.as_mut(), env, info, msg).expect("pre-threshold commit must succeed");
@@ -1419,6 +1473,10 @@ mod native_raised_net_semantics_tests {
             transaction_deadline: None,
             belief_price: None,
             max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
         };
 
         let res = execute(deps.as_mut(), env, info, msg)
@@ -1492,6 +1550,10 @@ mod native_raised_net_semantics_tests {
             transaction_deadline: None,
             belief_price: None,
             max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
         };
 
         let res = execute(deps.as_mut(), env, info, msg)
@@ -1789,3 +1851,1082 @@ mod native_raised_net_semantics_tests {
         );
     }
 }
+
+// ===========================================================================
+// Failed-raise refunds
+// ===========================================================================
+
+#[test]
+fn test_failed_raise_refunds_committers_pro_rata() {
+    use crate::msg::{CommitRefundResponse, QueryMsg};
+    use crate::query::query;
+    use crate::state::{COMMIT_REFUND_STATE, NATIVE_RAISED_FROM_COMMIT, RAISE_DEADLINE};
+
+    let mut deps = mock_dependencies();
+    setup_pool_with_excess_config(&mut deps);
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { .. } => {
+            let response = ConversionResponse {
+                amount: Uint128::new(5_000_000),
+                rate_used: Uint128::new(1_000_000),
+                timestamp: 1571797419u64,
+            };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::InvalidRequest {
+            error: "Unknown query".to_string(),
+            request: Binary::default(),
+        }),
+    });
+    let env = mock_env();
+    let deadline = env.block.time.plus_seconds(3_600);
+    RAISE_DEADLINE.save(&mut deps.storage, &deadline).unwrap();
+
+    let commit_msg = |amount: u128| ExecuteMsg::Commit {
+        asset: TokenInfo {
+            info: TokenType::Native {
+                denom: "ubluechip".to_string(),
+            },
+            amount: Uint128::new(amount),
+        },
+        transaction_deadline: None,
+        belief_price: None,
+        max_spread: None,
+        tier: None,
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
+    };
+    let alice = deps.api.addr_make("alice");
+    let bob = deps.api.addr_make("bob");
+    for (who, amount) in [(&alice, 100_000_000u128), (&bob, 300_000_000u128)] {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(who, &[coin(amount, "ubluechip")]),
+            commit_msg(amount),
+        )
+        .unwrap();
+    }
+    // 6% commit fees: 94M + 282M net.
+    let net_raised = NATIVE_RAISED_FROM_COMMIT.load(&deps.storage).unwrap();
+    assert_eq!(net_raised, Uint128::new(376_000_000));
+
+    // Raise still live: no refunds.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&alice, &[]),
+        ExecuteMsg::ClaimCommitRefund {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::RefundsNotOpen {}));
+
+    let mut late = env.clone();
+    late.block.time = deadline;
+
+    // Commits are closed once the deadline passes.
+    let err = execute(
+        deps.as_mut(),
+        late.clone(),
+        message_info(&bob, &[coin(100_000_000, "ubluechip")]),
+        commit_msg(100_000_000),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::RaiseExpired { .. }));
+
+    let status: CommitRefundResponse = from_json(
+        query(
+            deps.as_ref(),
+            late.clone(),
+            QueryMsg::CommitRefund {
+                wallet: Some(alice.to_string()),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(status.refunds_open);
+    assert_eq!(status.raise_deadline, Some(deadline));
+    assert_eq!(status.wallet_refund, Some(Uint128::new(94_000_000)));
+
+    let res = execute(
+        deps.as_mut(),
+        late.clone(),
+        message_info(&alice, &[]),
+        ExecuteMsg::ClaimCommitRefund {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: alice.to_string(),
+            amount: vec![coin(94_000_000, "ubluechip")],
+        })
+    );
+    assert!(COMMIT_LEDGER.may_load(&deps.storage, &alice).unwrap().is_none());
+
+    let err = execute(
+        deps.as_mut(),
+        late.clone(),
+        message_info(&alice, &[]),
+        ExecuteMsg::ClaimCommitRefund {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NoCommitRefund {}));
+
+    execute(
+        deps.as_mut(),
+        late,
+        message_info(&bob, &[]),
+        ExecuteMsg::ClaimCommitRefund {},
+    )
+    .unwrap();
+    let state = COMMIT_REFUND_STATE.load(&deps.storage).unwrap();
+    assert_eq!(state.refunded_bluechip, net_raised);
+    assert_eq!(state.claim_count, 2);
+}
+
+// ===========================================================================
+// Subscriptions
+// ===========================================================================
+
+#[test]
+fn test_subscription_renews_from_escrow_and_cancel_refunds_rest() {
+    use crate::msg::{QueryMsg, SubscriptionStatusResponse};
+    use crate::query::query;
+
+    let mut deps = mock_dependencies();
+    setup_pool_with_excess_config(&mut deps);
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { .. } => {
+            let response = ConversionResponse {
+                amount: Uint128::new(5_000_000),
+                rate_used: Uint128::new(1_000_000),
+                // Zero skips the staleness check across the day jumps.
+                timestamp: 0,
+            };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::InvalidRequest {
+            error: "Unknown query".to_string(),
+            request: Binary::default(),
+        }),
+    });
+    let mut env = mock_env();
+    let alice = deps.api.addr_make("alice");
+    let keeper = deps.api.addr_make("keeper");
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&alice, &[coin(250_000_000, "ubluechip")]),
+        ExecuteMsg::Subscribe {
+            usd_per_period: Uint128::new(100_000_000),
+            period_seconds: 60,
+            periods: 3,
+            tier: None,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidSubscription { .. }));
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&alice, &[coin(250_000_000, "ubluechip")]),
+        ExecuteMsg::Subscribe {
+            usd_per_period: Uint128::new(100_000_000),
+            period_seconds: 86_400,
+            periods: 3,
+            tier: None,
+        },
+    )
+    .unwrap();
+    let renew = ExecuteMsg::RenewSubscription {
+        wallet: alice.to_string(),
+    };
+
+    // The keeper is paid from alice's escrow, never by the factory.
+    let keeper_fee = CosmosMsg::Bank(BankMsg::Send {
+        to_address: keeper.to_string(),
+        amount: vec![coin(500_000, "ubluechip")],
+    });
+    let pays_factory = |res: &cosmwasm_std::Response| {
+        res.messages.iter().any(|m| matches!(
+            &m.msg,
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) if contract_addr == "factory_contract"
+        ))
+    };
+
+    // First period is due at once and commits as alice.
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&keeper, &[]),
+        renew.clone(),
+    )
+    .unwrap();
+    assert!(!pays_factory(&res));
+    assert!(res.messages.iter().any(|m| m.msg == keeper_fee));
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "keeper_fee" && a.value == "500000"));
+    let committed = COMMIT_INFO.load(&deps.storage, &alice).unwrap();
+    assert_eq!(committed.total_paid_bluechip, Uint128::new(100_000_000));
+    assert!(COMMIT_LEDGER.has(&deps.storage, &alice));
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&keeper, &[]),
+        renew.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::SubscriptionNotDue { .. }));
+
+    let status: SubscriptionStatusResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::SubscriptionStatus {
+                wallet: alice.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(status.next_due, Some(env.block.time.plus_seconds(86_400)));
+    assert!(!status.is_due);
+    assert_eq!(
+        status.subscription.unwrap().escrow_bluechip,
+        Uint128::new(149_500_000)
+    );
+
+    env.block.time = env.block.time.plus_seconds(86_400);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&keeper, &[]),
+        renew.clone(),
+    )
+    .unwrap();
+    assert!(!pays_factory(&res));
+    assert!(res.messages.iter().any(|m| m.msg == keeper_fee));
+
+    // 49M left can't cover the third period.
+    env.block.time = env.block.time.plus_seconds(86_400);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&keeper, &[]),
+        renew,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::SubscriptionUnderfunded { .. }));
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&alice, &[]),
+        ExecuteMsg::CancelSubscription {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: alice.to_string(),
+            amount: vec![coin(49_000_000, "ubluechip")],
+        })
+    );
+    let status: SubscriptionStatusResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::SubscriptionStatus {
+                wallet: alice.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(status.subscription.is_none());
+    assert!(!status.is_due);
+}
+
+#[test]
+fn test_subscription_self_renewal_pays_no_keeper_fee() {
+    let mut deps = mock_dependencies();
+    setup_pool_with_excess_config(&mut deps);
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { .. } => {
+            let response = ConversionResponse {
+                amount: Uint128::new(5_000_000),
+                rate_used: Uint128::new(1_000_000),
+                timestamp: 0,
+            };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::InvalidRequest {
+            error: "Unknown query".to_string(),
+            request: Binary::default(),
+        }),
+    });
+    let mut env = mock_env();
+    let alice = deps.api.addr_make("alice");
+    let keeper = deps.api.addr_make("keeper");
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&alice, &[coin(300_400_000, "ubluechip")]),
+        ExecuteMsg::Subscribe {
+            usd_per_period: Uint128::new(100_000_000),
+            period_seconds: 86_400,
+            periods: 3,
+            tier: None,
+        },
+    )
+    .unwrap();
+    let renew = ExecuteMsg::RenewSubscription {
+        wallet: alice.to_string(),
+    };
+    let keeper_fee = |res: &cosmwasm_std::Response| {
+        res.attributes
+            .iter()
+            .find(|a| a.key == "keeper_fee")
+            .map(|a| a.value.clone())
+    };
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&alice, &[]),
+        renew.clone(),
+    )
+    .unwrap();
+    assert_eq!(keeper_fee(&res).as_deref(), Some("0"));
+
+    // A due period renewed by the subscriber themselves pays nothing;
+    // the same period from a keeper would.
+    env.block.time = env.block.time.plus_seconds(86_400);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&alice, &[]),
+        renew.clone(),
+    )
+    .unwrap();
+    assert_eq!(keeper_fee(&res).as_deref(), Some("0"));
+    assert!(!res.messages.iter().any(|m| matches!(
+        &m.msg,
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) if contract_addr == "factory_contract"
+    )));
+
+    env.block.time = env.block.time.plus_seconds(86_400);
+    // The last period leaves 400K in escrow, so the keeper's 0.5% is
+    // capped there rather than blocking the renewal.
+    let res = execute(deps.as_mut(), env, message_info(&keeper, &[]), renew).unwrap();
+    assert_eq!(keeper_fee(&res).as_deref(), Some("400000"));
+    assert!(res.messages.iter().any(|m| m.msg
+        == CosmosMsg::Bank(BankMsg::Send {
+            to_address: keeper.to_string(),
+            amount: vec![coin(400_000, "ubluechip")],
+        })));
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "subscription_complete" && a.value == "true"));
+}
+
+#[test]
+fn test_tier_priced_subscription_renews_at_uneven_oracle_rate() {
+    use pool_factory_interfaces::SubscriptionTier;
+
+    let mut deps = mock_dependencies();
+    setup_pool_with_excess_config(&mut deps);
+    // $3 per bluechip: $10 is 3_333_333.33 bluechip, so a floor-rounded
+    // renewal would convert back to $9.999999 and miss the tier.
+    with_factory_oracle(&mut deps, Uint128::new(3_000_000));
+    let env = mock_env();
+    let fan = deps.api.addr_make("fan");
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        ExecuteMsg::SetSubscriptionTiers {
+            creator: "creator".to_string(),
+            tiers: vec![SubscriptionTier {
+                name: "gold".to_string(),
+                usd_price: Uint128::new(10_000_000),
+                duration_seconds: 86_400 * 30,
+            }],
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&fan, &[coin(10_000_000, "ubluechip")]),
+        ExecuteMsg::Subscribe {
+            usd_per_period: Uint128::new(10_000_000),
+            period_seconds: 86_400 * 30,
+            periods: 2,
+            tier: Some("gold".to_string()),
+        },
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&fan, &[]),
+        ExecuteMsg::RenewSubscription {
+            wallet: fan.to_string(),
+        },
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "renewal_bluechip" && a.value == "3333334"));
+    let membership = crate::state::MEMBERSHIPS.load(&deps.storage, &fan).unwrap();
+    assert_eq!(membership.tier, "gold");
+    assert_eq!(membership.expires_at, env.block.time.plus_seconds(86_400 * 30));
+}
+
+#[test]
+fn test_tier_tagged_commits_extend_membership() {
+    use crate::msg::{ActiveMembershipResponse, QueryMsg};
+    use crate::query::query;
+    use pool_factory_interfaces::SubscriptionTier;
+
+    let mut deps = mock_dependencies();
+    setup_pool_with_excess_config(&mut deps);
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { .. } => {
+            let response = ConversionResponse {
+                amount: Uint128::new(5_000_000),
+                rate_used: Uint128::new(1_000_000),
+                timestamp: 0,
+            };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::InvalidRequest {
+            error: "Unknown query".to_string(),
+            request: Binary::default(),
+        }),
+    });
+    let mut env = mock_env();
+    let fan = deps.api.addr_make("fan");
+    let tiers = vec![
+        SubscriptionTier {
+            name: "gold".to_string(),
+            usd_price: Uint128::new(2_000_000),
+            duration_seconds: 86_400 * 30,
+        },
+        SubscriptionTier {
+            name: "platinum".to_string(),
+            usd_price: Uint128::new(10_000_000),
+            duration_seconds: 86_400 * 30,
+        },
+    ];
+    let set_tiers = |creator: &str| ExecuteMsg::SetSubscriptionTiers {
+        creator: creator.to_string(),
+        tiers: tiers.clone(),
+    };
+
+    // Only the factory may forward, and only for the creator wallet.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&Addr::unchecked("creator"), &[]),
+        set_tiers("creator"),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        set_tiers("someone_else"),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&Addr::unchecked("factory_contract"), &[]),
+        set_tiers("creator"),
+    )
+    .unwrap();
+
+    let commit_msg = |tier: &str| ExecuteMsg::Commit {
+        asset: TokenInfo {
+            info: TokenType::Native {
+                denom: "ubluechip".to_string(),
+            },
+            amount: Uint128::new(5_000_000),
+        },
+        transaction_deadline: None,
+        belief_price: None,
+        max_spread: None,
+        tier: Some(tier.to_string()),
+        beneficiary: None,
+        referrer: None,
+        allowlist_proof: None,
+    };
+    let membership = |deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, env: &cosmwasm_std::Env| {
+        from_json::<ActiveMembershipResponse>(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ActiveMembership {
+                    wallet: fan.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&fan, &[coin(5_000_000, "ubluechip")]),
+        commit_msg("bronze"),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::UnknownSubscriptionTier { .. }));
+    // Unit-test storage keeps the rejected commit's rate-limit stamp.
+    env.block.time = env.block.time.plus_seconds(3_600);
+    // The oracle values every commit at $5, short of platinum's $10.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&fan, &[coin(5_000_000, "ubluechip")]),
+        commit_msg("platinum"),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::TierUnderpaid { .. }));
+    env.block.time = env.block.time.plus_seconds(3_600);
+
+    // $5 buys two whole $2 gold periods.
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&fan, &[coin(5_000_000, "ubluechip")]),
+        commit_msg("gold"),
+    )
+    .unwrap();
+    let first_expiry = env.block.time.plus_seconds(2 * 86_400 * 30);
+    let status = membership(&deps, &env);
+    assert_eq!(status.tier.as_deref(), Some("gold"));
+    assert_eq!(status.expires_at, Some(first_expiry));
+    assert!(status.active);
+
+    // Renewing before expiry stacks on the current expiry.
+    env.block.time = env.block.time.plus_seconds(86_400);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&fan, &[coin(5_000_000, "ubluechip")]),
+        commit_msg("gold"),
+    )
+    .unwrap();
+    let stacked_expiry = first_expiry.plus_seconds(2 * 86_400 * 30);
+    assert_eq!(membership(&deps, &env).expires_at, Some(stacked_expiry));
+
+    env.block.time = stacked_expiry;
+    let status = membership(&deps, &env);
+    assert_eq!(status.tier.as_deref(), Some("gold"));
+    assert!(!status.active);
+}
+
+// ===========================================================================
+// Creator-reward vesting
+// ===========================================================================
+
+#[test]
+fn test_creator_reward_vests_linearly_after_cliff() {
+    use crate::commit::threshold_payout::trigger_threshold_payout;
+    use crate::state::{
+        CREATOR_VESTING, CREATOR_VESTING_CONFIG, NATIVE_RAISED_FROM_COMMIT, POOL_FEE_STATE,
+        POOL_INFO, THRESHOLD_PAYOUT_AMOUNTS,
+    };
+    use pool_factory_interfaces::CreatorVestingConfig;
+
+    let mut deps = mock_dependencies();
+    setup_pool_storage(&mut deps);
+    NATIVE_RAISED_FROM_COMMIT
+        .save(&mut deps.storage, &Uint128::new(1_000_000))
+        .unwrap();
+    CREATOR_VESTING_CONFIG
+        .save(
+            &mut deps.storage,
+            &CreatorVestingConfig {
+                cliff_seconds: 1_000,
+                duration_seconds: 10_000,
+            },
+        )
+        .unwrap();
+
+    let env = mock_env();
+    let pool_info = POOL_INFO.load(&deps.storage).unwrap();
+    let mut pool_state = POOL_STATE.load(&deps.storage).unwrap();
+    let mut pool_fee_state = POOL_FEE_STATE.load(&deps.storage).unwrap();
+    let commit_config = COMMIT_LIMIT_INFO.load(&deps.storage).unwrap();
+    let payout = THRESHOLD_PAYOUT_AMOUNTS.load(&deps.storage).unwrap();
+    let fee_info = COMMITFEEINFO.load(&deps.storage).unwrap();
+    let msgs = trigger_threshold_payout(
+        &mut deps.storage,
+        &pool_info,
+        &mut pool_state,
+        &mut pool_fee_state,
+        &commit_config,
+        &payout,
+        &fee_info,
+        &env,
+    )
+    .unwrap();
+
+    // The creator reward is minted to the pool, not the creator.
+    let creator_mint = msgs
+        .other_msgs
+        .iter()
+        .find_map(|m| match m {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                match from_json::<cw20::Cw20ExecuteMsg>(msg) {
+                    Ok(cw20::Cw20ExecuteMsg::Mint { recipient, amount })
+                        if amount == payout.creator_reward_amount =>
+                    {
+                        Some(recipient)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(creator_mint, env.contract.address.to_string());
+
+    let creator = fee_info.creator_wallet_address.clone();
+    let claim = |deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+                 sender: &Addr,
+                 secs: u64| {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(secs);
+        execute(
+            deps.as_mut(),
+            env,
+            message_info(sender, &[]),
+            ExecuteMsg::ClaimVestedCreatorReward {},
+        )
+    };
+    let transferred = |res: cosmwasm_std::Response| match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            match from_json::<cw20::Cw20ExecuteMsg>(msg).unwrap() {
+                cw20::Cw20ExecuteMsg::Transfer { amount, .. } => amount,
+                other => panic!("expected Transfer, got {:?}", other),
+            }
+        }
+        other => panic!("expected Wasm Execute, got {:?}", other),
+    };
+
+    // Nothing before the cliff, and only the creator may claim.
+    let err = claim(&mut deps, &creator, 999).unwrap_err();
+    assert!(matches!(err, ContractError::NoVestedCreatorReward {}));
+    let err = claim(&mut deps, &Addr::unchecked("stranger"), 5_000).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Linear from the crossing, not from the cliff.
+    let quarter = payout.creator_reward_amount.multiply_ratio(1u128, 4u128);
+    assert_eq!(transferred(claim(&mut deps, &creator, 2_500).unwrap()), quarter);
+    let err = claim(&mut deps, &creator, 2_500).unwrap_err();
+    assert!(matches!(err, ContractError::NoVestedCreatorReward {}));
+
+    // Past the end the remainder is released and the schedule is spent.
+    assert_eq!(
+        transferred(claim(&mut deps, &creator, 20_000).unwrap()),
+        payout.creator_reward_amount - quarter
+    );
+    let vesting = CREATOR_VESTING.load(&deps.storage).unwrap();
+    assert_eq!(vesting.claimed, payout.creator_reward_amount);
+    let err = claim(&mut deps, &creator, 30_000).unwrap_err();
+    assert!(matches!(err, ContractError::NoVestedCreatorReward {}));
+}
+
+// ===========================================================================
+// Pull-mode distribution
+// ===========================================================================
+
+#[test]
+fn test_pull_distribution_committers_claim_own_share() {
+    use crate::commit::threshold_payout::trigger_threshold_payout;
+    use crate::state::{
+        DISTRIBUTION_MODE, NATIVE_RAISED_FROM_COMMIT, POOL_FEE_STATE, POOL_INFO,
+        PULL_DISTRIBUTION, THRESHOLD_PAYOUT_AMOUNTS,
+    };
+    use pool_factory_interfaces::DistributionMode;
+
+    let mut deps = mock_dependencies();
+    setup_pool_storage(&mut deps);
+    DISTRIBUTION_MODE
+        .save(&mut deps.storage, &DistributionMode::Pull)
+        .unwrap();
+    NATIVE_RAISED_FROM_COMMIT
+        .save(&mut deps.storage, &Uint128::new(1_000_000))
+        .unwrap();
+    // Roughly thirds of the $25k threshold.
+    let alice = deps.api.addr_make("alice");
+    let bob = deps.api.addr_make("bob");
+    let carol = deps.api.addr_make("carol");
+    for (who, usd) in [
+        (&alice, 8_333_333_333u128),
+        (&bob, 8_333_333_333),
+        (&carol, 8_333_333_334),
+    ] {
+        COMMIT_LEDGER
+            .save(&mut deps.storage, who, &Uint128::new(usd))
+            .unwrap();
+    }
+
+    let pool_info = POOL_INFO.load(&deps.storage).unwrap();
+    let mut pool_state = POOL_STATE.load(&deps.storage).unwrap();
+    let mut pool_fee_state = POOL_FEE_STATE.load(&deps.storage).unwrap();
+    let commit_config = COMMIT_LIMIT_INFO.load(&deps.storage).unwrap();
+    let payout = THRESHOLD_PAYOUT_AMOUNTS.load(&deps.storage).unwrap();
+    let fee_info = COMMITFEEINFO.load(&deps.storage).unwrap();
+    trigger_threshold_payout(
+        &mut deps.storage,
+        &pool_info,
+        &mut pool_state,
+        &mut pool_fee_state,
+        &commit_config,
+        &payout,
+        &fee_info,
+        &mock_env(),
+    )
+    .unwrap();
+
+    // No keeper loop; totals frozen instead.
+    assert!(DISTRIBUTION_STATE.may_load(&deps.storage).unwrap().is_none());
+    let frozen = PULL_DISTRIBUTION.load(&deps.storage).unwrap();
+    assert_eq!(frozen.total_to_distribute, payout.commit_return_amount);
+    assert_eq!(
+        frozen.total_committed_usd,
+        commit_config.commit_amount_for_threshold_usd
+    );
+
+    let claim = |deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, who: &Addr| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(who, &[]),
+            ExecuteMsg::ClaimCommitReward {},
+        )
+    };
+    let mints = |res: &cosmwasm_std::Response| -> Vec<(String, Uint128)> {
+        res.messages
+            .iter()
+            .map(|m| match &m.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                    match from_json::<cw20::Cw20ExecuteMsg>(msg).unwrap() {
+                        cw20::Cw20ExecuteMsg::Mint { recipient, amount } => (recipient, amount),
+                        other => panic!("expected Mint, got {:?}", other),
+                    }
+                }
+                other => panic!("expected Wasm Execute, got {:?}", other),
+            })
+            .collect()
+    };
+    let share = |usd: u128| {
+        payout
+            .commit_return_amount
+            .multiply_ratio(usd, commit_config.commit_amount_for_threshold_usd)
+    };
+
+    let stranger = deps.api.addr_make("stranger");
+    let err = claim(&mut deps, &stranger).unwrap_err();
+    assert!(matches!(err, ContractError::NoCommitReward {}));
+
+    let res = claim(&mut deps, &alice).unwrap();
+    assert_eq!(mints(&res), vec![(alice.to_string(), share(8_333_333_333))]);
+    let err = claim(&mut deps, &alice).unwrap_err();
+    assert!(matches!(err, ContractError::NoCommitReward {}));
+
+    let res = claim(&mut deps, &bob).unwrap();
+    assert_eq!(mints(&res), vec![(bob.to_string(), share(8_333_333_333))]);
+
+    // The three shares cover the whole tranche, so the last claim has
+    // no floor dust to settle.
+    let res = claim(&mut deps, &carol).unwrap();
+    assert_eq!(mints(&res), vec![(carol.to_string(), share(8_333_333_334))]);
+    let frozen = PULL_DISTRIBUTION.load(&deps.storage).unwrap();
+    assert_eq!(frozen.claim_count, 3);
+    assert_eq!(frozen.claimed, payout.commit_return_amount);
+}
+
+#[test]
+fn test_early_supporter_weighting_favours_early_commits_and_pays_exact_tranche() {
+    use crate::commit::distribution_batch::process_distribution_batch;
+    use crate::commit::early_supporter::credit_commit_ledger;
+    use crate::commit::threshold_payout::trigger_threshold_payout;
+    use crate::query::query_early_supporter_weighting;
+    use crate::state::{
+        EarlySupporterWeighting, EARLY_SUPPORTER_WEIGHTING, NATIVE_RAISED_FROM_COMMIT,
+        POOL_FEE_STATE, POOL_INFO, THRESHOLD_PAYOUT_AMOUNTS, WEIGHTED_COMMIT_LEDGER,
+    };
+    use pool_factory_interfaces::EarlySupporterCurve;
+
+    let mut deps = mock_dependencies();
+    setup_pool_storage(&mut deps);
+    NATIVE_RAISED_FROM_COMMIT
+        .save(&mut deps.storage, &Uint128::new(1_000_000))
+        .unwrap();
+    let start = mock_env();
+    let decay_seconds = 86_400 * 10;
+    EARLY_SUPPORTER_WEIGHTING
+        .save(
+            &mut deps.storage,
+            &EarlySupporterWeighting {
+                curve: EarlySupporterCurve {
+                    max_multiplier: Decimal::percent(200),
+                    decay_seconds,
+                },
+                starts_at: start.block.time,
+            },
+        )
+        .unwrap();
+    let at = |secs: u64| {
+        let mut env = mock_env();
+        env.block.time = start.block.time.plus_seconds(secs);
+        env
+    };
+
+    // Alice commits at creation (2x), bob halfway through the decay
+    // (1.5x), carol after it (1x). Together they fill the $25k threshold.
+    let alice = deps.api.addr_make("alice");
+    let bob = deps.api.addr_make("bob");
+    let carol = deps.api.addr_make("carol");
+    credit_commit_ledger(&mut deps.storage, &at(0), &alice, Uint128::new(10_000_000_000)).unwrap();
+    let halfway = query_early_supporter_weighting(deps.as_ref(), at(decay_seconds / 2)).unwrap();
+    assert_eq!(halfway.current_multiplier, Decimal::percent(150));
+    credit_commit_ledger(
+        &mut deps.storage,
+        &at(decay_seconds / 2),
+        &bob,
+        Uint128::new(5_000_000_000),
+    )
+    .unwrap();
+    credit_commit_ledger(
+        &mut deps.storage,
+        &at(decay_seconds + 1),
+        &carol,
+        Uint128::new(10_000_000_000),
+    )
+    .unwrap();
+    assert_eq!(
+        WEIGHTED_COMMIT_LEDGER.load(&deps.storage, &bob).unwrap(),
+        Uint128::new(7_500_000_000)
+    );
+
+    let pool_info = POOL_INFO.load(&deps.storage).unwrap();
+    let mut pool_state = POOL_STATE.load(&deps.storage).unwrap();
+    let mut pool_fee_state = POOL_FEE_STATE.load(&deps.storage).unwrap();
+    let commit_config = COMMIT_LIMIT_INFO.load(&deps.storage).unwrap();
+    let payout = THRESHOLD_PAYOUT_AMOUNTS.load(&deps.storage).unwrap();
+    let fee_info = COMMITFEEINFO.load(&deps.storage).unwrap();
+    let env = at(decay_seconds + 2);
+    trigger_threshold_payout(
+        &mut deps.storage,
+        &pool_info,
+        &mut pool_state,
+        &mut pool_fee_state,
+        &commit_config,
+        &payout,
+        &fee_info,
+        &env,
+    )
+    .unwrap();
+    let dist = DISTRIBUTION_STATE.load(&deps.storage).unwrap();
+    assert_eq!(dist.total_committed_usd, Uint128::new(37_500_000_000));
+
+    let (submsgs, processed) = process_distribution_batch(&mut deps.storage, &pool_info, &env).unwrap();
+    assert_eq!(processed, 3);
+    let mut minted: Vec<(String, Uint128)> = submsgs
+        .iter()
+        .map(|m| match &m.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                match from_json::<cw20::Cw20ExecuteMsg>(msg).unwrap() {
+                    cw20::Cw20ExecuteMsg::Mint { recipient, amount } => (recipient, amount),
+                    other => panic!("expected Mint, got {:?}", other),
+                }
+            }
+            other => panic!("expected Wasm Execute, got {:?}", other),
+        })
+        .collect();
+    let reward_of = |who: &Addr| {
+        minted
+            .iter()
+            .find(|(r, _)| r == who.as_str())
+            .map(|(_, a)| *a)
+            .unwrap()
+    };
+    // Same USD as carol, twice the weight.
+    assert_eq!(reward_of(&alice), Uint128::new(266_666_666_666));
+    assert_eq!(reward_of(&bob), Uint128::new(100_000_000_000));
+    assert_eq!(reward_of(&carol), Uint128::new(133_333_333_333));
+    // Floor dust goes to the creator, so the tranche is paid exactly.
+    let total: Uint128 = minted.drain(..).map(|(_, a)| a).sum();
+    assert_eq!(total, payout.commit_return_amount);
+    assert!(COMMIT_LEDGER.is_empty(&deps.storage));
+    assert!(WEIGHTED_COMMIT_LEDGER.is_empty(&deps.storage));
+}
+
+// ===========================================================================
+// Creator revenue split
+// ===========================================================================
+
+fn save_70_30_revenue_split(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+) -> (Addr, Addr) {
+    use crate::state::{RevenueRecipient, REVENUE_SPLIT};
+
+    let alice = deps.api.addr_make("alice");
+    let bob = deps.api.addr_make("bob");
+    REVENUE_SPLIT
+        .save(
+            &mut deps.storage,
+            &vec![
+                RevenueRecipient {
+                    address: alice.clone(),
+                    bps: 7_000,
+                },
+                RevenueRecipient {
+                    address: bob.clone(),
+                    bps: 3_000,
+                },
+            ],
+        )
+        .unwrap();
+    (alice, bob)
+}
+
+#[test]
+fn test_commit_creator_fee_follows_revenue_split() {
+    let mut deps = mock_dependencies();
+    setup_pool_with_excess_config(&mut deps);
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { .. } => {
+            let response = ConversionResponse {
+                amount: Uint128::new(5_000_000),
+                rate_used: Uint128::new(1_000_000),
+                timestamp: 0,
+            };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::InvalidRequest {
+            error: "Unknown query".to_string(),
+            request: Binary::default(),
+        }),
+    });
+    let (alice, bob) = save_70_30_revenue_split(&mut deps);
+    let committer = deps.api.addr_make("committer");
+
+    // 5% creator fee on 5_000_020 is 250_001; the odd unit of floor
+    // dust goes to the first recipient.
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&committer, &[coin(5_000_020, "ubluechip")]),
+        ExecuteMsg::Commit {
+            asset: TokenInfo {
+                info: TokenType::Native {
+                    denom: "ubluechip".to_string(),
+                },
+                amount: Uint128::new(5_000_020),
+            },
+            transaction_deadline: None,
+            belief_price: None,
+            max_spread: None,
+            tier: None,
+            beneficiary: None,
+            referrer: None,
+            allowlist_proof: None,
+        },
+    )
+    .unwrap();
+    let sent_to = |who: &str| {
+        res.messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) if to_address == who => {
+                    Some(amount[0].amount)
+                }
+                _ => None,
+            })
+            .sum::<Uint128>()
+    };
+    assert_eq!(sent_to(alice.as_str()), Uint128::new(175_001));
+    assert_eq!(sent_to(bob.as_str()), Uint128::new(75_000));
+    assert!(sent_to("creator_wallet").is_zero());
+}
+
+#[test]
+fn test_threshold_creator_reward_mint_follows_revenue_split() {
+    use crate::commit::threshold_payout::trigger_threshold_payout;
+    use crate::state::{
+        NATIVE_RAISED_FROM_COMMIT, POOL_FEE_STATE, POOL_INFO, THRESHOLD_PAYOUT_AMOUNTS,
+    };
+
+    let mut deps = mock_dependencies();
+    setup_pool_storage(&mut deps);
+    NATIVE_RAISED_FROM_COMMIT
+        .save(&mut deps.storage, &Uint128::new(1_000_000))
+        .unwrap();
+    let (alice, bob) = save_70_30_revenue_split(&mut deps);
+
+    let env = mock_env();
+    let pool_info = POOL_INFO.load(&deps.storage).unwrap();
+    let mut pool_state = POOL_STATE.load(&deps.storage).unwrap();
+    let mut pool_fee_state = POOL_FEE_STATE.load(&deps.storage).unwrap();
+    let commit_config = COMMIT_LIMIT_INFO.load(&deps.storage).unwrap();
+    let payout = THRESHOLD_PAYOUT_AMOUNTS.load(&deps.storage).unwrap();
+    let fee_info = COMMITFEEINFO.load(&deps.storage).unwrap();
+    let msgs = trigger_threshold_payout(
+        &mut deps.storage,
+        &pool_info,
+        &mut pool_state,
+        &mut pool_fee_state,
+        &commit_config,
+        &payout,
+        &fee_info,
+        &env,
+    )
+    .unwrap();
+
+    let minted_to = |who: &str| {
+        msgs.other_msgs
+            .iter()
+            .filter_map(|m| match m {
+                CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                    match from_json::<cw20::Cw20ExecuteMsg>(msg) {
+                        Ok(cw20::Cw20ExecuteMsg::Mint { recipient, amount })
+                            if recipient == who =>
+                        {
+                            Some(amount)
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .sum::<Uint128>()
+    };
+    let to_alice = minted_to(alice.as_str());
+    let to_bob = minted_to(bob.as_str());
+    assert_eq!(to_bob, payout.creator_reward_amount.multiply_ratio(3_000u128, 10_000u128));
+    assert_eq!(to_alice + to_bob, payout.creator_reward_amount);
+    assert!(minted_to(fee_info.creator_wallet_address.as_str()).is_zero());
+}
@@ -203,3 +203,73 @@ fn test_update_config_accepts_floors_at_max_boundary() {
     assert_eq!(stored.min_commit_usd_pre_threshold, MAX_MIN_COMMIT_USD);
     assert_eq!(stored.min_commit_usd_post_threshold, MAX_MIN_COMMIT_USD);
 }
+
+/// Per-wallet commit caps: a USD cap below the pre-threshold floor and a
+/// share above 100% are rejected; zero lifts a cap that was set.
+#[test]
+fn test_update_config_wallet_commit_caps() {
+    let mut deps = mock_dependencies();
+    setup_pool_storage(&mut deps);
+    let factory_info = message_info(&Addr::unchecked("factory_contract"), &[]);
+    let floor = COMMIT_LIMIT_INFO
+        .load(&deps.storage)
+        .unwrap()
+        .min_commit_usd_pre_threshold;
+
+    for update in [
+        PoolConfigUpdate {
+            max_commit_usd_per_wallet: Some(floor - Uint128::one()),
+            ..Default::default()
+        },
+        PoolConfigUpdate {
+            max_commit_share_per_wallet: Some(Decimal::percent(101)),
+            ..Default::default()
+        },
+    ] {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            factory_info.clone(),
+            ExecuteMsg::UpdateConfigFromFactory { update },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::InvalidCommitWalletCap { .. }),
+            "got: {:?}",
+            err
+        );
+    }
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        factory_info.clone(),
+        ExecuteMsg::UpdateConfigFromFactory {
+            update: PoolConfigUpdate {
+                max_commit_usd_per_wallet: Some(Uint128::new(8_000_000_000)),
+                max_commit_share_per_wallet: Some(Decimal::percent(20)),
+                ..Default::default()
+            },
+        },
+    )
+    .unwrap();
+    let stored = COMMIT_LIMIT_INFO.load(&deps.storage).unwrap();
+    // 20% of the $25k threshold is tighter than the $8k USD cap.
+    assert_eq!(stored.wallet_commit_cap(), Some(Uint128::new(5_000_000_000)));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        factory_info,
+        ExecuteMsg::UpdateConfigFromFactory {
+            update: PoolConfigUpdate {
+                max_commit_share_per_wallet: Some(Decimal::zero()),
+                ..Default::default()
+            },
+        },
+    )
+    .unwrap();
+    let stored = COMMIT_LIMIT_INFO.load(&deps.storage).unwrap();
+    assert_eq!(stored.max_commit_share_per_wallet, None);
+    assert_eq!(stored.wallet_commit_cap(), Some(Uint128::new(8_000_000_000)));
+}
@@ -141,6 +141,31 @@ pub enum ContractError {
         asset_b: String,
     },
 
+    // ---------------------------------------------------------------------
+    // Creator profile registry.
+    // ---------------------------------------------------------------------
+    #[error("No creator profile for {creator}; profiles open on the creator's first Create")]
+    CreatorProfileNotFound { creator: String },
+
+    #[error("Invalid creator profile: {reason}")]
+    InvalidCreatorProfile { reason: String },
+
+    // ---------------------------------------------------------------------
+    // Role-based access control.
+    // ---------------------------------------------------------------------
+    #[error("A {role} role change for {holder} is already pending. Cancel it first.")]
+    RoleChangeAlreadyPending { role: String, holder: String },
+
+    #[error("No pending {role} role change for {holder}")]
+    NoPendingRoleChange { role: String, holder: String },
+
+    #[error("{holder} already {state} the {role} role; nothing to change")]
+    RoleChangeNoChange {
+        role: String,
+        holder: String,
+        state: &'static str,
+    },
+
     // ---------------------------------------------------------------------
     // Migration / config errors.
     // ---------------------------------------------------------------------
@@ -1,6 +1,6 @@
 //! Factory contract entry points + shared reply-ID machinery.
 //!
-//! The bulk of the handler logic has been split into four submodules
+//! The bulk of the handler logic has been split into six submodules
 //! by message family:
 //!
 //! - [`config`]         — propose / apply / cancel for both factory
@@ -16,16 +16,22 @@
 //! [`crate::internal_bluechip_price_oracle`].
 //! - [`upgrades`]       — pool wasm upgrade proposal + batched migrate
 //! apply.
+//! - [`creators`]       — creator profile edits and the admin-granted
+//! verified badge.
+//! - [`roles`]          — timelocked grants and revokes of the
+//! delegable admin roles.
 //!
 //! This file keeps the `#[entry_point]` exports (`instantiate`,
-//! `execute`, `reply`), the cross-module helpers (`ensure_admin`,
+//! `execute`, `reply`), the cross-module helpers (`ensure_admin`, `ensure_role`,
 //! `encode_reply_id`, `decode_reply_id`), and the reply-step
 //! constants. Every other public item in `crate::execute` is
 //! re-exported from a submodule via `pub use`.
 
 pub mod config;
+pub mod creators;
 pub mod oracle;
 pub mod pool_lifecycle;
+pub mod roles;
 pub mod upgrades;
 
 // Explicit re-exports keep the public surface of `crate::execute::*`
@@ -37,6 +43,10 @@ pub use config::{
     execute_cancel_pool_config_update, execute_propose_factory_config_update,
     execute_propose_pool_config_update, execute_update_factory_config,
 };
+pub use creators::{
+    execute_notify_creator_wallet_rotated, execute_set_creator_verified,
+    execute_update_creator_profile,
+};
 // `validate_factory_config` is intentionally NOT re-exported — it's
 // reached via the `config::validate_factory_config(...)` path in
 // `instantiate` so the gate is visible at the call site.
@@ -50,9 +60,15 @@ pub use oracle::{
     execute_set_pyth_conf_threshold_bps,
 };
 pub use pool_lifecycle::admin::{
-    execute_cancel_emergency_withdraw_pool, execute_emergency_withdraw_pool,
-    execute_notify_threshold_crossed, execute_pause_pool, execute_recover_pool_stuck_states,
-    execute_sweep_unclaimed_emergency_shares_pool, execute_unpause_pool,
+    execute_cancel_emergency_withdraw_pool, execute_collect_pool_protocol_fees,
+    execute_emergency_withdraw_pool, execute_notify_threshold_crossed, execute_pause_pool,
+    execute_recover_pool_stuck_states, execute_set_pool_commit_allowlist,
+    execute_set_pool_subscription_tiers,
+    execute_sync_pool_protocol_fee, execute_sweep_unclaimed_emergency_shares_pool,
+    execute_unpause_pool, execute_veto_pool_creator_wallet,
+};
+pub use roles::{
+    execute_apply_role_change, execute_cancel_role_change, execute_propose_role_change,
 };
 pub use upgrades::{
     execute_apply_pool_upgrade, execute_cancel_pool_upgrade, execute_continue_pool_upgrade,
@@ -69,8 +85,8 @@ use crate::internal_bluechip_price_oracle::{
 use crate::msg::ExecuteMsg;
 use crate::pool_creation_reply::{finalize_pool, mint_create_pool, set_tokens};
 use crate::state::{
-    DISTRIBUTION_BOUNTY_USD, FACTORYINSTANTIATEINFO, INITIAL_ANCHOR_SET,
-    ORACLE_UPDATE_BOUNTY_USD,
+    Role, DISTRIBUTION_BOUNTY_USD, FACTORYINSTANTIATEINFO, INITIAL_ANCHOR_SET,
+    ORACLE_UPDATE_BOUNTY_USD, ROLE_HOLDERS,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
@@ -205,10 +221,25 @@ pub fn execute(
         ExecuteMsg::SweepUnclaimedEmergencyPool { pool_id } => {
             execute_sweep_unclaimed_emergency_shares_pool(deps, info, pool_id)
         }
+        ExecuteMsg::VetoPoolCreatorWallet { pool_id } => {
+            execute_veto_pool_creator_wallet(deps, info, pool_id)
+        }
         ExecuteMsg::RecoverPoolStuckStates {
             pool_id,
             recovery_type,
         } => execute_recover_pool_stuck_states(deps, info, pool_id, recovery_type),
+        ExecuteMsg::SyncPoolProtocolFee { pool_id } => {
+            execute_sync_pool_protocol_fee(deps, pool_id)
+        }
+        ExecuteMsg::CollectPoolProtocolFees { pool_id } => {
+            execute_collect_pool_protocol_fees(deps, pool_id)
+        }
+        ExecuteMsg::SetPoolSubscriptionTiers { pool_id, tiers } => {
+            execute_set_pool_subscription_tiers(deps, info, pool_id, tiers)
+        }
+        ExecuteMsg::SetPoolCommitAllowlist { pool_id, allowlist } => {
+            execute_set_pool_commit_allowlist(deps, env, info, pool_id, allowlist)
+        }
         ExecuteMsg::CreateStandardPool {
             pool_token_info,
             label,
@@ -247,6 +278,28 @@ pub fn execute(
         ExecuteMsg::RefreshOraclePoolSnapshot {} => {
             execute_refresh_oracle_pool_snapshot(deps, env)
         }
+        ExecuteMsg::UpdateCreatorProfile {
+            display_name,
+            avatar_uri,
+            links,
+        } => execute_update_creator_profile(deps, info, display_name, avatar_uri, links),
+        ExecuteMsg::SetCreatorVerified { creator, verified } => {
+            execute_set_creator_verified(deps, info, creator, verified)
+        }
+        ExecuteMsg::NotifyCreatorWalletRotated { new_wallet } => {
+            execute_notify_creator_wallet_rotated(deps, env, info, new_wallet)
+        }
+        ExecuteMsg::ProposeRoleChange {
+            role,
+            holder,
+            grant,
+        } => execute_propose_role_change(deps, env, info, role, holder, grant),
+        ExecuteMsg::ApplyRoleChange { role, holder } => {
+            execute_apply_role_change(deps, env, info, role, holder)
+        }
+        ExecuteMsg::CancelRoleChange { role, holder } => {
+            execute_cancel_role_change(deps, info, role, holder)
+        }
     }
 }
 
@@ -382,7 +435,7 @@ pub fn pool_creation_reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Respon
     }
 }
 
-/// Admin gate used by every admin-only handler in this module's submodules.
+/// Super-admin gate used by every handler no narrower [`Role`] covers.
 /// Loads the factory config and rejects with [`ContractError::Unauthorized`]
 /// if `info.sender` does not match `factory_admin_address`.
 pub fn ensure_admin(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
@@ -392,3 +445,13 @@ pub fn ensure_admin(deps: Deps, info: &MessageInfo) -> Result<(), ContractError>
     }
     Ok(())
 }
+
+/// Role gate: passes for holders of `role` and for the super-admin, who
+/// holds every role implicitly. Rejects with [`ContractError::Unauthorized`]
+/// otherwise.
+pub fn ensure_role(deps: Deps, info: &MessageInfo, role: Role) -> Result<(), ContractError> {
+    if ROLE_HOLDERS.has(deps.storage, (role.as_str(), &info.sender)) {
+        return Ok(());
+    }
+    ensure_admin(deps, info)
+}
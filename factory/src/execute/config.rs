@@ -145,6 +145,71 @@ pub(crate) fn validate_factory_config(
         ))));
     }
 
+    // Launch-window limits are snapshotted into every commit pool created
+    // under this config, so reject nonsense before the timelock starts.
+    let launch = &config.launch_protection;
+    if launch.window_blocks > crate::state::LAUNCH_WINDOW_MAX_BLOCKS {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "launch_protection.window_blocks {} exceeds maximum {}",
+            launch.window_blocks,
+            crate::state::LAUNCH_WINDOW_MAX_BLOCKS,
+        ))));
+    }
+    if launch.max_swap_bps > 10_000 || launch.max_wallet_buy_bps > 10_000 {
+        return Err(ContractError::Std(StdError::generic_err(
+            "launch_protection bps values must be <= 10000",
+        )));
+    }
+    if launch.launch_fee > crate::state::LAUNCH_FEE_MAX {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "launch_protection.launch_fee {} exceeds maximum {}",
+            launch.launch_fee,
+            crate::state::LAUNCH_FEE_MAX,
+        ))));
+    }
+    if launch.launch_fee_decay_blocks > crate::state::LAUNCH_WINDOW_MAX_BLOCKS {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "launch_protection.launch_fee_decay_blocks {} exceeds maximum {}",
+            launch.launch_fee_decay_blocks,
+            crate::state::LAUNCH_WINDOW_MAX_BLOCKS,
+        ))));
+    }
+    for (name, share) in [
+        ("commit_pool_share", config.protocol_fee.commit_pool_share),
+        ("standard_pool_share", config.protocol_fee.standard_pool_share),
+    ] {
+        if share > crate::state::PROTOCOL_FEE_SHARE_MAX {
+            return Err(ContractError::Std(StdError::generic_err(format!(
+                "protocol_fee.{} {} exceeds maximum {}",
+                name,
+                share,
+                crate::state::PROTOCOL_FEE_SHARE_MAX,
+            ))));
+        }
+    }
+
+    let vesting = &config.creator_vesting;
+    if vesting.duration_seconds > crate::state::CREATOR_VESTING_MAX_SECONDS {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "creator_vesting.duration_seconds {} exceeds maximum {}",
+            vesting.duration_seconds,
+            crate::state::CREATOR_VESTING_MAX_SECONDS,
+        ))));
+    }
+    if vesting.cliff_seconds > vesting.duration_seconds {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "creator_vesting.cliff_seconds {} exceeds duration_seconds {}",
+            vesting.cliff_seconds, vesting.duration_seconds,
+        ))));
+    }
+    if config.creator_wallet_veto_seconds > crate::state::CREATOR_WALLET_VETO_MAX_SECONDS {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "creator_wallet_veto_seconds {} exceeds maximum {}",
+            config.creator_wallet_veto_seconds,
+            crate::state::CREATOR_WALLET_VETO_MAX_SECONDS,
+        ))));
+    }
+
     // Strict anchor-pool validation on the post-bootstrap path. Without
     // this gate, the propose/update flow would let an admin point the
     // anchor at any well-formed address — including a non-pool contract
@@ -349,12 +414,17 @@ pub fn execute_propose_pool_config_update(
     // makes the misuse loud and saves a 48h timelock cycle.
     if pool_details.pool_kind == pool_factory_interfaces::PoolKind::Standard
         && (update_msg.min_commit_usd_pre_threshold.is_some()
-            || update_msg.min_commit_usd_post_threshold.is_some())
+            || update_msg.min_commit_usd_post_threshold.is_some()
+            || update_msg.referral_fee_share.is_some()
+            || update_msg.max_commit_usd_per_wallet.is_some()
+            || update_msg.max_commit_share_per_wallet.is_some())
     {
         return Err(ContractError::Std(StdError::generic_err(format!(
-            "Pool {} is a standard pool — commit-floor knobs \
-             (min_commit_usd_pre_threshold, min_commit_usd_post_threshold) \
-             are creator-pool-only. Drop those fields or target a commit pool.",
+            "Pool {} is a standard pool — commit-floor, referral and wallet-cap knobs \
+             (min_commit_usd_pre_threshold, min_commit_usd_post_threshold, \
+             referral_fee_share, max_commit_usd_per_wallet, \
+             max_commit_share_per_wallet) are creator-pool-only. Drop those \
+             fields or target a commit pool.",
             pool_id
         ))));
     }
@@ -0,0 +1,171 @@
+//! Creator profile registry: the creator's own metadata edits, the
+//! admin's verified badge and creator wallet rotations reported by
+//! pools. Profiles are opened by [`open_creator_profile`] from the
+//! commit-pool `Create` path; pool lists come from the registry's
+//! `creator` index.
+
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Storage, Timestamp};
+use pool_factory_interfaces::PoolKind;
+
+use crate::error::ContractError;
+use crate::state::{
+    pool_by_address, pool_ids_by_creator, CreatorProfile, CREATOR_DISPLAY_NAME_MAX_LEN,
+    CREATOR_MAX_LINKS, CREATOR_PROFILES, CREATOR_URI_MAX_LEN, POOLS_BY_ID,
+};
+
+use super::ensure_admin;
+
+/// Opens an empty, unverified profile for `creator` unless one exists.
+pub(crate) fn open_creator_profile(
+    storage: &mut dyn Storage,
+    creator: &Addr,
+    now: Timestamp,
+) -> Result<(), ContractError> {
+    if !CREATOR_PROFILES.has(storage, creator.clone()) {
+        CREATOR_PROFILES.save(
+            storage,
+            creator.clone(),
+            &CreatorProfile {
+                display_name: None,
+                avatar_uri: None,
+                links: vec![],
+                verified: false,
+                registered_at: now,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+fn validate_uri(field: &str, uri: &str) -> Result<(), ContractError> {
+    if uri.trim().is_empty() || uri.len() > CREATOR_URI_MAX_LEN {
+        return Err(ContractError::InvalidCreatorProfile {
+            reason: format!("{} must be 1..={} bytes", field, CREATOR_URI_MAX_LEN),
+        });
+    }
+    if uri.chars().any(char::is_whitespace) {
+        return Err(ContractError::InvalidCreatorProfile {
+            reason: format!("{} must not contain whitespace", field),
+        });
+    }
+    Ok(())
+}
+
+/// Replaces the sender's profile metadata. The verified badge and
+/// registration time are untouched.
+pub fn execute_update_creator_profile(
+    deps: DepsMut,
+    info: MessageInfo,
+    display_name: Option<String>,
+    avatar_uri: Option<String>,
+    links: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut profile = CREATOR_PROFILES
+        .may_load(deps.storage, info.sender.clone())?
+        .ok_or_else(|| ContractError::CreatorProfileNotFound {
+            creator: info.sender.to_string(),
+        })?;
+
+    if let Some(name) = &display_name {
+        if name.trim().is_empty() || name.len() > CREATOR_DISPLAY_NAME_MAX_LEN {
+            return Err(ContractError::InvalidCreatorProfile {
+                reason: format!(
+                    "display_name must be 1..={} bytes",
+                    CREATOR_DISPLAY_NAME_MAX_LEN
+                ),
+            });
+        }
+    }
+    if let Some(uri) = &avatar_uri {
+        validate_uri("avatar_uri", uri)?;
+    }
+    if links.len() > CREATOR_MAX_LINKS {
+        return Err(ContractError::InvalidCreatorProfile {
+            reason: format!("at most {} links", CREATOR_MAX_LINKS),
+        });
+    }
+    for link in &links {
+        validate_uri("link", link)?;
+    }
+
+    profile.display_name = display_name;
+    profile.avatar_uri = avatar_uri;
+    profile.links = links;
+    CREATOR_PROFILES.save(deps.storage, info.sender.clone(), &profile)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_creator_profile")
+        .add_attribute("creator", info.sender))
+}
+
+/// Admin-only. Grants or revokes a creator's verified badge. Immediate:
+/// the badge is display metadata and moves no funds.
+pub fn execute_set_creator_verified(
+    deps: DepsMut,
+    info: MessageInfo,
+    creator: String,
+    verified: bool,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.as_ref(), &info)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let mut profile = CREATOR_PROFILES
+        .may_load(deps.storage, creator.clone())?
+        .ok_or_else(|| ContractError::CreatorProfileNotFound {
+            creator: creator.to_string(),
+        })?;
+    profile.verified = verified;
+    CREATOR_PROFILES.save(deps.storage, creator.clone(), &profile)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_creator_verified")
+        .add_attribute("creator", creator)
+        .add_attribute("verified", verified.to_string()))
+}
+
+/// Commit-pool only. Re-lists the calling pool under `new_wallet` and
+/// carries the previous wallet's profile over: copied when `new_wallet`
+/// has none, and removed from the previous wallet once it lists no pools.
+/// The copy is unverified; the admin re-grants the badge to the new
+/// wallet. `new_wallet` always ends with a profile, so it can edit it
+/// straight away.
+pub fn execute_notify_creator_wallet_rotated(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_wallet: String,
+) -> Result<Response, ContractError> {
+    let mut details =
+        pool_by_address(deps.storage, &info.sender)?.ok_or(ContractError::Unauthorized {})?;
+    if details.pool_kind != PoolKind::Commit {
+        return Err(ContractError::Unauthorized {});
+    }
+    let new_wallet = deps.api.addr_validate(&new_wallet)?;
+
+    let previous = details.creator.replace(new_wallet.clone());
+    POOLS_BY_ID.save(deps.storage, details.pool_id, &details)?;
+
+    let mut profile_moved = false;
+    if let Some(previous) = previous.as_ref().filter(|p| **p != new_wallet) {
+        if let Some(mut profile) = CREATOR_PROFILES.may_load(deps.storage, previous.clone())? {
+            if !CREATOR_PROFILES.has(deps.storage, new_wallet.clone()) {
+                profile.verified = false;
+                CREATOR_PROFILES.save(deps.storage, new_wallet.clone(), &profile)?;
+                profile_moved = true;
+            }
+            if pool_ids_by_creator(deps.storage, previous)?.is_empty() {
+                CREATOR_PROFILES.remove(deps.storage, previous.clone());
+            }
+        }
+    }
+    open_creator_profile(deps.storage, &new_wallet, env.block.time)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "notify_creator_wallet_rotated")
+        .add_attribute("pool_id", details.pool_id.to_string())
+        .add_attribute(
+            "previous_creator",
+            previous.map(|p| p.to_string()).unwrap_or_default(),
+        )
+        .add_attribute("new_creator", new_wallet)
+        .add_attribute("profile_moved", profile_moved.to_string()))
+}
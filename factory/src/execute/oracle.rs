@@ -15,16 +15,15 @@ use cw_storage_plus::Item;
 
 use crate::error::ContractError;
 use crate::state::{
-    AllowlistedOraclePool, PendingCommitPoolsAutoEligible, PendingOracleEligiblePoolAdd,
+    AllowlistedOraclePool, PendingCommitPoolsAutoEligible, PendingOracleEligiblePoolAdd, Role,
     ADMIN_TIMELOCK_SECONDS, COMMIT_POOLS_AUTO_ELIGIBLE, DISTRIBUTION_BOUNTY_USD,
     FACTORYINSTANTIATEINFO, LAST_ORACLE_REFRESH_BLOCK, MAX_DISTRIBUTION_BOUNTY_USD,
-    MAX_ORACLE_UPDATE_BOUNTY_USD, ORACLE_ELIGIBLE_POOLS,
-    ORACLE_REFRESH_RATE_LIMIT_BLOCKS, ORACLE_UPDATE_BOUNTY_USD,
-    PENDING_COMMIT_POOLS_AUTO_ELIGIBLE, PENDING_ORACLE_ELIGIBLE_POOL_ADD,
-    POOLS_BY_CONTRACT_ADDRESS, POOLS_BY_ID,
+    MAX_ORACLE_UPDATE_BOUNTY_USD, ORACLE_ELIGIBLE_POOLS, ORACLE_REFRESH_RATE_LIMIT_BLOCKS,
+    ORACLE_UPDATE_BOUNTY_USD, PENDING_COMMIT_POOLS_AUTO_ELIGIBLE, PENDING_ORACLE_ELIGIBLE_POOL_ADD,
+    POOLS_BY_ID,
 };
 
-use super::ensure_admin;
+use super::{ensure_admin, ensure_role};
 
 /// Shared body for the two bounty setters (oracle-update and distribution).
 /// Validates against the per-bounty cap, persists the new value, and emits
@@ -69,7 +68,7 @@ fn pay_distribution_bounty_skip(
     resp
 }
 
-/// Admin-only. Sets the per-call USD bounty (6 decimals, e.g. 5_000 = $0.005)
+/// Treasurer role. Sets the per-call USD bounty (6 decimals, e.g. 5_000 = $0.005)
 /// paid to oracle keepers. Capped by MAX_ORACLE_UPDATE_BOUNTY_USD ($0.10).
 /// At payout time the value is converted to bluechip via the internal oracle.
 pub fn execute_set_oracle_update_bounty(
@@ -77,7 +76,7 @@ pub fn execute_set_oracle_update_bounty(
     info: MessageInfo,
     new_bounty: Uint128,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::Treasurer)?;
     save_bounty_with_cap(
         deps.storage,
         ORACLE_UPDATE_BOUNTY_USD,
@@ -121,7 +120,7 @@ pub fn execute_set_pyth_conf_threshold_bps(
         .add_attribute("new_bps", bps.to_string()))
 }
 
-/// Admin-only. Sets the per-batch USD bounty (6 decimals, e.g. 50_000 = $0.05)
+/// Treasurer role. Sets the per-batch USD bounty (6 decimals, e.g. 50_000 = $0.05)
 /// paid to keepers calling pool.ContinueDistribution. Capped by
 /// MAX_DISTRIBUTION_BOUNTY_USD ($0.10). Converted to bluechip at payout time.
 pub fn execute_set_distribution_bounty(
@@ -129,7 +128,7 @@ pub fn execute_set_distribution_bounty(
     info: MessageInfo,
     new_bounty: Uint128,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::Treasurer)?;
     save_bounty_with_cap(
         deps.storage,
         DISTRIBUTION_BOUNTY_USD,
@@ -156,20 +155,16 @@ pub fn execute_pay_distribution_bounty(
     info: MessageInfo,
     recipient: String,
 ) -> Result<Response, ContractError> {
-    // Auth: caller must be a registered COMMIT pool. POOLS_BY_CONTRACT_ADDRESS
-    // is populated at pool creation and keyed by the pool's contract address;
-    // it contains both commit and standard pools, so the registry-presence
-    // check alone would let any registered pool drain the factory's bounty
-    // reserve. Only commit pools run distributions, so we additionally
-    // require `pool_kind == Commit` as defense-in-depth: if a future
-    // migration of either pool wasm ever introduced a hostile or buggy
-    // path that called `PayDistributionBounty`, this gate prevents standard
-    // pools from triggering a payout entirely.
-    if !POOLS_BY_CONTRACT_ADDRESS.has(deps.storage, info.sender.clone()) {
-        return Err(ContractError::Unauthorized {});
-    }
-    let pool_details = lookup_pool_by_addr(deps.as_ref(), &info.sender)?
-        .ok_or(ContractError::Unauthorized {})?;
+    // Auth: caller must be a registered COMMIT pool. The registry's
+    // address index covers both commit and standard pools, so the
+    // registry-presence check alone would let any registered pool drain
+    // the factory's bounty reserve. Only commit pools run distributions,
+    // so we additionally require `pool_kind == Commit` as defense-in-depth:
+    // if a future migration of either pool wasm ever introduced a hostile
+    // or buggy path that called `PayDistributionBounty`, this gate
+    // prevents standard pools from triggering a payout entirely.
+    let pool_details =
+        lookup_pool_by_addr(deps.as_ref(), &info.sender)?.ok_or(ContractError::Unauthorized {})?;
     if pool_details.pool_kind != pool_factory_interfaces::PoolKind::Commit {
         return Err(ContractError::Unauthorized {});
     }
@@ -412,74 +407,13 @@ pub(crate) fn validate_anchor_pool_choice(
 }
 
 /// Look up a registered pool by its contract address. Returns the
-/// `PoolDetails` if present, or `None` if no pool matches.
-///
-/// Fast path: `POOL_ID_BY_ADDRESS.may_load` resolves the address to a
-/// `pool_id` in O(1), then `POOLS_BY_ID.load` resolves the id to the
-/// full record. Both maps are written atomically inside
-/// `state::register_pool`, so every pool created through the live
-/// reply chain hits the fast path.
-///
-/// Slow-path fallback: if the reverse index misses, fall back to an
-/// O(N) linear scan of `POOLS_BY_ID`. This exists ONLY so that test
-/// fixtures (which historically wrote `POOLS_BY_ID` directly without
-/// going through `register_pool`) continue to resolve. Hitting this
-/// path in production would indicate a `POOLS_BY_ID` write that
-/// bypassed `register_pool` — a bug. The fallback emits no marker on
-/// chain; a future tightening could replace it with a defensive panic
-/// once all test fixtures and any migrate back-fill are confirmed to
-/// populate `POOL_ID_BY_ADDRESS`.
+/// `PoolDetails` if present, or `None` if no pool matches. One read of
+/// the registry's unique `address` index.
 pub(crate) fn lookup_pool_by_addr(
     deps: cosmwasm_std::Deps,
     pool_addr: &cosmwasm_std::Addr,
 ) -> StdResult<Option<crate::pool_struct::PoolDetails>> {
-    if let Some(pool_id) =
-        crate::state::POOL_ID_BY_ADDRESS.may_load(deps.storage, pool_addr.clone())?
-    {
-        return Ok(Some(POOLS_BY_ID.load(deps.storage, pool_id)?));
-    }
-    // POOL_ID_BY_ADDRESS missed. Distinguish "address is not a pool"
-    // from "registry inconsistency":
-    //
-    // - In prod, every pool created via the live reply chain runs
-    // through `state::register_pool`, which writes
-    // POOL_ID_BY_ADDRESS and POOLS_BY_CONTRACT_ADDRESS atomically.
-    // A miss in POOL_ID_BY_ADDRESS but a hit in
-    // POOLS_BY_CONTRACT_ADDRESS means a write bypassed
-    // register_pool — a real bug. Surface it loudly rather than
-    // paper over it with an O(N) POOLS_BY_ID scan that silently
-    // succeeds.
-    //
-    // - In tests, legacy fixtures may write POOLS_BY_ID directly.
-    // Keep the linear-scan fallback there so existing fixtures
-    // keep resolving without rewrites.
-    #[cfg(not(test))]
-    {
-        if crate::state::POOLS_BY_CONTRACT_ADDRESS
-            .has(deps.storage, pool_addr.clone())
-        {
-            return Err(cosmwasm_std::StdError::generic_err(format!(
-                "Registry inconsistency: pool {} exists in POOLS_BY_CONTRACT_ADDRESS \
-                 but not in POOL_ID_BY_ADDRESS reverse index. Every pool created via \
-                 the reply chain populates both atomically via state::register_pool; \
-                 reaching this branch means a write bypassed that helper. Investigate \
-                 before retrying.",
-                pool_addr
-            )));
-        }
-        Ok(None)
-    }
-    #[cfg(test)]
-    {
-        use cosmwasm_std::Order;
-        for entry in POOLS_BY_ID.range(deps.storage, None, None, Order::Ascending) {
-            let (_id, details) = entry?;
-            if &details.creator_pool_addr == pool_addr {
-                return Ok(Some(details));
-            }
-        }
-        Ok(None)
-    }
+    crate::state::pool_by_address(deps.storage, pool_addr)
 }
 
 /// Refresh `INTERNAL_ORACLE` after the anchor pool has changed. Mirrors
@@ -608,7 +542,7 @@ fn resolve_pool_for_allowlist(
     // activity, so its cumulative-delta TWAP is either zero (no activity)
     // or determined entirely by seeded reserves — neither is a
     // meaningful oracle contributor. The auto-eligible source already
-    // gates on `POOL_THRESHOLD_MINTED` (in
+    // gates on `PoolDetails.threshold_crossed` (in
     // `internal_bluechip_price_oracle::get_eligible_creator_pools`);
     // mirroring the gate here keeps the admin-curated and auto-eligible
     // sources from disagreeing on what counts as a valid oracle pool,
@@ -616,10 +550,7 @@ fn resolve_pool_for_allowlist(
     // pool that would fail the same gate at sample time anyway.
     // Standard pools have no threshold concept and are exempt.
     if pool_details.pool_kind == pool_factory_interfaces::PoolKind::Commit {
-        let threshold_minted = crate::state::POOL_THRESHOLD_MINTED
-            .may_load(deps.storage, pool_details.pool_id)?
-            .unwrap_or(false);
-        if !threshold_minted {
+        if !pool_details.threshold_crossed {
             return Err(ContractError::OracleEligiblePoolCommitPreThreshold {
                 pool_addr: pool_addr.to_string(),
             });
@@ -629,7 +560,7 @@ fn resolve_pool_for_allowlist(
     Ok((pool_details, bluechip_index))
 }
 
-/// Admin-only. Stage a pool address for inclusion in the oracle allowlist.
+/// Oracle curator role. Stage a pool address for inclusion in the oracle allowlist.
 /// Validates pool existence + bluechip-side resolution at propose time so
 /// the timelock isn't burned on a pool that can't possibly be eligible;
 /// the same validation runs again at apply time as defense in depth.
@@ -639,7 +570,7 @@ pub fn execute_propose_add_oracle_eligible_pool(
     info: MessageInfo,
     pool_addr: String,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
     let pool_addr = deps.api.addr_validate(&pool_addr)?;
 
     if ORACLE_ELIGIBLE_POOLS.has(deps.storage, pool_addr.clone()) {
@@ -673,7 +604,7 @@ pub fn execute_propose_add_oracle_eligible_pool(
         .add_attribute("effective_after", effective_after.to_string()))
 }
 
-/// Admin-only. Apply a previously-proposed allowlist add. Re-resolves the
+/// Oracle curator role. Apply a previously-proposed allowlist add. Re-resolves the
 /// pool's bluechip-side index against current registry state — if the pool
 /// somehow lost its bluechip side between propose and apply (which
 /// shouldn't be possible, but isn't disprovable in storage), the apply
@@ -684,7 +615,7 @@ pub fn execute_apply_add_oracle_eligible_pool(
     info: MessageInfo,
     pool_addr: String,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
     let pool_addr = deps.api.addr_validate(&pool_addr)?;
 
     let pending = PENDING_ORACLE_ELIGIBLE_POOL_ADD
@@ -724,14 +655,14 @@ pub fn execute_apply_add_oracle_eligible_pool(
         .add_attribute("bluechip_index", bluechip_index.to_string()))
 }
 
-/// Admin-only. Discard a pending allowlist add before the timelock has
+/// Oracle curator role. Discard a pending allowlist add before the timelock has
 /// expired. Errors if there is no matching pending entry.
 pub fn execute_cancel_add_oracle_eligible_pool(
     deps: DepsMut,
     info: MessageInfo,
     pool_addr: String,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
     let pool_addr = deps.api.addr_validate(&pool_addr)?;
 
     if !PENDING_ORACLE_ELIGIBLE_POOL_ADD.has(deps.storage, pool_addr.clone()) {
@@ -746,7 +677,7 @@ pub fn execute_cancel_add_oracle_eligible_pool(
         .add_attribute("pool_addr", pool_addr.to_string()))
 }
 
-/// Admin-only. Drop a pool from the oracle allowlist. Effect is immediate
+/// Oracle curator role. Drop a pool from the oracle allowlist. Effect is immediate
 /// (no timelock) — removing a contributor is always safe relative to oracle
 /// integrity, and the breaker / next-snapshot-refresh handle the
 /// recomputation cleanly.
@@ -755,7 +686,7 @@ pub fn execute_remove_oracle_eligible_pool(
     info: MessageInfo,
     pool_addr: String,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
     let pool_addr = deps.api.addr_validate(&pool_addr)?;
 
     if !ORACLE_ELIGIBLE_POOLS.has(deps.storage, pool_addr.clone()) {
@@ -770,7 +701,7 @@ pub fn execute_remove_oracle_eligible_pool(
         .add_attribute("pool_addr", pool_addr.to_string()))
 }
 
-/// Admin-only. Stage a flip of `COMMIT_POOLS_AUTO_ELIGIBLE`. Both
+/// Oracle curator role. Stage a flip of `COMMIT_POOLS_AUTO_ELIGIBLE`. Both
 /// directions (ON→OFF and OFF→ON) go through the same 48h timelock so
 /// creator-pool operators losing oracle weight have the same
 /// observability window as new operators gaining it.
@@ -780,7 +711,7 @@ pub fn execute_propose_set_commit_pools_auto_eligible(
     info: MessageInfo,
     enabled: bool,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
 
     if PENDING_COMMIT_POOLS_AUTO_ELIGIBLE.may_load(deps.storage)?.is_some() {
         return Err(ContractError::CommitPoolsAutoEligibleAlreadyPending);
@@ -806,13 +737,13 @@ pub fn execute_propose_set_commit_pools_auto_eligible(
         .add_attribute("effective_after", effective_after.to_string()))
 }
 
-/// Admin-only. Apply a previously-proposed flag flip after the timelock.
+/// Oracle curator role. Apply a previously-proposed flag flip after the timelock.
 pub fn execute_apply_set_commit_pools_auto_eligible(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
 
     let pending = PENDING_COMMIT_POOLS_AUTO_ELIGIBLE
         .may_load(deps.storage)?
@@ -833,12 +764,12 @@ pub fn execute_apply_set_commit_pools_auto_eligible(
         .add_attribute("new_value", pending.new_value.to_string()))
 }
 
-/// Admin-only. Discard a pending flag flip before the timelock has expired.
+/// Oracle curator role. Discard a pending flag flip before the timelock has expired.
 pub fn execute_cancel_set_commit_pools_auto_eligible(
     deps: DepsMut,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
 
     if PENDING_COMMIT_POOLS_AUTO_ELIGIBLE.may_load(deps.storage)?.is_none() {
         return Err(ContractError::NoPendingCommitPoolsAutoEligible);
@@ -1,9 +1,19 @@
 //! Per-pool admin forwards: pause, unpause, emergency withdraw + its
-//! cancel, and the stuck-state recovery escape hatch. All five handlers
-//! are admin-only and wrap a single `WasmMsg::Execute` to the pool
-//! contract — the pool itself gates them on
+//! cancel, the stuck-state recovery escape hatch, and the creator
+//! wallet veto. All six handlers wrap a single `WasmMsg::Execute` to the
+//! pool contract — the pool itself gates them on
 //! `info.sender == pool_info.factory_addr`, so the factory is the only
-//! entity that can issue these commands.
+//! entity that can issue these commands. Pause and unpause take the
+//! `Pauser` role; the rest are super-admin only.
+//!
+//! The protocol fee switch forwards (`SyncPoolProtocolFee`,
+//! `CollectPoolProtocolFees`) go through the same pool-side gate but are
+//! permissionless here: neither lets the caller choose a value or a
+//! recipient.
+//!
+//! `SetPoolSubscriptionTiers` and `SetPoolCommitAllowlist` are the
+//! creator-facing forwards: the factory stamps the caller in as
+//! `creator` and the pool checks it against its creator wallet.
 //!
 //! Also hosts `execute_notify_threshold_crossed`, the pool-to-factory
 //! callback fired when a pool's commit threshold crosses; it lives with
@@ -15,9 +25,10 @@ use cosmwasm_std::{
 
 use crate::error::ContractError;
 use crate::mint_bluechips_pool_creation::calculate_and_mint_bluechip;
-use crate::state::{POOLS_BY_ID, POOL_THRESHOLD_MINTED};
+use crate::state::{set_pool_threshold_crossed, Role, FACTORYINSTANTIATEINFO, POOLS_BY_ID};
 
-use super::super::ensure_admin;
+use super::super::{ensure_admin, ensure_role};
+use super::create::validate_commit_allowlist;
 
 /// Messages forwarded to the pool contract on behalf of the factory admin.
 /// The pool's handler rejects anything that isn't sent by the factory, so
@@ -35,6 +46,17 @@ enum PoolAdminMsg {
     /// verifies dormancy elapsed and `info.sender == factory_addr`
     /// before sending the residual to the bluechip wallet.
     SweepUnclaimedEmergencyShares {},
+    SetProtocolFeeShare { share: cosmwasm_std::Decimal },
+    CollectProtocolFees {},
+    SetSubscriptionTiers {
+        creator: String,
+        tiers: Vec<pool_factory_interfaces::SubscriptionTier>,
+    },
+    SetCommitAllowlist {
+        creator: String,
+        allowlist: pool_factory_interfaces::CommitAllowlist,
+    },
+    VetoCreatorWallet {},
 }
 
 fn forward_pool_admin(
@@ -45,6 +67,15 @@ fn forward_pool_admin(
     pool_msg: PoolAdminMsg,
 ) -> Result<Response, ContractError> {
     ensure_admin(deps, &info)?;
+    forward_pool_msg(deps, pool_id, action, pool_msg)
+}
+
+fn forward_pool_msg(
+    deps: Deps,
+    pool_id: u64,
+    action: &'static str,
+    pool_msg: PoolAdminMsg,
+) -> Result<Response, ContractError> {
     let pool_addr = POOLS_BY_ID
         .load(deps.storage, pool_id)
         .map_err(|_| {
@@ -66,12 +97,15 @@ fn forward_pool_admin(
         .add_attribute("pool_addr", pool_addr.to_string()))
 }
 
+/// Pauser role: immediate, so an ops key can halt a pool without the
+/// super-admin.
 pub fn execute_pause_pool(
     deps: DepsMut,
     info: MessageInfo,
     pool_id: u64,
 ) -> Result<Response, ContractError> {
-    forward_pool_admin(deps.as_ref(), info, pool_id, "pause_pool", PoolAdminMsg::Pause {})
+    ensure_role(deps.as_ref(), &info, Role::Pauser)?;
+    forward_pool_msg(deps.as_ref(), pool_id, "pause_pool", PoolAdminMsg::Pause {})
 }
 
 pub fn execute_unpause_pool(
@@ -79,7 +113,8 @@ pub fn execute_unpause_pool(
     info: MessageInfo,
     pool_id: u64,
 ) -> Result<Response, ContractError> {
-    forward_pool_admin(deps.as_ref(), info, pool_id, "unpause_pool", PoolAdminMsg::Unpause {})
+    ensure_role(deps.as_ref(), &info, Role::Pauser)?;
+    forward_pool_msg(deps.as_ref(), pool_id, "unpause_pool", PoolAdminMsg::Unpause {})
 }
 
 pub fn execute_emergency_withdraw_pool(
@@ -165,10 +200,129 @@ pub fn execute_sweep_unclaimed_emergency_shares_pool(
     )
 }
 
+/// Forwards `VetoCreatorWallet {}`; the pool rejects it once the
+/// pending wallet's veto window has closed.
+pub fn execute_veto_pool_creator_wallet(
+    deps: DepsMut,
+    info: MessageInfo,
+    pool_id: u64,
+) -> Result<Response, ContractError> {
+    forward_pool_admin(
+        deps.as_ref(),
+        info,
+        pool_id,
+        "veto_pool_creator_wallet",
+        PoolAdminMsg::VetoCreatorWallet {},
+    )
+}
+
+/// Pushes the factory's current protocol fee share for the pool's kind.
+pub fn execute_sync_pool_protocol_fee(
+    deps: DepsMut,
+    pool_id: u64,
+) -> Result<Response, ContractError> {
+    let pool_details = POOLS_BY_ID.load(deps.storage, pool_id).map_err(|_| {
+        ContractError::Std(StdError::generic_err(format!(
+            "Pool {} not found in registry",
+            pool_id
+        )))
+    })?;
+    let share = FACTORYINSTANTIATEINFO
+        .load(deps.storage)?
+        .protocol_fee
+        .share_for(&pool_details.pool_kind);
+    Ok(forward_pool_msg(
+        deps.as_ref(),
+        pool_id,
+        "sync_pool_protocol_fee",
+        PoolAdminMsg::SetProtocolFeeShare { share },
+    )?
+    .add_attribute("share", share.to_string()))
+}
+
+pub fn execute_collect_pool_protocol_fees(
+    deps: DepsMut,
+    pool_id: u64,
+) -> Result<Response, ContractError> {
+    forward_pool_msg(
+        deps.as_ref(),
+        pool_id,
+        "collect_pool_protocol_fees",
+        PoolAdminMsg::CollectProtocolFees {},
+    )
+}
+
+pub fn execute_set_pool_subscription_tiers(
+    deps: DepsMut,
+    info: MessageInfo,
+    pool_id: u64,
+    tiers: Vec<pool_factory_interfaces::SubscriptionTier>,
+) -> Result<Response, ContractError> {
+    let pool_details = POOLS_BY_ID.load(deps.storage, pool_id).map_err(|_| {
+        ContractError::Std(StdError::generic_err(format!(
+            "Pool {} not found in registry",
+            pool_id
+        )))
+    })?;
+    if pool_details.pool_kind == pool_factory_interfaces::PoolKind::Standard {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Pool {} is a standard pool; subscription tiers are creator-pool-only",
+            pool_id
+        ))));
+    }
+    let tier_count = tiers.len();
+    Ok(forward_pool_msg(
+        deps.as_ref(),
+        pool_id,
+        "set_pool_subscription_tiers",
+        PoolAdminMsg::SetSubscriptionTiers {
+            creator: info.sender.to_string(),
+            tiers,
+        },
+    )?
+    .add_attribute("creator", info.sender.to_string())
+    .add_attribute("tier_count", tier_count.to_string()))
+}
+
+pub fn execute_set_pool_commit_allowlist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pool_id: u64,
+    allowlist: pool_factory_interfaces::CommitAllowlist,
+) -> Result<Response, ContractError> {
+    let pool_details = POOLS_BY_ID.load(deps.storage, pool_id).map_err(|_| {
+        ContractError::Std(StdError::generic_err(format!(
+            "Pool {} not found in registry",
+            pool_id
+        )))
+    })?;
+    if pool_details.pool_kind == pool_factory_interfaces::PoolKind::Standard {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Pool {} is a standard pool; commit allowlists are creator-pool-only",
+            pool_id
+        ))));
+    }
+    // The raise deadline lives on the pool, which checks it on apply.
+    validate_commit_allowlist(deps.as_ref(), &env, &allowlist, None)?;
+    let public_at = allowlist.public_at;
+    Ok(forward_pool_msg(
+        deps.as_ref(),
+        pool_id,
+        "set_pool_commit_allowlist",
+        PoolAdminMsg::SetCommitAllowlist {
+            creator: info.sender.to_string(),
+            allowlist,
+        },
+    )?
+    .add_attribute("creator", info.sender.to_string())
+    .add_attribute("public_at", public_at.seconds().to_string()))
+}
+
 /// Called by a pool when its commit threshold has been crossed. Triggers
 /// the bluechip mint for this pool (only once per pool — the
-/// `POOL_THRESHOLD_MINTED` gate prevents a malicious pool from calling
-/// back repeatedly).
+/// `PoolDetails.threshold_crossed` gate prevents a malicious pool from
+/// calling back repeatedly).
 pub fn execute_notify_threshold_crossed(
     mut deps: DepsMut,
     env: Env,
@@ -201,17 +355,12 @@ pub fn execute_notify_threshold_crossed(
     }
 
     // Check if this pool has already triggered its mint
-    if POOL_THRESHOLD_MINTED
-        .may_load(deps.storage, pool_id)?
-        .unwrap_or(false)
-    {
+    if !set_pool_threshold_crossed(deps.storage, pool_id)? {
         return Err(ContractError::Std(StdError::generic_err(
             "Bluechip mint already triggered for this pool",
         )));
     }
 
-    POOL_THRESHOLD_MINTED.save(deps.storage, pool_id, &true)?;
-
     let mint_messages = calculate_and_mint_bluechip(&mut deps, env, pool_id)?;
 
     Ok(Response::new()
@@ -7,20 +7,24 @@
 //! but share the same reply-ID / register_pool plumbing downstream.
 
 use cosmwasm_std::{
-    to_json_binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdError, SubMsg,
-    Uint128, WasmMsg,
+    to_json_binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    SubMsg, Timestamp, Uint128, WasmMsg,
 };
+use pool_factory_interfaces::CommitAllowlist;
 use cw20::MinterResponse;
 use cw_utils::{must_pay, PaymentError};
 
 use crate::error::ContractError;
-use crate::msg::{CreatorTokenInfo, TokenInstantiateMsg};
+use crate::msg::{CreatorTokenInfo, TokenInstantiateMsg, TokenMarketingInfo};
 use crate::pool_struct::{CreatePool, TempPoolCreation};
 use crate::state::{
-    canonical_pair_key, CreationStatus, COMMIT_POOL_COUNTER,
-    COMMIT_POOL_CREATE_RATE_LIMIT_SECONDS, FACTORYINSTANTIATEINFO, LAST_COMMIT_POOL_CREATE_AT,
-    LAST_STANDARD_POOL_CREATE_AT, PAIRS, POOL_COUNTER, POOL_CREATION_CONTEXT,
-    PoolCreationContext, PoolCreationState, STANDARD_POOL_CREATE_RATE_LIMIT_SECONDS,
+    canonical_pair_key, pool_id_for_pair, CreationStatus, PoolCreationContext, PoolCreationState,
+    COMMIT_ALLOWLIST_MAX_PHASE_SECONDS, COMMIT_ALLOWLIST_MAX_WALLETS, COMMIT_POOL_COUNTER,
+    COMMIT_POOL_CREATE_RATE_LIMIT_SECONDS, EARLY_SUPPORTER_DECAY_MAX_SECONDS,
+    EARLY_SUPPORTER_DECAY_MIN_SECONDS, EARLY_SUPPORTER_MAX_MULTIPLIER, FACTORYINSTANTIATEINFO,
+    LAST_COMMIT_POOL_CREATE_AT, LAST_STANDARD_POOL_CREATE_AT, POOL_COUNTER, POOL_CREATION_CONTEXT,
+    RAISE_DEADLINE_MAX_SECONDS, RAISE_DEADLINE_MIN_SECONDS,
+    STANDARD_POOL_CREATE_RATE_LIMIT_SECONDS,
 };
 
 use super::super::{encode_reply_id, MINT_STANDARD_NFT, SET_TOKENS};
@@ -85,6 +89,60 @@ pub(crate) fn validate_pool_token_info(
     }
 }
 
+/// Validates a commit allowlist, at `Create` and on every
+/// `SetPoolCommitAllowlist`:
+/// - a Merkle root (32 bytes) or at least one on-chain wallet
+/// - at most `COMMIT_ALLOWLIST_MAX_WALLETS` wallets, each a valid address
+/// - `public_at` after now, within `COMMIT_ALLOWLIST_MAX_PHASE_SECONDS`,
+///   and before the raise deadline when one is set
+pub(crate) fn validate_commit_allowlist(
+    deps: Deps,
+    env: &Env,
+    allowlist: &CommitAllowlist,
+    raise_deadline: Option<Timestamp>,
+) -> Result<(), ContractError> {
+    match &allowlist.merkle_root {
+        Some(root) if root.len() != 32 => {
+            return Err(ContractError::Std(StdError::generic_err(format!(
+                "commit_allowlist.merkle_root must be 32 bytes, got {}",
+                root.len()
+            ))));
+        }
+        None if allowlist.wallets.is_empty() => {
+            return Err(ContractError::Std(StdError::generic_err(
+                "commit_allowlist needs a merkle_root or at least one wallet",
+            )));
+        }
+        _ => {}
+    }
+    if allowlist.wallets.len() > COMMIT_ALLOWLIST_MAX_WALLETS {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "commit_allowlist.wallets has {} entries, maximum is {}",
+            allowlist.wallets.len(),
+            COMMIT_ALLOWLIST_MAX_WALLETS
+        ))));
+    }
+    for wallet in &allowlist.wallets {
+        deps.api.addr_validate(wallet)?;
+    }
+    let latest = env
+        .block
+        .time
+        .plus_seconds(COMMIT_ALLOWLIST_MAX_PHASE_SECONDS);
+    if allowlist.public_at <= env.block.time || allowlist.public_at > latest {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "commit_allowlist.public_at {} must be after {} and at most {}",
+            allowlist.public_at, env.block.time, latest
+        ))));
+    }
+    if raise_deadline.is_some_and(|deadline| allowlist.public_at >= deadline) {
+        return Err(ContractError::Std(StdError::generic_err(
+            "commit_allowlist.public_at must be before raise_deadline",
+        )));
+    }
+    Ok(())
+}
+
 /// Validates creator token metadata before any state is written.
 /// - decimals must be 6 (threshold payout and mint cap are calibrated for 6-decimal tokens)
 /// - name: 3-50 chars, printable ASCII only (no control chars, no extended unicode)
@@ -158,6 +216,39 @@ pub(crate) fn execute_create_creator_pool(
     validate_creator_token_info(&token_info)?;
     let factory_cw20 = FACTORYINSTANTIATEINFO.load(deps.storage)?;
     validate_pool_token_info(&pool_msg.pool_token_info, &factory_cw20.bluechip_denom)?;
+    if let Some(deadline) = pool_msg.raise_deadline {
+        let earliest = env.block.time.plus_seconds(RAISE_DEADLINE_MIN_SECONDS);
+        let latest = env.block.time.plus_seconds(RAISE_DEADLINE_MAX_SECONDS);
+        if deadline < earliest || deadline > latest {
+            return Err(ContractError::Std(StdError::generic_err(format!(
+                "raise_deadline {} must be between {} and {}",
+                deadline, earliest, latest
+            ))));
+        }
+    }
+    if let Some(allowlist) = &pool_msg.commit_allowlist {
+        validate_commit_allowlist(deps.as_ref(), &env, allowlist, pool_msg.raise_deadline)?;
+    }
+    if let Some(curve) = &pool_msg.early_supporter_curve {
+        if curve.max_multiplier <= Decimal::one()
+            || curve.max_multiplier > EARLY_SUPPORTER_MAX_MULTIPLIER
+        {
+            return Err(ContractError::Std(StdError::generic_err(format!(
+                "early_supporter_curve.max_multiplier {} must be above 1 and at most {}",
+                curve.max_multiplier, EARLY_SUPPORTER_MAX_MULTIPLIER
+            ))));
+        }
+        if !(EARLY_SUPPORTER_DECAY_MIN_SECONDS..=EARLY_SUPPORTER_DECAY_MAX_SECONDS)
+            .contains(&curve.decay_seconds)
+        {
+            return Err(ContractError::Std(StdError::generic_err(format!(
+                "early_supporter_curve.decay_seconds {} must be between {} and {}",
+                curve.decay_seconds,
+                EARLY_SUPPORTER_DECAY_MIN_SECONDS,
+                EARLY_SUPPORTER_DECAY_MAX_SECONDS
+            ))));
+        }
+    }
 
     // Per-address rate limit. Reject if `info.sender` already
     // created a commit pool within the last
@@ -321,6 +412,8 @@ pub(crate) fn execute_create_creator_pool(
     }
 
     let creator_attr = info.sender.to_string();
+    // First commit pool from this wallet opens its creator profile.
+    crate::execute::creators::open_creator_profile(deps.storage, &info.sender, env.block.time)?;
     let pool_counter = POOL_COUNTER.may_load(deps.storage)?.unwrap_or(0);
     let pool_id = pool_counter + 1;
     POOL_COUNTER.save(deps.storage, &pool_id)?;
@@ -357,6 +450,12 @@ pub(crate) fn execute_create_creator_pool(
                 // additional supply be created.
                 cap: Some(factory_cw20.threshold_payout_amounts.total_mint()?),
             }),
+            marketing: Some(TokenMarketingInfo {
+                project: None,
+                description: None,
+                marketing: Some(env.contract.address.to_string()),
+                logo: None,
+            }),
         })?,
         //no initial balance. waits until threshold is crossed to mint creator tokens.
         funds: vec![],
@@ -532,7 +631,7 @@ pub(crate) fn execute_create_standard_pool(
     // we only canonicalize already-shape-validated pairs, so the key
     // function never sees malformed input.
     let pair_key = canonical_pair_key(&pool_token_info);
-    if let Some(existing) = PAIRS.may_load(deps.storage, pair_key.clone())? {
+    if let Some(existing) = pool_id_for_pair(deps.storage, pair_key.clone())? {
         return Err(ContractError::DuplicatePair {
             existing_pool_id: existing,
             asset_a: pair_key.0,
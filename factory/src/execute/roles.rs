@@ -0,0 +1,123 @@
+//! Role grants and revokes. Super-admin only, and every change waits out
+//! the standard 48h timelock before it lands: propose, then apply after
+//! `ADMIN_TIMELOCK_SECONDS`, or cancel in between. The gate the roles
+//! feed is [`super::ensure_role`].
+
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use crate::error::ContractError;
+use crate::state::{
+    PendingRoleChange, Role, ADMIN_TIMELOCK_SECONDS, PENDING_ROLE_CHANGES, ROLE_HOLDERS,
+};
+
+use super::ensure_admin;
+
+/// Super-admin only. Stages a grant (`grant = true`) or revoke of `role`
+/// for `holder`. Rejects no-op changes so the timelock isn't burned on
+/// them.
+pub fn execute_propose_role_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: Role,
+    holder: String,
+    grant: bool,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.as_ref(), &info)?;
+    let holder = deps.api.addr_validate(&holder)?;
+    let key = (role.as_str(), &holder);
+
+    if PENDING_ROLE_CHANGES.has(deps.storage, key) {
+        return Err(ContractError::RoleChangeAlreadyPending {
+            role: role.as_str().to_string(),
+            holder: holder.to_string(),
+        });
+    }
+    if ROLE_HOLDERS.has(deps.storage, key) == grant {
+        return Err(ContractError::RoleChangeNoChange {
+            role: role.as_str().to_string(),
+            holder: holder.to_string(),
+            state: if grant { "holds" } else { "lacks" },
+        });
+    }
+
+    PENDING_ROLE_CHANGES.save(
+        deps.storage,
+        key,
+        &PendingRoleChange {
+            grant,
+            proposed_at: env.block.time,
+        },
+    )?;
+
+    let effective_after = env.block.time.plus_seconds(ADMIN_TIMELOCK_SECONDS);
+    Ok(Response::new()
+        .add_attribute("action", "propose_role_change")
+        .add_attribute("role", role.as_str())
+        .add_attribute("holder", holder.to_string())
+        .add_attribute("grant", grant.to_string())
+        .add_attribute("effective_after", effective_after.to_string()))
+}
+
+/// Super-admin only. Lands a pending role change once its timelock has
+/// elapsed.
+pub fn execute_apply_role_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: Role,
+    holder: String,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.as_ref(), &info)?;
+    let holder = deps.api.addr_validate(&holder)?;
+    let key = (role.as_str(), &holder);
+
+    let pending = PENDING_ROLE_CHANGES
+        .may_load(deps.storage, key)?
+        .ok_or_else(|| ContractError::NoPendingRoleChange {
+            role: role.as_str().to_string(),
+            holder: holder.to_string(),
+        })?;
+    let effective_after = pending.proposed_at.plus_seconds(ADMIN_TIMELOCK_SECONDS);
+    if env.block.time < effective_after {
+        return Err(ContractError::TimelockNotExpired { effective_after });
+    }
+
+    if pending.grant {
+        ROLE_HOLDERS.save(deps.storage, key, &env.block.time)?;
+    } else {
+        ROLE_HOLDERS.remove(deps.storage, key);
+    }
+    PENDING_ROLE_CHANGES.remove(deps.storage, key);
+
+    Ok(Response::new()
+        .add_attribute("action", "apply_role_change")
+        .add_attribute("role", role.as_str())
+        .add_attribute("holder", holder.to_string())
+        .add_attribute("grant", pending.grant.to_string()))
+}
+
+/// Super-admin only. Discards a pending role change.
+pub fn execute_cancel_role_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    holder: String,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.as_ref(), &info)?;
+    let holder = deps.api.addr_validate(&holder)?;
+    let key = (role.as_str(), &holder);
+
+    if !PENDING_ROLE_CHANGES.has(deps.storage, key) {
+        return Err(ContractError::NoPendingRoleChange {
+            role: role.as_str().to_string(),
+            holder: holder.to_string(),
+        });
+    }
+    PENDING_ROLE_CHANGES.remove(deps.storage, key);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_role_change")
+        .add_attribute("role", role.as_str())
+        .add_attribute("holder", holder.to_string()))
+}
@@ -12,6 +12,9 @@
 //!
 //! Paused pools are skipped rather than migrated — the admin must unpause
 //! and re-run to include them.
+//!
+//! Every step is gated on the `Upgrader` role (the super-admin holds it
+//! implicitly).
 
 use cosmwasm_std::{
     Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult,
@@ -20,10 +23,10 @@ use cosmwasm_std::{
 
 use crate::error::ContractError;
 use crate::state::{
-    PoolUpgrade, ADMIN_TIMELOCK_SECONDS, PENDING_POOL_UPGRADE, POOLS_BY_ID,
+    PoolUpgrade, Role, ADMIN_TIMELOCK_SECONDS, PENDING_POOL_UPGRADE, POOLS_BY_ID,
 };
 
-use super::ensure_admin;
+use super::ensure_role;
 
 pub fn execute_propose_pool_upgrade(
     deps: DepsMut,
@@ -34,7 +37,7 @@ pub fn execute_propose_pool_upgrade(
     pool_ids: Option<Vec<u64>>,
     migrate_msg: Binary,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::Upgrader)?;
 
     if PENDING_POOL_UPGRADE.may_load(deps.storage)?.is_some() {
         return Err(ContractError::Std(StdError::generic_err(
@@ -260,7 +263,7 @@ pub fn execute_apply_pool_upgrade(
     env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::Upgrader)?;
 
     let upgrade = PENDING_POOL_UPGRADE.load(deps.storage)?;
 
@@ -334,7 +337,7 @@ pub fn execute_cancel_pool_upgrade(
     deps: DepsMut,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::Upgrader)?;
 
     let upgrade = PENDING_POOL_UPGRADE.may_load(deps.storage)?;
     if upgrade.is_none() {
@@ -352,12 +355,12 @@ pub fn execute_continue_pool_upgrade(
     env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    // Admin-only now. Previously this was self-called from
+    // Upgrader-only now. Previously this was self-called from
     // execute_apply_pool_upgrade, which worked only until the pool list grew
     // large enough that the chained execute messages exceeded block gas
-    // limits in a single tx. Making this admin-only forces batches to be
+    // limits in a single tx. Gating it on a caller forces batches to be
     // submitted as separate transactions, each with its own gas budget.
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::Upgrader)?;
 
     let mut upgrade = PENDING_POOL_UPGRADE.load(deps.storage)?;
 
@@ -2,9 +2,8 @@
 use crate::pyth_types::{PriceFeedResponse, PythQueryMsg};
 
 use crate::state::{
-    EligiblePoolSnapshot, ELIGIBLE_POOL_REFRESH_BLOCKS, ELIGIBLE_POOL_SNAPSHOT,
-    FACTORYINSTANTIATEINFO, ORACLE_UPDATE_BOUNTY_USD,
-    POOLS_BY_ID, POOL_THRESHOLD_MINTED,
+    EligiblePoolSnapshot, Role, ELIGIBLE_POOL_REFRESH_BLOCKS, ELIGIBLE_POOL_SNAPSHOT,
+    FACTORYINSTANTIATEINFO, ORACLE_UPDATE_BOUNTY_USD, POOLS_BY_ID,
 };
 // `POOLS_BY_CONTRACT_ADDRESS` is read only by the `#[cfg(test)]` branch
 // of `query_pool_safe` (the prod path goes through `deps.querier`).
@@ -12,7 +11,7 @@ use crate::state::{
 // release builds while keeping the test path compiling unchanged.
 #[cfg(test)]
 use crate::state::POOLS_BY_CONTRACT_ADDRESS;
-use crate::execute::ensure_admin;
+use crate::execute::{ensure_admin, ensure_role};
 use crate::{asset::TokenType, error::ContractError};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
@@ -790,9 +789,11 @@ pub fn get_eligible_creator_pools(
     // a rotation interval coincided with snapshot staleness.
     //
     // New approach: random-pull-with-reject. Pick a random pool id in
-    // `[1, POOL_COUNTER]`, validate (kind == Commit, threshold-minted,
-    // bluechip side present, liquidity floor), accept on success or
-    // toss and re-pick on any failure. Capped at
+    // `[1, POOL_COUNTER]` and load exactly that record, so every crossed
+    // commit pool is equally likely and the cost tracks the number of
+    // attempts, not the registry size. Validate (kind == Commit,
+    // threshold crossed, bluechip side present, liquidity floor), accept
+    // on success or toss and re-pick on any failure. Capped at
     // `MAX_AUTO_ELIGIBLE_SAMPLE_ATTEMPTS` total attempts so a registry
     // dominated by pre-threshold or drained pools cannot brick the
     // refresh by exhausting the loop. Target sample size is
@@ -826,13 +827,14 @@ pub fn get_eligible_creator_pools(
             hasher.update(env.block.time.seconds().to_be_bytes());
             hasher.update(env.block.height.to_be_bytes());
             hasher.update(env.block.chain_id.as_bytes());
-            hasher.update((pool_count_max).to_be_bytes());
+            hasher.update(pool_count_max.to_be_bytes());
             let hash = hasher.finalize();
 
             let mut tried: HashSet<u64> = HashSet::new();
             let mut attempts = 0usize;
             while entries.len() < TARGET_SAMPLE_SIZE
                 && attempts < MAX_AUTO_ELIGIBLE_SAMPLE_ATTEMPTS
+                && (tried.len() as u64) < pool_count_max
             {
                 // Take 8 bytes from a rotating window of the hash, mixed
                 // with the attempt index so we visit distinct slots.
@@ -856,11 +858,14 @@ pub fn get_eligible_creator_pools(
                     // the current loop; skip without burning a query.
                     continue;
                 }
-
                 let pool_details = match POOLS_BY_ID.may_load(deps.storage, candidate_id)? {
                     Some(d) => d,
                     None => continue,
                 };
+                // Auto-eligible source covers crossed commit pools only.
+                if pool_details.pool_kind != PoolKind::Commit || !pool_details.threshold_crossed {
+                    continue;
+                }
 
                 let pool_addr_str = pool_details.creator_pool_addr.to_string();
                 if pool_addr_str == atom_pool_contract_address {
@@ -869,10 +874,6 @@ pub fn get_eligible_creator_pools(
                 if seen.contains(&pool_addr_str) {
                     continue;
                 }
-                // Auto-eligible source covers commit pools only.
-                if pool_details.pool_kind == PoolKind::Standard {
-                    continue;
-                }
                 let bluechip_idx = match pool_details
                     .pool_token_info
                     .iter()
@@ -881,13 +882,6 @@ pub fn get_eligible_creator_pools(
                     Some(i) => i as u8,
                     None => continue,
                 };
-                if !POOL_THRESHOLD_MINTED
-                    .may_load(deps.storage, candidate_id)?
-                    .unwrap_or(false)
-                {
-                    continue;
-                }
-
                 let pool_state: PoolStateResponseForFactory = match deps
                     .querier
                     .query_wasm_smart(pool_addr_str.clone(), &PoolQueryMsg::GetPoolState {})
@@ -2631,7 +2625,7 @@ pub fn execute_force_rotate_pools(
 // `PendingBootstrapPrice` doc on `state.rs` and branch (d) of
 // `update_internal_oracle_price` above for the full flow.
 
-/// Admin-only. Reads the buffered bootstrap-price candidate (set by
+/// Oracle curator role. Reads the buffered bootstrap-price candidate (set by
 /// branch (d) of `update_internal_oracle_price`), enforces the
 /// `BOOTSTRAP_OBSERVATION_SECONDS` (1h) observation window from the
 /// candidate's `proposed_at`, then publishes it as `last_price`
@@ -2640,7 +2634,7 @@ pub fn execute_force_rotate_pools(
 /// branch (a).
 ///
 /// Reverts when:
-/// - sender is not the factory admin
+/// - sender holds neither the `OracleCurator` role nor super-admin
 /// - no candidate is pending (admin has nothing to confirm)
 /// - `block.time < proposed_at + BOOTSTRAP_OBSERVATION_SECONDS`
 /// (insufficient observation window — admin must wait)
@@ -2649,7 +2643,7 @@ pub fn execute_confirm_bootstrap_price(
     env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
 
     let pending = crate::state::PENDING_BOOTSTRAP_PRICE
         .may_load(deps.storage)?
@@ -2738,17 +2732,17 @@ pub fn execute_confirm_bootstrap_price(
         ))
 }
 
-/// Admin-only. Discards the buffered bootstrap-price candidate. The
+/// Oracle curator role. Discards the buffered bootstrap-price candidate. The
 /// next successful `UpdateOraclePrice` round in branch (d) starts
 /// over with a fresh candidate and a fresh observation window.
 ///
-/// Reverts when sender is not the factory admin or when there is no
-/// pending candidate to cancel.
+/// Reverts when sender holds neither the `OracleCurator` role nor
+/// super-admin, or when there is no pending candidate to cancel.
 pub fn execute_cancel_bootstrap_price(
     deps: DepsMut,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref(), &info)?;
+    ensure_role(deps.as_ref(), &info, Role::OracleCurator)?;
     if crate::state::PENDING_BOOTSTRAP_PRICE
         .may_load(deps.storage)?
         .is_none()
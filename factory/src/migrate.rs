@@ -1,14 +1,22 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{Addr, DepsMut, Empty, Env, Response, StdResult};
 use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Map;
+use pool_factory_interfaces::{FeeInfoResponse, PoolKind, PoolQueryMsg};
 use semver::Version;
 
 use crate::error::ContractError;
 use crate::{CONTRACT_NAME, CONTRACT_VERSION};
 
+// Registry side maps folded into `POOLS_BY_ID`'s indexes. Read (and
+// cleared) only by `migrate`.
+const LEGACY_PAIRS: Map<(String, String), u64> = Map::new("pairs");
+const LEGACY_POOL_ID_BY_ADDRESS: Map<Addr, u64> = Map::new("pool_id_by_address");
+const LEGACY_POOL_THRESHOLD_MINTED: Map<u64, bool> = Map::new("pool_threshold_minted");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, _msg: Empty) -> Result<Response, ContractError> {
     let stored_version = get_contract_version(deps.storage)?;
     let current: Version = CONTRACT_VERSION.parse()?;
     let stored_semver: Version = stored_version.version.parse()?;
@@ -45,50 +53,75 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
         crate::state::COMMIT_POOLS_AUTO_ELIGIBLE.save(deps.storage, &true)?;
     }
 
-    // PAIRS back-fill. Older deployments registered pools through the
-    // pre-uniqueness `register_pool`, so `PAIRS` is empty even though
-    // pools exist. Walk `POOLS_BY_ID` once and insert one entry per
-    // pair, keeping the FIRST pool seen for any given pair (lowest
-    // `pool_id`) and skipping subsequent duplicates. This preserves
-    // any legacy duplicates already registered (they remain queryable
-    // via `POOLS_BY_ID` / `POOLS_BY_CONTRACT_ADDRESS`) but blocks any
-    // FURTHER duplicate creations of the same pair after migration —
-    // which is the security-relevant invariant we care about.
+    // Registry re-index. `POOLS_BY_ID` used to be a plain map with
+    // `PAIRS`, `POOL_ID_BY_ADDRESS` and `POOL_THRESHOLD_MINTED` beside it;
+    // its records are stored unchanged under the same key, so re-saving
+    // each one through the `IndexedMap` writes its index entries. The
+    // old threshold flags move onto `PoolDetails.threshold_crossed`
+    // (standard pools count as crossed) and the three legacy maps are
+    // cleared. Commit pools from before `PoolDetails.creator` existed
+    // get their creator from the pool's own fee info (and an empty
+    // profile, as `Create` would have opened); a pool that cannot answer
+    // keeps `creator: None` rather than blocking the migrate. Standard
+    // pools have no creator.
     //
-    // `range(..)` already iterates in ascending pool_id order, so the
-    // first-seen pool wins naturally without a sort.
+    // Chains that predate the pair guard may hold duplicate pairs. They
+    // stay registered and the lowest pool id remains the pair's pool,
+    // which blocks any FURTHER duplicate creations of the same pair —
+    // the security-relevant invariant we care about. `range(..)`
+    // iterates in ascending pool_id order, so the first-seen pool wins
+    // naturally.
     //
-    // Idempotent: if PAIRS is already populated (re-run migrate), the
-    // `may_load` check below short-circuits each entry as a no-op.
+    // Idempotent: re-saving an already indexed record rewrites the same
+    // index entries, and the counters only count entries that were
+    // missing.
     let pool_ids: Vec<u64> = crate::state::POOLS_BY_ID
         .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .collect::<cosmwasm_std::StdResult<Vec<u64>>>()?;
+        .collect::<StdResult<Vec<u64>>>()?;
     let mut backfilled: u32 = 0;
     let mut legacy_duplicates: u32 = 0;
-    // POOL_ID_BY_ADDRESS reverse-index back-fill. Same walk as PAIRS,
-    // no extra IO. Idempotent — `may_load`-then-save short-circuits if
-    // already populated by a prior migrate or a fresh register_pool.
     let mut addr_index_backfilled: u32 = 0;
+    let mut creators_backfilled: u32 = 0;
     for pool_id in pool_ids {
-        let details = crate::state::POOLS_BY_ID.load(deps.storage, pool_id)?;
+        let mut details = crate::state::POOLS_BY_ID.load(deps.storage, pool_id)?;
         let key = crate::state::canonical_pair_key(&details.pool_token_info);
-        if crate::state::PAIRS.may_load(deps.storage, key.clone())?.is_none() {
-            crate::state::PAIRS.save(deps.storage, key, &pool_id)?;
-            backfilled += 1;
-        } else {
-            legacy_duplicates += 1;
+        match crate::state::pool_id_for_pair(deps.storage, key)? {
+            None => backfilled += 1,
+            Some(existing) if existing < pool_id => legacy_duplicates += 1,
+            Some(_) => {}
         }
-        if crate::state::POOL_ID_BY_ADDRESS
-            .may_load(deps.storage, details.creator_pool_addr.clone())?
-            .is_none()
-        {
-            crate::state::POOL_ID_BY_ADDRESS.save(
-                deps.storage,
-                details.creator_pool_addr.clone(),
-                &pool_id,
-            )?;
+        if crate::state::pool_by_address(deps.storage, &details.creator_pool_addr)?.is_none() {
             addr_index_backfilled += 1;
         }
+        details.threshold_crossed = details.threshold_crossed
+            || details.pool_kind == PoolKind::Standard
+            || LEGACY_POOL_THRESHOLD_MINTED
+                .may_load(deps.storage, pool_id)?
+                .unwrap_or(false);
+        if details.creator.is_none() && details.pool_kind == PoolKind::Commit {
+            let fee_info: StdResult<FeeInfoResponse> = deps
+                .querier
+                .query_wasm_smart(details.creator_pool_addr.to_string(), &PoolQueryMsg::FeeInfo {});
+            if let Ok(resp) = fee_info {
+                let creator = resp.fee_info.creator_wallet_address;
+                crate::execute::creators::open_creator_profile(
+                    deps.storage,
+                    &creator,
+                    env.block.time,
+                )?;
+                details.creator = Some(creator);
+                creators_backfilled += 1;
+            }
+        }
+        crate::state::POOLS_BY_ID.save(deps.storage, pool_id, &details)?;
+        LEGACY_POOL_THRESHOLD_MINTED.remove(deps.storage, pool_id);
+        LEGACY_POOL_ID_BY_ADDRESS.remove(deps.storage, details.creator_pool_addr);
+    }
+    let legacy_pairs: Vec<(String, String)> = LEGACY_PAIRS
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for pair in legacy_pairs {
+        LEGACY_PAIRS.remove(deps.storage, pair);
     }
 
     // Integration-test-only: rotation_interval and update_interval on the
@@ -117,5 +150,6 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
         .add_attribute(
             "pool_id_by_address_backfilled",
             addr_index_backfilled.to_string(),
-        ))
+        )
+        .add_attribute("creators_backfilled", creators_backfilled.to_string()))
 }
@@ -5,7 +5,9 @@ use cosmwasm_std::{
     from_json, to_json_binary, Addr, Coin, Empty, OwnedDeps, Querier, QuerierResult, QueryRequest,
     SystemError, SystemResult, WasmQuery,
 };
-use pool_factory_interfaces::{IsPausedResponse, PoolQueryMsg, PoolStateResponseForFactory};
+use pool_factory_interfaces::{
+    CommitFeeInfo, FeeInfoResponse, IsPausedResponse, PoolQueryMsg, PoolStateResponseForFactory,
+};
 
 use crate::pyth_types::{PriceResponse, PythQueryMsg};
 use crate::query::QueryMsg;
@@ -43,6 +45,9 @@ pub struct WasmMockQuerier {
     // returns the same numbers regardless of which pool was asked.
     pub pool_state_overrides:
         std::collections::HashMap<String, PoolStateResponseForFactory>,
+    // Per-pool creator wallets for `PoolQueryMsg::FeeInfo`. Pools without
+    // an entry reject the query.
+    pub pool_creator_wallets: std::collections::HashMap<String, Addr>,
 }
 
 impl Querier for WasmMockQuerier {
@@ -107,6 +112,25 @@ impl WasmMockQuerier {
                                 to_json_binary(&IsPausedResponse { paused }).into(),
                             );
                         }
+                        PoolQueryMsg::FeeInfo {} => {
+                            if let Some(creator) =
+                                self.pool_creator_wallets.get(contract_addr.as_str())
+                            {
+                                let resp = FeeInfoResponse {
+                                    fee_info: CommitFeeInfo {
+                                        bluechip_wallet_address: Addr::unchecked("bluechip"),
+                                        creator_wallet_address: creator.clone(),
+                                        commit_fee_bluechip: cosmwasm_std::Decimal::percent(1),
+                                        commit_fee_creator: cosmwasm_std::Decimal::percent(5),
+                                    },
+                                };
+                                return SystemResult::Ok(to_json_binary(&resp).into());
+                            }
+                            return SystemResult::Err(SystemError::InvalidRequest {
+                                error: "Unsupported pool query".to_string(),
+                                request: msg.clone(),
+                            });
+                        }
                         _ => {
                             return SystemResult::Err(SystemError::InvalidRequest {
                                 error: "Unsupported pool query".to_string(),
@@ -157,6 +181,7 @@ impl WasmMockQuerier {
             query_error_pools: std::collections::HashSet::new(),
             mock_bluechip_usd_price: None,
             pool_state_overrides: std::collections::HashMap::new(),
+            pool_creator_wallets: std::collections::HashMap::new(),
         }
     }
 
@@ -1,11 +1,15 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
 
-use cw20::{Cw20Coin, MinterResponse};
+use cw20::{Cw20Coin, Logo, MinterResponse};
 
 use crate::asset::TokenType;
 use crate::pool_struct::{CommitFeeInfo, CreatePool, PoolConfigUpdate, RecoveryType};
-use crate::state::FactoryInstantiate;
+use crate::state::{FactoryInstantiate, Role};
+use pool_factory_interfaces::{
+    CommitAllowlist, CreatorVestingConfig, DistributionMode, EarlySupporterCurve,
+    LaunchProtectionConfig, SubscriptionTier,
+};
 
 //triggers inside factory reply, used to complete the pool creation process.
 #[cw_serde]
@@ -25,6 +29,14 @@ pub struct CreatePoolReplyMsg {
     pub position_nft_address: Addr,
     pub max_bluechip_lock_per_pool: Uint128,
     pub creator_excess_liquidity_lock_days: u64,
+    pub launch_protection: LaunchProtectionConfig,
+    pub protocol_fee_share: Decimal,
+    pub raise_deadline: Option<Timestamp>,
+    pub creator_vesting: CreatorVestingConfig,
+    pub creator_wallet_veto_seconds: u64,
+    pub distribution_mode: DistributionMode,
+    pub early_supporter_curve: Option<EarlySupporterCurve>,
+    pub commit_allowlist: Option<CommitAllowlist>,
 }
 
 #[cw_serde]
@@ -44,6 +56,7 @@ pub enum ExecuteMsg {
     ProposeForceRotateOraclePools {},
     CancelForceRotateOraclePools {},
     ForceRotateOraclePools {},
+    // Upgrader role, as are the execute / cancel / continue steps below.
     UpgradePools {
         new_code_id: u64,
         pool_ids: Option<Vec<u64>>,
@@ -70,9 +83,10 @@ pub enum ExecuteMsg {
         pool_id: u64,
     },
 
-    // Admin-only pool admin forwards. The pool checks that info.sender ==
+    // Pool admin forwards. The pool checks that info.sender ==
     // pool_info.factory_addr, so these must be routed through the factory
-    // contract rather than called directly.
+    // contract rather than called directly. Pause / unpause take the
+    // pauser role; the rest are super-admin only.
     PausePool {
         pool_id: u64,
     },
@@ -96,17 +110,46 @@ pub enum ExecuteMsg {
     SweepUnclaimedEmergencyPool {
         pool_id: u64,
     },
+    /// Admin-only. Drops a commit pool's pending creator wallet change
+    /// while its `creator_wallet_veto_seconds` window is still open.
+    VetoPoolCreatorWallet {
+        pool_id: u64,
+    },
     RecoverPoolStuckStates {
         pool_id: u64,
         recovery_type: RecoveryType,
     },
-    // Admin sets the per-call bounty paid to anyone who successfully
+    /// Pushes the current `protocol_fee` share for the pool's kind to
+    /// the pool. Permissionless: the value comes from the timelocked
+    /// factory config, so anyone may propagate an applied update.
+    SyncPoolProtocolFee {
+        pool_id: u64,
+    },
+    /// Has the pool pay its owed protocol fees to the bluechip wallet.
+    /// Permissionless: the recipient is fixed by the factory config.
+    CollectPoolProtocolFees {
+        pool_id: u64,
+    },
+    /// Replaces a commit pool's membership tiers. Open to anyone here;
+    /// the pool only accepts it when the sender is its creator wallet.
+    SetPoolSubscriptionTiers {
+        pool_id: u64,
+        tiers: Vec<SubscriptionTier>,
+    },
+    /// Replaces a commit pool's allowlist phase. Open to anyone here;
+    /// the pool only accepts it from its creator wallet, and only while
+    /// no commit has landed or the current phase is still running.
+    SetPoolCommitAllowlist {
+        pool_id: u64,
+        allowlist: CommitAllowlist,
+    },
+    // Treasurer role. Sets the per-call bounty paid to anyone who successfully
     // invokes UpdateOraclePrice. Capped by MAX_ORACLE_UPDATE_BOUNTY.
     // Set to zero to disable the bounty entirely.
     SetOracleUpdateBounty {
         new_bounty: Uint128,
     },
-    // Admin sets the per-batch bounty paid to keepers calling
+    // Treasurer role. Sets the per-batch bounty paid to keepers calling
     // pool.ContinueDistribution. Capped by MAX_DISTRIBUTION_BOUNTY.
     // Set to zero to disable the bounty entirely.
     SetDistributionBounty {
@@ -173,7 +216,7 @@ pub enum ExecuteMsg {
     // BOOTSTRAP_OBSERVATION_SECONDS) and then calls `ConfirmBootstrapPrice`
     // to publish it. Mitigates the single-block anchor-manipulation
     // window that would otherwise let an attacker anchor the breaker
-    // for branch (a) to a chosen value.
+    // for branch (a) to a chosen value. Oracle curator role.
     ConfirmBootstrapPrice {},
     CancelBootstrapPrice {},
     // permissionless storage hygiene. Iterates the
@@ -199,7 +242,7 @@ pub enum ExecuteMsg {
     // creator pools flow in automatically.
     // Add: 48h timelock. Remove: immediate. Flag flip: 48h timelock.
 
-    /// Admin-only. Stage a pool address for inclusion in the oracle
+    /// Oracle curator role. Stage a pool address for inclusion in the oracle
     /// allowlist. Requires the standard 48h timelock before
     /// `ApplyAddOracleEligiblePool` can land it. Validates at propose
     /// time that the pool exists in `POOLS_BY_ID` and has a bluechip
@@ -207,19 +250,19 @@ pub enum ExecuteMsg {
     ProposeAddOracleEligiblePool {
         pool_addr: String,
     },
-    /// Admin-only. Apply a previously-proposed oracle-allowlist add
+    /// Oracle curator role. Apply a previously-proposed oracle-allowlist add
     /// after the 48h timelock has elapsed. Re-validates the pool's
     /// bluechip-side index against current registry state.
     ApplyAddOracleEligiblePool {
         pool_addr: String,
     },
-    /// Admin-only. Discard a pending oracle-allowlist add before
+    /// Oracle curator role. Discard a pending oracle-allowlist add before
     /// the timelock has expired. No-op on already-applied adds
     /// (use `RemoveOracleEligiblePool` for those).
     CancelAddOracleEligiblePool {
         pool_addr: String,
     },
-    /// Admin-only. Drop a pool from the oracle allowlist. Effect is
+    /// Oracle curator role. Drop a pool from the oracle allowlist. Effect is
     /// immediate (no timelock) — removing a contributor is always
     /// safe relative to oracle integrity, and the breaker /
     /// snapshot-refresh machinery handles the consequent
@@ -227,7 +270,7 @@ pub enum ExecuteMsg {
     RemoveOracleEligiblePool {
         pool_addr: String,
     },
-    /// Admin-only. Stage a flip of the global `COMMIT_POOLS_AUTO_ELIGIBLE`
+    /// Oracle curator role. Stage a flip of the global `COMMIT_POOLS_AUTO_ELIGIBLE`
     /// flag. Standard 48h timelock before `ApplySetCommitPoolsAutoEligible`
     /// can land it. Both ON→OFF and OFF→ON are timelocked: turning OFF
     /// affects creator-pool operators who will lose oracle weight, and
@@ -235,10 +278,10 @@ pub enum ExecuteMsg {
     ProposeSetCommitPoolsAutoEligible {
         enabled: bool,
     },
-    /// Admin-only. Apply the previously-proposed flag flip after the
+    /// Oracle curator role. Apply the previously-proposed flag flip after the
     /// 48h timelock has elapsed.
     ApplySetCommitPoolsAutoEligible {},
-    /// Admin-only. Discard a pending flag flip before the timelock
+    /// Oracle curator role. Discard a pending flag flip before the timelock
     /// has expired.
     CancelSetCommitPoolsAutoEligible {},
     /// Permissionless. Force a rebuild of `ELIGIBLE_POOL_SNAPSHOT` from
@@ -250,6 +293,59 @@ pub enum ExecuteMsg {
     /// Has no effect on which pools are eligible — only on when the
     /// snapshot reflects the current eligibility inputs.
     RefreshOraclePoolSnapshot {},
+
+    // ---- Creator profiles ----
+    //
+    // A profile opens automatically on a wallet's first `Create`; its
+    // pool list is every pool registered with that wallet as creator.
+    // A pool's creator wallet rotation re-lists the pool under the new
+    // wallet and carries the profile over without the verified badge.
+
+    /// Creator-only. Replaces the sender's profile metadata: display
+    /// name (≤ 64 bytes), avatar URI and up to 5 links (≤ 256 bytes
+    /// each). Omitted fields are cleared.
+    UpdateCreatorProfile {
+        display_name: Option<String>,
+        avatar_uri: Option<String>,
+        #[serde(default)]
+        links: Vec<String>,
+    },
+    /// Admin-only. Grants (`true`) or revokes (`false`) a creator's
+    /// verified badge. Immediate.
+    SetCreatorVerified {
+        creator: String,
+        verified: bool,
+    },
+    /// Pool-only. Sent by a commit pool when its creator accepts a
+    /// wallet rotation: the pool is re-listed under `new_wallet` and the
+    /// previous wallet's profile moves with it, unverified.
+    NotifyCreatorWalletRotated {
+        new_wallet: String,
+    },
+
+    // ---- Roles ----
+    //
+    // The super-admin (`factory_admin_address`) holds every role and
+    // alone manages them. Grants and revokes: 48h timelock.
+
+    /// Super-admin only. Stage a grant (`grant: true`) or revoke of
+    /// `role` for `holder`.
+    ProposeRoleChange {
+        role: Role,
+        holder: String,
+        grant: bool,
+    },
+    /// Super-admin only. Apply a staged role change after the 48h
+    /// timelock has elapsed.
+    ApplyRoleChange {
+        role: Role,
+        holder: String,
+    },
+    /// Super-admin only. Discard a staged role change.
+    CancelRoleChange {
+        role: Role,
+        holder: String,
+    },
 }
 
 #[cw_serde]
@@ -264,6 +360,19 @@ pub struct TokenInstantiateMsg {
     pub decimals: u8,
     pub initial_balances: Vec<Cw20Coin>,
     pub mint: Option<MinterResponse>,
+    /// cw20-base's `InstantiateMarketingInfo`. The factory only names
+    /// itself marketing admin here and hands the role to the pool with
+    /// the minter, so the creator can fill in the fields later through
+    /// the pool's `UpdateTokenMarketing`.
+    pub marketing: Option<TokenMarketingInfo>,
+}
+
+#[cw_serde]
+pub struct TokenMarketingInfo {
+    pub project: Option<String>,
+    pub description: Option<String>,
+    pub marketing: Option<String>,
+    pub logo: Option<Logo>,
 }
 
 #[cw_serde]
@@ -101,6 +101,18 @@ pub fn give_pool_ownership_cw20_and_nft(
             funds: vec![],
         }
         .into(),
+        // Marketing admin follows the minter so the pool can forward the
+        // creator's `UpdateTokenMarketing`.
+        WasmMsg::Execute {
+            contract_addr: token_addr.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::UpdateMarketing {
+                project: None,
+                description: None,
+                marketing: Some(pool_addr_str.clone()),
+            })?,
+            funds: vec![],
+        }
+        .into(),
         WasmMsg::Execute {
             contract_addr: nft_addr.to_string(),
             msg: to_json_binary(&Cw721ExecuteMsg::<()>::UpdateOwnership(
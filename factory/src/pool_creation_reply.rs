@@ -150,6 +150,16 @@ pub fn mint_create_pool(
         position_nft_address: nft_address.clone(),
         max_bluechip_lock_per_pool: factory_config.max_bluechip_lock_per_pool,
         creator_excess_liquidity_lock_days: factory_config.creator_excess_liquidity_lock_days,
+        launch_protection: factory_config.launch_protection.clone(),
+        protocol_fee_share: factory_config
+            .protocol_fee
+            .share_for(&PoolKind::Commit),
+        raise_deadline: ctx.temp.temp_pool_info.raise_deadline,
+        creator_vesting: factory_config.creator_vesting.clone(),
+        creator_wallet_veto_seconds: factory_config.creator_wallet_veto_seconds,
+        distribution_mode: ctx.temp.temp_pool_info.distribution_mode.clone(),
+        early_supporter_curve: ctx.temp.temp_pool_info.early_supporter_curve.clone(),
+        commit_allowlist: ctx.temp.temp_pool_info.commit_allowlist.clone(),
     };
     let pool_msg = WasmMsg::Instantiate {
         code_id: factory_config.create_pool_wasm_contract_id,
@@ -234,6 +244,8 @@ pub fn finalize_pool(
         // semantics rather than a global pool counter mixed with
         // permissionlessly-created standard pools.
         commit_pool_ordinal: ctx.commit_pool_ordinal,
+        creator: Some(ctx.temp.temp_creator_wallet.clone()),
+        threshold_crossed: false,
     };
 
     let ownership_msgs =
@@ -323,6 +335,9 @@ pub fn mint_standard_nft(
         // controllable wallet instead of the factory contract (which
         // has no withdrawal mechanism).
         bluechip_wallet_address: factory_config.bluechip_wallet_address.clone(),
+        protocol_fee_share: factory_config
+            .protocol_fee
+            .share_for(&PoolKind::Standard),
     };
     // Dual-code_id routing: standard pools instantiate against the
     // separate standard-pool wasm, sending a flat
@@ -385,6 +400,8 @@ pub fn finalize_standard_pool(
         // schedule. Zero ordinal flags this in `calculate_and_mint_bluechip`
         // (which never runs for standard pools anyway, but defense-in-depth).
         commit_pool_ordinal: 0,
+        creator: Some(ctx.creator.clone()),
+        threshold_crossed: true,
     };
 
     // Standard pools have only the NFT to transfer (no CW20 minter to
@@ -2,8 +2,8 @@ use cosmwasm_schema::cw_serde;
 
 use crate::asset::TokenType;
 
-use cosmwasm_std::{Addr, Decimal, StdError, StdResult, Uint128};
-use pool_factory_interfaces::PoolKind;
+use cosmwasm_std::{Addr, Decimal, StdError, StdResult, Timestamp, Uint128};
+use pool_factory_interfaces::{CommitAllowlist, DistributionMode, EarlySupporterCurve, PoolKind};
 
 /// Caller-supplied portion of the commit-pool create message.
 ///
@@ -15,12 +15,34 @@ use pool_factory_interfaces::PoolKind;
 /// silently overwrote them with `factory_config.*` values, so a caller
 /// thinking they were tuning their pool was just being ignored.
 ///
-/// Reduced to the single load-bearing field so the wire format matches
+/// Reduced to the load-bearing fields so the wire format matches
 /// what the contract actually consumes; downstream tooling that used to
 /// supply the dropped fields no longer has to construct sentinel zeros.
 #[cw_serde]
 pub struct CreatePool {
     pub pool_token_info: [TokenType; 2],
+    /// Optional end of the commit phase. If the pool hasn't crossed its
+    /// threshold by then, commits stop and committers can reclaim their
+    /// net-of-fee bluechip with `ClaimCommitRefund`. Must fall within
+    /// `RAISE_DEADLINE_{MIN,MAX}_SECONDS` of the `Create` block.
+    /// `#[serde(default)]` keeps existing clients (and stored creation
+    /// contexts) deserializing with no deadline.
+    #[serde(default)]
+    pub raise_deadline: Option<Timestamp>,
+    /// Keeper-pushed (default) or committer-pulled distribution of the
+    /// commit-return tranche once the threshold crosses.
+    #[serde(default)]
+    pub distribution_mode: DistributionMode,
+    /// Optional time-weighting that favours early committers in the
+    /// commit-return split. Bounded by
+    /// `EARLY_SUPPORTER_MAX_MULTIPLIER` and
+    /// `EARLY_SUPPORTER_DECAY_{MIN,MAX}_SECONDS`.
+    #[serde(default)]
+    pub early_supporter_curve: Option<EarlySupporterCurve>,
+    /// Optional early-access phase: only allowlisted wallets may commit
+    /// until `public_at`. See `validate_commit_allowlist` for bounds.
+    #[serde(default)]
+    pub commit_allowlist: Option<CommitAllowlist>,
 }
 
 #[cw_serde]
@@ -43,6 +65,20 @@ pub struct PoolConfigUpdate {
     /// `min_commit_usd_pre_threshold` above.
     #[serde(default)]
     pub min_commit_usd_post_threshold: Option<Uint128>,
+    /// Share of a commit pool's `commit_fee_creator` paid to the
+    /// commit's referrer. Creator-pool only.
+    /// Bounds: `0 <= v <= POOL_CONFIG_MAX_REFERRAL_FEE_SHARE`.
+    #[serde(default)]
+    pub referral_fee_share: Option<Decimal>,
+    /// Per-wallet cap on pre-threshold committed USD (6 decimals).
+    /// Creator-pool only. `Some(0)` lifts the cap; the pool checks a
+    /// non-zero cap against its own pre-threshold floor at apply.
+    #[serde(default)]
+    pub max_commit_usd_per_wallet: Option<Uint128>,
+    /// Per-wallet cap as a share of the commit threshold. Creator-pool
+    /// only. Bounds: `0 <= v <= 1`; zero lifts the cap.
+    #[serde(default)]
+    pub max_commit_share_per_wallet: Option<Decimal>,
     // `oracle_address` removed. Mirrors the same field's
     // removal from `pool_core::msg::PoolConfigUpdate`. Per-pool oracle
     // rotation was an admin-compromise vector — a malicious oracle could
@@ -64,6 +100,11 @@ pub const POOL_CONFIG_MIN_COMMIT_INTERVAL_MAX_SECONDS: u64 = 86_400;
 /// 48h timelock.
 pub const POOL_CONFIG_MAX_MIN_COMMIT_USD: Uint128 = Uint128::new(1_000_000_000);
 
+/// Inclusive upper bound on `referral_fee_share`: at most half of the
+/// creator's commit fee may go to referrers. Mirrors the pool side's
+/// `MAX_REFERRAL_FEE_SHARE` in `creator-pool::state`.
+pub const POOL_CONFIG_MAX_REFERRAL_FEE_SHARE: Decimal = Decimal::percent(50);
+
 impl PoolConfigUpdate {
     /// Validate the update at propose time so a misconfigured value fails
     /// fast rather than after the 48h timelock. The pool side enforces the
@@ -74,6 +115,8 @@ impl PoolConfigUpdate {
     /// Bounds mirror `pool_core`:
     /// - `lp_fee`     : `MIN_LP_FEE` (0.1%) ..= `MAX_LP_FEE` (10%)
     /// - `min_commit_interval` : 0 ..= 86400 seconds
+    /// - `referral_fee_share` : 0 ..= 50%
+    /// - `max_commit_share_per_wallet` : 0 ..= 100%
     /// Constants are duplicated rather than imported from `pool-core` to keep
     /// the factory crate free of a `pool-core` dependency (pool-core already
     /// depends on the factory-interfaces crate).
@@ -115,6 +158,22 @@ impl PoolConfigUpdate {
                 }
             }
         }
+        if let Some(share) = self.referral_fee_share {
+            if share > POOL_CONFIG_MAX_REFERRAL_FEE_SHARE {
+                return Err(StdError::generic_err(format!(
+                    "referral_fee_share {} exceeds maximum {}; pool will reject at apply time",
+                    share, POOL_CONFIG_MAX_REFERRAL_FEE_SHARE
+                )));
+            }
+        }
+        if let Some(share) = self.max_commit_share_per_wallet {
+            if share > Decimal::one() {
+                return Err(StdError::generic_err(format!(
+                    "max_commit_share_per_wallet {} exceeds 1; pool will reject at apply time",
+                    share
+                )));
+            }
+        }
         Ok(())
     }
 }
@@ -238,6 +297,16 @@ pub struct PoolDetails {
     /// records (none exist).
     #[serde(default)]
     pub commit_pool_ordinal: u64,
+    /// Wallet that created the pool, as of creation; a later creator
+    /// wallet rotation on the pool is not reflected here. `None` for
+    /// pools registered before this field existed.
+    #[serde(default)]
+    pub creator: Option<Addr>,
+    /// Set once the factory has recorded the pool's threshold crossing
+    /// (`NotifyThresholdCrossed`). Standard pools are registered with it
+    /// set, as they have no commit phase.
+    #[serde(default)]
+    pub threshold_crossed: bool,
 }
 
 impl ThresholdPayoutAmounts {
@@ -3,19 +3,32 @@ use crate::internal_bluechip_price_oracle::{
     bluechip_to_usd, get_bluechip_usd_price, usd_to_bluechip,
 };
 use crate::msg::FactoryInstantiateResponse;
+use crate::pool_struct::PoolDetails;
 use crate::state::{
-    CreationStatus, DISTRIBUTION_BOUNTY_USD, FACTORYINSTANTIATEINFO, ORACLE_UPDATE_BOUNTY_USD,
-    POOLS_BY_ID, POOL_CREATION_CONTEXT,
+    canonical_pair_key, pool_by_address, pool_id_for_pair, pool_ids_by_creator, pool_kind_key,
+    CreationStatus, CreatorProfile, Role, ADMIN_TIMELOCK_SECONDS, CREATOR_PROFILES,
+    DISTRIBUTION_BOUNTY_USD, FACTORYINSTANTIATEINFO, ORACLE_UPDATE_BOUNTY_USD, PENDING_ROLE_CHANGES,
+    POOLS_BY_ID, POOL_CREATION_CONTEXT, ROLE_HOLDERS,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, Env, QueryRequest, StdResult, Timestamp, Uint128,
-    WasmQuery,
+    to_json_binary, Addr, Binary, Deps, Env, Order, QueryRequest, StdError, StdResult, Timestamp,
+    Uint128, WasmQuery,
 };
-use cw20::{Cw20QueryMsg, TokenInfoResponse};
-use pool_factory_interfaces::FactoryQueryMsg;
+use cw20::{Cw20QueryMsg, MarketingInfoResponse, TokenInfoResponse};
+use cw_storage_plus::Bound;
+use pool_factory_interfaces::{FactoryQueryMsg, IsPausedResponse, PoolKind, PoolQueryMsg};
+
+/// Page size for the pool registry queries when `limit` is unset.
+pub const POOLS_QUERY_DEFAULT_LIMIT: u32 = 10;
+/// Hard cap on `limit` for the pool registry queries. Each returned pool
+/// costs one `IsPaused` query against the pool.
+pub const POOLS_QUERY_MAX_LIMIT: u32 = 30;
+/// Page size for `CreatorsPaged` when `limit` is unset.
+pub const CREATORS_QUERY_DEFAULT_LIMIT: u32 = 10;
+pub const CREATORS_QUERY_MAX_LIMIT: u32 = 30;
 
 #[cw_serde]
 pub struct CreatorTokenInfoResponse {
@@ -24,6 +37,9 @@ pub struct CreatorTokenInfoResponse {
     pub decimals: u8,
     pub total_supply: Uint128,
     pub token_address: Addr,
+    /// Project URL, description, logo and marketing admin as set through
+    /// the pool's `UpdateTokenMarketing`.
+    pub marketing: MarketingInfoResponse,
 }
 
 /// Configured oracle-update keeper bounty (paid per successful
@@ -58,6 +74,63 @@ pub struct PoolCreationStatusResponse {
     pub status: CreationStatus,
 }
 
+/// A registered pool plus its live status.
+#[cw_serde]
+pub struct PoolResponse {
+    pub details: PoolDetails,
+    /// Whether the factory has recorded the pool's threshold crossing.
+    /// Always true for standard pools, which have no commit phase.
+    pub threshold_crossed: bool,
+    /// The pool's own `IsPaused` answer; `None` when the pool could not
+    /// be queried.
+    pub paused: Option<bool>,
+}
+
+#[cw_serde]
+pub struct PoolsResponse {
+    pub pools: Vec<PoolResponse>,
+    /// Pool id to pass as `start_after` for the next page; `None` once
+    /// the registry has been read to the end.
+    pub next_start_after: Option<u64>,
+}
+
+#[cw_serde]
+pub struct CreatorProfileResponse {
+    pub creator: Addr,
+    pub profile: CreatorProfile,
+    /// Pools registered with this creator, in pool-id order.
+    pub pool_ids: Vec<u64>,
+}
+
+#[cw_serde]
+pub struct CreatorsResponse {
+    pub creators: Vec<CreatorProfileResponse>,
+}
+
+#[cw_serde]
+pub struct RoleHolder {
+    pub role: Role,
+    pub holder: Addr,
+    pub granted_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct PendingRoleChangeResponse {
+    pub role: Role,
+    pub holder: Addr,
+    /// True for a grant, false for a revoke.
+    pub grant: bool,
+    pub effective_after: Timestamp,
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    /// Implicitly holds every role.
+    pub super_admin: Addr,
+    pub holders: Vec<RoleHolder>,
+    pub pending: Vec<PendingRoleChangeResponse>,
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -75,6 +148,48 @@ pub enum QueryMsg {
     /// when creation completed cleanly and the entry was reaped.
     #[returns(Option<PoolCreationStatusResponse>)]
     PoolCreationStatus { pool_id: u64 },
+    /// Registered pools in pool-id order. `start_after` is an exclusive
+    /// pool id. `limit` caps the registry entries read, not the matches:
+    /// the `paused` filter queries every pool it reads, so a page can
+    /// come back short. Keep paging on `next_start_after`.
+    #[returns(PoolsResponse)]
+    Pools {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        kind: Option<PoolKind>,
+        threshold_crossed: Option<bool>,
+        paused: Option<bool>,
+    },
+    /// The pool for an unordered asset pair, if one is registered.
+    #[returns(Option<PoolResponse>)]
+    PoolByPair { asset_a: TokenType, asset_b: TokenType },
+    #[returns(Option<PoolResponse>)]
+    PoolByAddress { addr: String },
+    /// Pools with `token` (a native denom or CW20 address) on either
+    /// side, in pool-id order.
+    #[returns(PoolsResponse)]
+    PoolsByToken {
+        token: String,
+        #[serde(default)]
+        start_after: Option<u64>,
+        #[serde(default)]
+        limit: Option<u32>,
+    },
+    /// The creator's profile and pools, or None before their first
+    /// `Create`.
+    #[returns(Option<CreatorProfileResponse>)]
+    CreatorProfile { creator: String },
+    /// Creator profiles in address order. `start_after` is an exclusive
+    /// creator address.
+    #[returns(CreatorsResponse)]
+    CreatorsPaged {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// The super-admin, every explicit role grant and every role change
+    /// still waiting on the timelock.
+    #[returns(RolesResponse)]
+    Roles {},
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -92,7 +207,277 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::PoolCreationStatus { pool_id } => {
             to_json_binary(&query_pool_creation_status(deps, pool_id)?)
         }
+        QueryMsg::Pools {
+            start_after,
+            limit,
+            kind,
+            threshold_crossed,
+            paused,
+        } => to_json_binary(&query_pools(
+            deps,
+            start_after,
+            limit,
+            kind,
+            threshold_crossed,
+            paused,
+        )?),
+        QueryMsg::PoolByPair { asset_a, asset_b } => {
+            to_json_binary(&query_pool_by_pair(deps, asset_a, asset_b)?)
+        }
+        QueryMsg::PoolByAddress { addr } => to_json_binary(&query_pool_by_address(deps, addr)?),
+        QueryMsg::PoolsByToken {
+            token,
+            start_after,
+            limit,
+        } => to_json_binary(&query_pools_by_token(deps, token, start_after, limit)?),
+        QueryMsg::CreatorProfile { creator } => {
+            to_json_binary(&query_creator_profile(deps, creator)?)
+        }
+        QueryMsg::CreatorsPaged { start_after, limit } => {
+            to_json_binary(&query_creators_paged(deps, start_after, limit)?)
+        }
+        QueryMsg::Roles {} => to_json_binary(&query_roles(deps)?),
+    }
+}
+
+fn pool_response(deps: Deps, details: PoolDetails) -> StdResult<PoolResponse> {
+    let threshold_crossed = details.threshold_crossed;
+    let paused = deps
+        .querier
+        .query_wasm_smart::<IsPausedResponse>(
+            details.creator_pool_addr.to_string(),
+            &PoolQueryMsg::IsPaused {},
+        )
+        .ok()
+        .map(|r| r.paused);
+    Ok(PoolResponse {
+        details,
+        threshold_crossed,
+        paused,
+    })
+}
+
+fn pools_query_limit(limit: Option<u32>) -> usize {
+    limit
+        .unwrap_or(POOLS_QUERY_DEFAULT_LIMIT)
+        .min(POOLS_QUERY_MAX_LIMIT) as usize
+}
+
+/// Pages `POOLS_BY_ID` from `start_after`, walking the `kind` or
+/// `threshold_kind` index when those filters are set. The `paused`
+/// filter queries each pool, so it runs last and at most `limit`
+/// entries are read per call.
+pub fn query_pools(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    kind: Option<PoolKind>,
+    threshold_crossed: Option<bool>,
+    paused: Option<bool>,
+) -> StdResult<PoolsResponse> {
+    let limit = pools_query_limit(limit);
+    let min = start_after.map(Bound::exclusive);
+    let entries: Box<dyn Iterator<Item = StdResult<(u64, PoolDetails)>>> =
+        match (&kind, threshold_crossed) {
+            (Some(kind), Some(crossed)) => POOLS_BY_ID
+                .idx
+                .threshold_kind
+                .prefix((u8::from(crossed), pool_kind_key(kind)))
+                .range(deps.storage, min, None, Order::Ascending),
+            (Some(kind), None) => POOLS_BY_ID.idx.kind.prefix(pool_kind_key(kind)).range(
+                deps.storage,
+                min,
+                None,
+                Order::Ascending,
+            ),
+            (None, Some(crossed)) => {
+                // One prefix per kind, merged back into pool id order.
+                let [commit, standard] = [PoolKind::Commit, PoolKind::Standard].map(|kind| {
+                    POOLS_BY_ID
+                        .idx
+                        .threshold_kind
+                        .prefix((u8::from(crossed), pool_kind_key(&kind)))
+                        .range(deps.storage, min.clone(), None, Order::Ascending)
+                        .peekable()
+                });
+                let (mut commit, mut standard) = (commit, standard);
+                Box::new(std::iter::from_fn(move || {
+                    match (commit.peek(), standard.peek()) {
+                        (Some(Ok((a, _))), Some(Ok((b, _)))) if b < a => standard.next(),
+                        (Some(_), _) => commit.next(),
+                        (None, _) => standard.next(),
+                    }
+                }))
+            }
+            (None, None) => POOLS_BY_ID.range(deps.storage, min, None, Order::Ascending),
+        };
+    let mut pools = Vec::with_capacity(limit);
+    let mut scanned = 0;
+    let mut last_scanned = None;
+    for entry in entries.take(limit) {
+        let details = entry?.1;
+        scanned += 1;
+        last_scanned = Some(details.pool_id);
+        let pool = pool_response(deps, details)?;
+        if paused.map_or(true, |p| pool.paused == Some(p)) {
+            pools.push(pool);
+        }
     }
+    Ok(PoolsResponse {
+        pools,
+        next_start_after: if scanned == limit { last_scanned } else { None },
+    })
+}
+
+/// Pages the pools holding `token` on either side, merging the `token0`
+/// and `token1` indexes in pool id order.
+pub fn query_pools_by_token(
+    deps: Deps,
+    token: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PoolsResponse> {
+    let limit = pools_query_limit(limit);
+    let mut pool_ids = Vec::with_capacity(limit * 2);
+    for index in [&POOLS_BY_ID.idx.token0, &POOLS_BY_ID.idx.token1] {
+        for pool_id in index
+            .prefix(token.clone())
+            .keys(
+                deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+        {
+            pool_ids.push(pool_id?);
+        }
+    }
+    pool_ids.sort_unstable();
+    pool_ids.dedup();
+    pool_ids.truncate(limit);
+    let next_start_after = if pool_ids.len() == limit {
+        pool_ids.last().copied()
+    } else {
+        None
+    };
+    let pools = pool_ids
+        .into_iter()
+        .map(|pool_id| pool_response(deps, POOLS_BY_ID.load(deps.storage, pool_id)?))
+        .collect::<StdResult<_>>()?;
+    Ok(PoolsResponse {
+        pools,
+        next_start_after,
+    })
+}
+
+pub fn query_pool_by_pair(
+    deps: Deps,
+    asset_a: TokenType,
+    asset_b: TokenType,
+) -> StdResult<Option<PoolResponse>> {
+    let Some(pool_id) = pool_id_for_pair(deps.storage, canonical_pair_key(&[asset_a, asset_b]))?
+    else {
+        return Ok(None);
+    };
+    POOLS_BY_ID
+        .may_load(deps.storage, pool_id)?
+        .map(|details| pool_response(deps, details))
+        .transpose()
+}
+
+pub fn query_pool_by_address(deps: Deps, addr: String) -> StdResult<Option<PoolResponse>> {
+    let addr = deps.api.addr_validate(&addr)?;
+    pool_by_address(deps.storage, &addr)?
+        .map(|details| pool_response(deps, details))
+        .transpose()
+}
+
+pub fn query_creator_profile(
+    deps: Deps,
+    creator: String,
+) -> StdResult<Option<CreatorProfileResponse>> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let Some(profile) = CREATOR_PROFILES.may_load(deps.storage, creator.clone())? else {
+        return Ok(None);
+    };
+    let pool_ids = pool_ids_by_creator(deps.storage, &creator)?;
+    Ok(Some(CreatorProfileResponse {
+        creator,
+        profile,
+        pool_ids,
+    }))
+}
+
+pub fn query_creators_paged(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<CreatorsResponse> {
+    let limit = limit
+        .unwrap_or(CREATORS_QUERY_DEFAULT_LIMIT)
+        .min(CREATORS_QUERY_MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let creators = CREATOR_PROFILES
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| {
+            let (creator, profile) = entry?;
+            let pool_ids = pool_ids_by_creator(deps.storage, &creator)?;
+            Ok(CreatorProfileResponse {
+                creator,
+                profile,
+                pool_ids,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(CreatorsResponse { creators })
+}
+
+fn role_from_key(key: &str) -> StdResult<Role> {
+    Role::from_key(key).ok_or_else(|| StdError::generic_err(format!("unknown role key: {}", key)))
+}
+
+/// Unpaginated: the role set is admin-curated and stays small.
+pub fn query_roles(deps: Deps) -> StdResult<RolesResponse> {
+    let super_admin = FACTORYINSTANTIATEINFO
+        .load(deps.storage)?
+        .factory_admin_address;
+    let holders = ROLE_HOLDERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|entry| {
+            let ((role, holder), granted_at) = entry?;
+            Ok(RoleHolder {
+                role: role_from_key(&role)?,
+                holder,
+                granted_at,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    let pending = PENDING_ROLE_CHANGES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|entry| {
+            let ((role, holder), change) = entry?;
+            Ok(PendingRoleChangeResponse {
+                role: role_from_key(&role)?,
+                holder,
+                grant: change.grant,
+                effective_after: change.proposed_at.plus_seconds(ADMIN_TIMELOCK_SECONDS),
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(RolesResponse {
+        super_admin,
+        holders,
+        pending,
+    })
 }
 
 pub fn query_pool_creation_status(
@@ -162,12 +547,19 @@ pub fn query_creator_token_info(deps: Deps, pool_id: u64) -> StdResult<CreatorTo
             msg: to_json_binary(&Cw20QueryMsg::TokenInfo {})?,
         }))?;
 
+    let marketing: MarketingInfoResponse =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: token_addr.to_string(),
+            msg: to_json_binary(&Cw20QueryMsg::MarketingInfo {})?,
+        }))?;
+
     Ok(CreatorTokenInfoResponse {
         name: token_info.name,
         symbol: token_info.symbol,
         decimals: token_info.decimals,
         total_supply: token_info.total_supply,
         token_address: token_addr,
+        marketing,
     })
 }
 
@@ -23,9 +23,12 @@
 use crate::asset::TokenType;
 use crate::pool_struct::{PoolDetails, TempPoolCreation, ThresholdPayoutAmounts};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Decimal, StdResult, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Item, Map};
-use pool_factory_interfaces::PoolStateResponseForFactory;
+use cosmwasm_std::{Addr, Binary, Decimal, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex};
+use pool_factory_interfaces::{
+    CreatorVestingConfig, LaunchProtectionConfig, PoolKind, PoolStateResponseForFactory,
+    ProtocolFeeConfig,
+};
 
 pub const FACTORYINSTANTIATEINFO: Item<FactoryInstantiate> = Item::new("config");
 // Single source of truth for every in-flight pool creation. Combines the
@@ -51,37 +54,145 @@ pub const POOL_COUNTER: Item<u64> = Item::new("pool_counter");
 /// anchored to actual commit-pool creation activity.
 pub const COMMIT_POOL_COUNTER: Item<u64> = Item::new("commit_pool_counter");
 
-// Three coupled pool-registry maps. They MUST stay in sync — every pool
-// that exists must appear in all three. Always go through `register_pool`
-// rather than touching them individually.
-// - POOLS_BY_ID:               pool_id  -> PoolDetails (token info, addresses)
-// - POOLS_BY_CONTRACT_ADDRESS: pool addr -> snapshot used by oracle / queries
-// - PAIRS:                     canonical (asset_a, asset_b) key -> pool_id.
+// The pool registry. `POOLS_BY_ID` is keyed by pool id and carries
+// every secondary index, so the indexes can't drift from the records.
+// Always go through `register_pool` to add a pool.
+// - `address`   (unique): pool contract address -> pool.
+// - `pair`:               canonical (asset_a, asset_b) key -> pools.
 // Single-pool-per-pair guard. The Uniswap-style invariant: at most one
 // pool exists per (asset_a, asset_b) tuple. Without it, any sender can
 // register an arbitrary number of identical pairs (each from a different
 // `info.sender` to bypass the per-address rate limit), bloating the
 // registry, fragmenting LP, and — most concretely — letting attackers
 // spawn thin "anchor candidate" duplicates that the oracle's snapshot
-// refresh may sample. See `canonical_pair_key` for the encoding.
-pub const POOLS_BY_ID: Map<u64, PoolDetails> = Map::new("pools_by_id");
+// refresh may sample. See `canonical_pair_key` for the encoding. A
+// multi-index rather than a unique one because chains that predate the
+// guard may hold legacy duplicates; `register_pool` enforces uniqueness
+// for every new pool and the lowest pool id is the pair's pool.
+// - `kind`:               `pool_kind_key` -> pools.
+// - `creator`:            creator wallet -> pools ("" when unknown).
+// - `token0` / `token1`:  `token_index_key` of each side -> pools.
+// - `threshold_kind`:     (1 once the threshold has crossed else 0,
+//                         `pool_kind_key`) -> pools. Kind-qualified so the
+//                         oracle can list crossed commit pools directly.
+//
+// POOLS_BY_CONTRACT_ADDRESS (pool addr -> creation-time state snapshot)
+// sits beside the registry and is also written by `register_pool`.
+pub struct PoolIndexes<'a> {
+    pub address: UniqueIndex<'a, Addr, PoolDetails, u64>,
+    pub pair: MultiIndex<'a, (String, String), PoolDetails, u64>,
+    pub kind: MultiIndex<'a, u8, PoolDetails, u64>,
+    pub creator: MultiIndex<'a, String, PoolDetails, u64>,
+    pub token0: MultiIndex<'a, String, PoolDetails, u64>,
+    pub token1: MultiIndex<'a, String, PoolDetails, u64>,
+    pub threshold_kind: MultiIndex<'a, (u8, u8), PoolDetails, u64>,
+}
+
+impl<'a> IndexList<PoolDetails> for PoolIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PoolDetails>> + '_> {
+        let v: Vec<&dyn Index<PoolDetails>> = vec![
+            &self.address,
+            &self.pair,
+            &self.kind,
+            &self.creator,
+            &self.token0,
+            &self.token1,
+            &self.threshold_kind,
+        ];
+        Box::new(v.into_iter())
+    }
+}
+
+pub const POOLS_BY_ID: IndexedMap<u64, PoolDetails, PoolIndexes> = IndexedMap::new(
+    "pools_by_id",
+    PoolIndexes {
+        address: UniqueIndex::new(|d| d.creator_pool_addr.clone(), "pools__address"),
+        pair: MultiIndex::new(
+            |_, d| canonical_pair_key(&d.pool_token_info),
+            "pools_by_id",
+            "pools__pair",
+        ),
+        kind: MultiIndex::new(
+            |_, d| pool_kind_key(&d.pool_kind),
+            "pools_by_id",
+            "pools__kind",
+        ),
+        creator: MultiIndex::new(
+            |_, d| d.creator.as_ref().map(Addr::to_string).unwrap_or_default(),
+            "pools_by_id",
+            "pools__creator",
+        ),
+        token0: MultiIndex::new(
+            |_, d| token_index_key(&d.pool_token_info[0]),
+            "pools_by_id",
+            "pools__token0",
+        ),
+        token1: MultiIndex::new(
+            |_, d| token_index_key(&d.pool_token_info[1]),
+            "pools_by_id",
+            "pools__token1",
+        ),
+        threshold_kind: MultiIndex::new(
+            |_, d| (u8::from(d.threshold_crossed), pool_kind_key(&d.pool_kind)),
+            "pools_by_id",
+            "pools__threshold_kind",
+        ),
+    },
+);
 pub const POOLS_BY_CONTRACT_ADDRESS: Map<Addr, PoolStateResponseForFactory> =
     Map::new("pools_by_contract_address");
-pub const PAIRS: Map<(String, String), u64> = Map::new("pairs");
-
-/// Reverse index: pool contract address -> `pool_id`. Maintained alongside
-/// `POOLS_BY_ID` by `register_pool` so any caller that has a pool address
-/// and needs the full `PoolDetails` can do two O(1) loads
-/// (`POOL_ID_BY_ADDRESS.load(addr) -> POOLS_BY_ID.load(id)`) instead of
-/// the previous O(N) linear scan of `POOLS_BY_ID` inside
-/// `lookup_pool_by_addr`. `POOLS_BY_CONTRACT_ADDRESS` exists but stores
-/// `PoolStateResponseForFactory` (a snapshot for oracle/queries), not the
-/// kind/ordinal-bearing `PoolDetails`, so it can't short-circuit the
-/// lookup on its own.
-///
-/// MUST stay in sync with `POOLS_BY_ID`. `register_pool` writes both
-/// atomically. Direct writes outside `register_pool` risk drift.
-pub const POOL_ID_BY_ADDRESS: Map<Addr, u64> = Map::new("pool_id_by_address");
+
+/// Factory-level identity for a commit-pool creator, keyed by the
+/// creator wallet. Opened with empty metadata on the creator's first
+/// `Create` and edited by that wallet through `UpdateCreatorProfile`. The
+/// creator's pools are read from the registry's `creator` index. When a
+/// pool rotates its creator wallet the pool notifies the factory, which
+/// re-lists the pool under the new wallet and copies this profile, minus
+/// the verified badge, to it (unless the new wallet already has one); the
+/// old wallet's profile is dropped once it has no pools left.
+#[cw_serde]
+pub struct CreatorProfile {
+    pub display_name: Option<String>,
+    pub avatar_uri: Option<String>,
+    pub links: Vec<String>,
+    /// Admin-granted badge, set and revoked through `SetCreatorVerified`.
+    pub verified: bool,
+    pub registered_at: Timestamp,
+}
+
+pub const CREATOR_PROFILES: Map<Addr, CreatorProfile> = Map::new("creator_profiles");
+pub const CREATOR_DISPLAY_NAME_MAX_LEN: usize = 64;
+/// Cap on `avatar_uri` and on each entry of `links`.
+pub const CREATOR_URI_MAX_LEN: usize = 256;
+pub const CREATOR_MAX_LINKS: usize = 5;
+
+/// Pools registered with `creator`, in pool-id order.
+pub fn pool_ids_by_creator(storage: &dyn Storage, creator: &Addr) -> StdResult<Vec<u64>> {
+    POOLS_BY_ID
+        .idx
+        .creator
+        .prefix(creator.to_string())
+        .keys(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Key of the `kind` index.
+pub fn pool_kind_key(kind: &PoolKind) -> u8 {
+    match kind {
+        PoolKind::Commit => 0,
+        PoolKind::Standard => 1,
+    }
+}
+
+/// Key of the `token0` / `token1` indexes: the native denom or the CW20
+/// address.
+pub fn token_index_key(token: &TokenType) -> String {
+    match token {
+        TokenType::Native { denom } => denom.clone(),
+        TokenType::CreatorToken { contract_addr } => contract_addr.to_string(),
+    }
+}
+
 // Maximum age (seconds) of a Pyth price we are willing to use for USD
 // conversions. 300 seconds (5 minutes) gives Pyth headroom across
 // publisher hiccups and short network outages without making the
@@ -184,7 +295,6 @@ pub const PENDING_POOL_UPGRADE: Item<PoolUpgrade> = Item::new("pending_upgrade")
 // is anchored to the first threshold event, not to when the factory was
 // deployed. Storage key is preserved for migration compatibility.
 pub const FIRST_THRESHOLD_TIMESTAMP: Item<Timestamp> = Item::new("first_pool_timestamp");
-pub const POOL_THRESHOLD_MINTED: Map<u64, bool> = Map::new("pool_threshold_minted");
 pub const PENDING_POOL_CONFIG: Map<u64, PendingPoolConfig> = Map::new("pending_pool_config");
 
 // Per-address rate limit on commit-pool creation: timestamp of each
@@ -492,10 +602,82 @@ pub struct FactoryInstantiate {
     /// deployments behave identically until the admin proposes an update.
     #[serde(default = "default_emergency_withdraw_delay_seconds")]
     pub emergency_withdraw_delay_seconds: u64,
+    /// Post-threshold launch window applied by commit pools: a per-swap
+    /// size cap and a per-wallet cumulative buy cap for
+    /// `window_blocks` blocks after the threshold crosses. Snapshotted
+    /// into each commit pool at create time (like
+    /// `threshold_payout_amounts`), so an update only affects pools
+    /// created after the timelock expires.
+    ///
+    /// Also carries the decaying anti-snipe `launch_fee`.
+    ///
+    /// Range-validated in `validate_factory_config` (bps ≤ 10_000,
+    /// window and fee decay ≤ `LAUNCH_WINDOW_MAX_BLOCKS`, fee ≤
+    /// `LAUNCH_FEE_MAX`). `#[serde(default)]` keeps
+    /// old serialized factory records deserializing with the window
+    /// disabled, so existing deployments behave identically until the
+    /// admin proposes an update.
+    #[serde(default)]
+    pub launch_protection: LaunchProtectionConfig,
+    /// Protocol share of swap LP fees, per pool kind. Seeded into each
+    /// pool at create time; existing pools pick up a change once
+    /// `SyncPoolProtocolFee` pushes it. Capped at
+    /// `PROTOCOL_FEE_SHARE_MAX` in `validate_factory_config`.
+    /// `#[serde(default)]` leaves old records with the switch off.
+    #[serde(default)]
+    pub protocol_fee: ProtocolFeeConfig,
+    /// Cliff and duration over which commit pools stream the creator's
+    /// threshold reward instead of minting it in one go. Snapshotted
+    /// into each commit pool at create time, like `launch_protection`.
+    /// Range-validated in `validate_factory_config` (cliff ≤ duration ≤
+    /// `CREATOR_VESTING_MAX_SECONDS`). `#[serde(default)]` leaves old
+    /// records with vesting disabled.
+    #[serde(default)]
+    pub creator_vesting: CreatorVestingConfig,
+    /// How long a creator pool's proposed creator wallet waits before it
+    /// can be accepted, during which the admin may veto it with
+    /// `VetoPoolCreatorWallet`. Snapshotted into each commit pool at
+    /// create time. Zero (the `#[serde(default)]`) means no veto window.
+    /// Capped at `CREATOR_WALLET_VETO_MAX_SECONDS`.
+    #[serde(default)]
+    pub creator_wallet_veto_seconds: u64,
 }
 
 pub const EMERGENCY_WITHDRAW_DELAY_MIN_SECONDS: u64 = 60;
 pub const EMERGENCY_WITHDRAW_DELAY_MAX_SECONDS: u64 = 86_400 * 7;
+/// Upper bound on `LaunchProtectionConfig.window_blocks` — roughly one
+/// week at ~6s blocks. Launch limits are meant to outlast the sniping
+/// rush, not to throttle a pool indefinitely.
+pub const LAUNCH_WINDOW_MAX_BLOCKS: u64 = 100_800;
+/// Upper bound on `LaunchProtectionConfig.launch_fee`. Above this the
+/// surcharge stops deterring snipers and starts confiscating buys.
+pub const LAUNCH_FEE_MAX: Decimal = Decimal::percent(50);
+/// Upper bound on either `ProtocolFeeConfig` share. Mirrors
+/// `pool_core::state::MAX_PROTOCOL_FEE_SHARE`; pools reject anything
+/// above it at instantiate and on `SetProtocolFeeShare`.
+pub const PROTOCOL_FEE_SHARE_MAX: Decimal = Decimal::percent(50);
+/// Upper bound on `CreatorVestingConfig.duration_seconds` (four years).
+pub const CREATOR_VESTING_MAX_SECONDS: u64 = 86_400 * 365 * 4;
+/// Upper bound on `creator_wallet_veto_seconds`. A creator locked out
+/// of their old key shouldn't wait more than a month for the new one.
+pub const CREATOR_WALLET_VETO_MAX_SECONDS: u64 = 86_400 * 30;
+/// Bounds on a commit pool's optional `CreatePool.raise_deadline`,
+/// measured from the `Create` block. The floor gives a raise at least a
+/// day to fund; the ceiling keeps committers from being locked into a
+/// raise for more than a year before refunds open.
+pub const RAISE_DEADLINE_MIN_SECONDS: u64 = 86_400;
+pub const RAISE_DEADLINE_MAX_SECONDS: u64 = 86_400 * 365;
+/// Bounds on `CreatePool.early_supporter_curve`. A day-one commit can
+/// earn at most 3x the per-dollar share of a late one, and the decay
+/// runs between a day and a year.
+pub const EARLY_SUPPORTER_MAX_MULTIPLIER: Decimal = Decimal::raw(3_000_000_000_000_000_000);
+pub const EARLY_SUPPORTER_DECAY_MIN_SECONDS: u64 = 86_400;
+pub const EARLY_SUPPORTER_DECAY_MAX_SECONDS: u64 = 86_400 * 365;
+/// Bounds on a commit pool's allowlist phase: it may run for at most
+/// 30 days and list at most 500 wallets on-chain (larger communities
+/// go in the Merkle root).
+pub const COMMIT_ALLOWLIST_MAX_PHASE_SECONDS: u64 = 86_400 * 30;
+pub const COMMIT_ALLOWLIST_MAX_WALLETS: usize = 500;
 
 pub fn default_emergency_withdraw_delay_seconds() -> u64 {
     86_400
@@ -706,6 +888,72 @@ pub const ORACLE_REFRESH_RATE_LIMIT_BLOCKS: u64 = 7_200;
 pub const ORACLE_REFRESH_RATE_LIMIT_BLOCKS: u64 = 1;
 pub const LAST_ORACLE_REFRESH_BLOCK: Item<u64> = Item::new("last_oracle_refresh_block");
 
+// ---------------------------------------------------------------------------
+// Role-based access control
+// ---------------------------------------------------------------------------
+//
+// `factory_admin_address` is the super-admin: it holds every role
+// implicitly and is the only address that can propose, apply or cancel
+// role changes. Grants and revokes both wait out `ADMIN_TIMELOCK_SECONDS`
+// in `PENDING_ROLE_CHANGES`, so a compromised super-admin key can't
+// quietly hand out a role before operators notice. Everything without a
+// narrower role (config, pool config, emergency drains, anchor, Pyth
+// gate, force-rotate, creator badges) stays super-admin only.
+
+/// A delegable slice of the super-admin's privileges.
+#[cw_serde]
+#[derive(Copy, Eq, PartialOrd, Ord)]
+pub enum Role {
+    /// `PausePool` / `UnpausePool`. Immediate, so it can sit on a hot
+    /// ops key.
+    Pauser,
+    /// Oracle allowlist and auto-eligible flag curation, plus the
+    /// bootstrap-price confirm / cancel.
+    OracleCurator,
+    /// `UpgradePools` and the rest of the pool upgrade flow.
+    Upgrader,
+    /// The oracle-update and distribution keeper bounties.
+    Treasurer,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [
+        Role::Pauser,
+        Role::OracleCurator,
+        Role::Upgrader,
+        Role::Treasurer,
+    ];
+
+    /// Inverse of [`Role::as_str`], for decoding storage keys.
+    pub fn from_key(key: &str) -> Option<Role> {
+        Role::ALL.into_iter().find(|role| role.as_str() == key)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Pauser => "pauser",
+            Role::OracleCurator => "oracle_curator",
+            Role::Upgrader => "upgrader",
+            Role::Treasurer => "treasurer",
+        }
+    }
+}
+
+/// Role holders keyed by `(Role::as_str(), holder)`; the value is the
+/// time the grant was applied.
+pub const ROLE_HOLDERS: Map<(&str, &Addr), Timestamp> = Map::new("role_holders");
+
+#[cw_serde]
+pub struct PendingRoleChange {
+    /// True for a grant, false for a revoke.
+    pub grant: bool,
+    pub proposed_at: Timestamp,
+}
+
+/// Role changes awaiting the timelock, keyed like `ROLE_HOLDERS`. One
+/// pending change per (role, holder).
+pub const PENDING_ROLE_CHANGES: Map<(&str, &Addr), PendingRoleChange> =
+    Map::new("pending_role_changes");
 
 #[cw_serde]
 pub enum CreationStatus {
@@ -747,9 +995,8 @@ pub struct PoolUpgrade {
 // ---------------------------------------------------------------------------
 // Pool registry helpers
 // ---------------------------------------------------------------------------
-// Centralized so the three pool-registry maps cannot drift. Direct writes to
-// POOLS_BY_ID / POOLS_BY_CONTRACT_ADDRESS / PAIRS outside this module risk
-// leaving the factory's view of pools internally inconsistent.
+// Centralized so `POOLS_BY_ID` and `POOLS_BY_CONTRACT_ADDRESS` cannot
+// drift, and so the pair guard runs for every new pool.
 
 /// Canonicalized fingerprint of a single side of a pool pair.
 ///
@@ -783,8 +1030,48 @@ pub fn canonical_pair_key(pair: &[TokenType; 2]) -> (String, String) {
     if a <= b { (a, b) } else { (b, a) }
 }
 
-/// Atomically register a freshly created pool across all three registry
-/// maps. Rejects with a generic_err if `pair` already exists in `PAIRS`
+/// The pool registered for `pair_key`: the lowest pool id when legacy
+/// duplicates exist.
+pub fn pool_id_for_pair(
+    storage: &dyn Storage,
+    pair_key: (String, String),
+) -> StdResult<Option<u64>> {
+    POOLS_BY_ID
+        .idx
+        .pair
+        .prefix(pair_key)
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .transpose()
+}
+
+/// The pool registered at `pool_address`, if any.
+pub fn pool_by_address(
+    storage: &dyn Storage,
+    pool_address: &Addr,
+) -> StdResult<Option<PoolDetails>> {
+    Ok(POOLS_BY_ID
+        .idx
+        .address
+        .item(storage, pool_address.clone())?
+        .map(|(_, details)| details))
+}
+
+/// Records `pool_id`'s threshold crossing. Returns false when it was
+/// already recorded.
+pub fn set_pool_threshold_crossed(storage: &mut dyn Storage, pool_id: u64) -> StdResult<bool> {
+    let mut details = POOLS_BY_ID.load(storage, pool_id)?;
+    if details.threshold_crossed {
+        return Ok(false);
+    }
+    details.threshold_crossed = true;
+    POOLS_BY_ID.save(storage, pool_id, &details)?;
+    Ok(true)
+}
+
+/// Atomically register a freshly created pool in `POOLS_BY_ID` and
+/// `POOLS_BY_CONTRACT_ADDRESS`. Rejects with a generic_err if `pair` is
+/// already registered
 /// — this is the canonical guard against silent duplicate registrations
 /// from any code path (entry-point pre-check, future admin restore,
 /// migrate back-fill, etc). The pre-check at the create entry points
@@ -801,19 +1088,20 @@ pub fn register_pool(
     pool_details: &PoolDetails,
 ) -> StdResult<()> {
     let pair_key = canonical_pair_key(&pool_details.pool_token_info);
-    if let Some(existing) = PAIRS.may_load(storage, pair_key.clone())? {
+    if let Some(existing) = pool_id_for_pair(storage, pair_key.clone())? {
         return Err(cosmwasm_std::StdError::generic_err(format!(
             "duplicate pair: pool_id {} already registered for ({}, {})",
             existing, pair_key.0, pair_key.1
         )));
     }
-    PAIRS.save(storage, pair_key, &pool_id)?;
 
-    POOLS_BY_ID.save(storage, pool_id, pool_details)?;
-    // Reverse index — see `POOL_ID_BY_ADDRESS` doc. Written here so the
-    // three-map invariant becomes a four-map invariant inside this
-    // single helper rather than every call site having to know about it.
-    POOL_ID_BY_ADDRESS.save(storage, pool_address.clone(), &pool_id)?;
+    // Standard pools have no commit phase, so they are registered as
+    // already past it.
+    let mut pool_details = pool_details.clone();
+    if pool_details.pool_kind == PoolKind::Standard {
+        pool_details.threshold_crossed = true;
+    }
+    POOLS_BY_ID.save(storage, pool_id, &pool_details)?;
 
     let asset_strings: Vec<String> = pool_details
         .pool_token_info
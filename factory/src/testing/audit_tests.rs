@@ -17,7 +17,7 @@ use crate::msg::{CreatorTokenInfo, ExecuteMsg};
 use crate::pool_struct::{CreatePool, PoolConfigUpdate, PoolDetails};
 use crate::state::{
     EligiblePoolSnapshot, FactoryInstantiate, ELIGIBLE_POOL_SNAPSHOT, PENDING_CONFIG,
-    POOLS_BY_CONTRACT_ADDRESS, POOLS_BY_ID, POOL_COUNTER, POOL_THRESHOLD_MINTED,
+    POOLS_BY_CONTRACT_ADDRESS, POOLS_BY_ID, POOL_COUNTER,
 };
 use crate::testing::tests::{
     create_instantiate_reply, creation_fee_funds, register_test_pool_addr, setup_atom_pool,
@@ -65,6 +65,7 @@ fn default_factory_config() -> FactoryInstantiate {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 14,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -72,6 +73,9 @@ fn default_factory_config() -> FactoryInstantiate {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     }
 }
 
@@ -118,9 +122,7 @@ fn test_notify_threshold_crossed_double_call_prevention() {
     register_test_pool_addr(&mut deps.storage, 1, &Addr::unchecked("pool_contract_1"));
 
     // Mark as already minted
-    POOL_THRESHOLD_MINTED
-        .save(&mut deps.storage, 1, &true)
-        .unwrap();
+    assert!(crate::state::set_pool_threshold_crossed(&mut deps.storage, 1).unwrap());
 
     let env = mock_env();
     let pool_info = message_info(&Addr::unchecked("pool_contract_1"), &[]);
@@ -341,7 +343,7 @@ fn test_update_pool_config_nonexistent_pool() {
 
 /// — propose-time bounds check + standard-pool rejection
 /// for the new `min_commit_usd_pre_threshold` /
-/// `min_commit_usd_post_threshold` knobs.
+/// `min_commit_usd_post_threshold` knobs and `referral_fee_share`.
 #[test]
 fn test_propose_pool_config_commit_floor_bounds_and_kind_gating() {
     let mut deps = mock_deps_with_querier(&[]);
@@ -364,6 +366,8 @@ fn test_propose_pool_config_commit_floor_bounds_and_kind_gating() {
         creator_pool_addr: Addr::unchecked("std_pool_2"),
         pool_kind: pool_factory_interfaces::PoolKind::Standard,
         commit_pool_ordinal: 0,
+        creator: None,
+        threshold_crossed: true,
     };
     POOLS_BY_ID
         .save(&mut deps.storage, 2u64, &std_details)
@@ -413,6 +417,50 @@ fn test_propose_pool_config_commit_floor_bounds_and_kind_gating() {
         err
     );
 
+    // Referral share above half the creator fee is rejected.
+    let greedy_referral = PoolConfigUpdate {
+        referral_fee_share: Some(
+            crate::pool_struct::POOL_CONFIG_MAX_REFERRAL_FEE_SHARE + Decimal::percent(1),
+        ),
+        ..Default::default()
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::ProposePoolConfigUpdate {
+            pool_id: 1,
+            pool_config: greedy_referral,
+        },
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("referral_fee_share"),
+        "expected referral ceiling rejection, got: {}",
+        err
+    );
+
+    // A per-wallet cap above the whole threshold is rejected.
+    let oversized_wallet_cap = PoolConfigUpdate {
+        max_commit_share_per_wallet: Some(Decimal::percent(101)),
+        ..Default::default()
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::ProposePoolConfigUpdate {
+            pool_id: 1,
+            pool_config: oversized_wallet_cap,
+        },
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("max_commit_share_per_wallet"),
+        "expected wallet-cap rejection, got: {}",
+        err
+    );
+
     // Standard pool target with commit-floor field set is rejected.
     let standard_floor = PoolConfigUpdate {
         min_commit_usd_pre_threshold: Some(Uint128::new(2_000_000)),
@@ -475,6 +523,8 @@ fn test_m_new_3_rotation_skips_pools_without_prior_snapshot() {
         creator_pool_addr: Addr::unchecked(&creator_addr),
         pool_kind: pool_factory_interfaces::PoolKind::Commit,
         commit_pool_ordinal: 0,
+        creator: None,
+        threshold_crossed: false,
     };
     POOLS_BY_ID
         .save(&mut deps.storage, 1, &pool_details)
@@ -732,7 +782,12 @@ fn test_m_new_5_multi_pool_creator_no_registry_collision() {
                 TokenType::CreatorToken {
                     contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"),
                 },
-            ] },
+            ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
+        },
         token_info: CreatorTokenInfo {
             name: "TokenA".to_string(),
             symbol: "TOKA".to_string(),
@@ -771,7 +826,12 @@ fn test_m_new_5_multi_pool_creator_no_registry_collision() {
                 TokenType::CreatorToken {
                     contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"),
                 },
-            ] },
+            ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
+        },
         token_info: CreatorTokenInfo {
             name: "TokenB".to_string(),
             symbol: "TOKB".to_string(),
@@ -957,6 +1017,33 @@ fn test_propose_config_update_rejects_zero_threshold() {
     );
 }
 
+// ---------------------------------------------------------------------------
+// Protocol fee shares above `PROTOCOL_FEE_SHARE_MAX` are rejected at
+// propose time, so a bad share never reaches `SyncPoolProtocolFee`.
+// ---------------------------------------------------------------------------
+#[test]
+fn test_propose_config_update_rejects_protocol_fee_share_above_max() {
+    let mut deps = mock_deps_with_querier(&[]);
+    setup_factory(&mut deps);
+
+    let mut bad = default_factory_config();
+    bad.protocol_fee.standard_pool_share = Decimal::percent(51);
+
+    let info = message_info(&admin_addr(), &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::ProposeConfigUpdate { config: bad },
+    );
+    let err = res.expect_err("protocol fee share above max must be rejected");
+    assert!(
+        err.to_string().contains("protocol_fee.standard_pool_share"),
+        "got: {}",
+        err
+    );
+}
+
 // ---------------------------------------------------------------------------
 // `pyth_contract_addr_for_conversions` must be a non-empty bech32-valid
 // address; an empty string used to slip through and only fail at query
@@ -1105,6 +1192,8 @@ fn test_pay_distribution_bounty_rejects_standard_pool() {
                 creator_pool_addr: std_pool_addr.clone(),
                 pool_kind: pool_factory_interfaces::PoolKind::Standard,
                 commit_pool_ordinal: 0,
+                creator: None,
+                threshold_crossed: true,
             },
         )
         .unwrap();
@@ -1153,6 +1242,10 @@ fn test_create_commit_pool_rejects_non_bluechip_funds() {
                     ),
                 },
             ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
         },
         token_info: CreatorTokenInfo {
             name: "TestToken".to_string(),
@@ -1679,6 +1772,10 @@ fn test_c2_pool_details_persists_real_creator_token_address() {
                     contract_addr: Addr::unchecked(CREATOR_TOKEN_SENTINEL),
                 },
             ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
         },
         token_info: CreatorTokenInfo {
             name: "TestToken".to_string(),
@@ -1811,6 +1908,10 @@ fn test_l7_create_rejects_all_numeric_symbol() {
                         ),
                     },
                 ],
+                raise_deadline: None,
+                distribution_mode: Default::default(),
+                early_supporter_curve: None,
+                commit_allowlist: None,
             },
             token_info: CreatorTokenInfo {
                 name: "All-digit symbol token".to_string(),
@@ -1848,6 +1949,10 @@ fn test_i6_commit_pool_create_rate_limit_per_address() {
                     contract_addr: Addr::unchecked(CREATOR_TOKEN_SENTINEL),
                 },
             ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
         },
         token_info: CreatorTokenInfo {
             name: format!("Token {}", sym),
@@ -2144,6 +2249,8 @@ mod anchor_bluechip_index_cache_tests {
             creator_pool_addr: pool_addr.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Standard,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, pool_id, &pool_details)
@@ -2539,6 +2646,10 @@ mod pool_admin_forwarder_tests {
         RecoverStuckStates {
             recovery_type: crate::pool_struct::RecoveryType,
         },
+        SetSubscriptionTiers {
+            creator: String,
+            tiers: Vec<pool_factory_interfaces::SubscriptionTier>,
+        },
     }
 
     fn setup_factory_with_pool(pool_id: u64) -> (
@@ -2728,6 +2839,37 @@ mod pool_admin_forwarder_tests {
         assert!(matches!(err, ContractError::Unauthorized {}));
     }
 
+    /// Anyone may call; the caller is stamped in as `creator` and the
+    /// pool decides whether that's its creator wallet.
+    #[test]
+    fn set_subscription_tiers_forwards_caller_as_creator() {
+        let (mut deps, pool_addr) = setup_factory_with_pool(5);
+        let creator = Addr::unchecked("creator_wallet");
+        let tiers = vec![pool_factory_interfaces::SubscriptionTier {
+            name: "gold".to_string(),
+            usd_price: Uint128::new(5_000_000),
+            duration_seconds: 86_400 * 30,
+        }];
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&creator, &[]),
+            ExecuteMsg::SetPoolSubscriptionTiers {
+                pool_id: 5,
+                tiers: tiers.clone(),
+            },
+        )
+        .unwrap();
+        assert_forwards_to_pool(
+            res,
+            &pool_addr,
+            PoolAdminMsgMirror::SetSubscriptionTiers {
+                creator: creator.to_string(),
+                tiers,
+            },
+        );
+    }
+
     /// `RecoverPoolStuckStates` against a Standard pool is rejected at
     /// the factory dispatch with a typed error rather than being forwarded
     /// to the standard pool's `ExecuteMsg` (which has no `RecoverStuckStates`
@@ -2755,6 +2897,8 @@ mod pool_admin_forwarder_tests {
             creator_pool_addr: pool_addr,
             pool_kind: pool_factory_interfaces::PoolKind::Standard,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, 50, &pool_details)
@@ -2828,6 +2972,8 @@ mod anchor_validation_failure_tests {
             creator_pool_addr: pool_addr,
             pool_kind: pool_factory_interfaces::PoolKind::Commit, // wrong kind
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: false,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, 50, &pool_details)
@@ -2867,6 +3013,8 @@ mod anchor_validation_failure_tests {
             creator_pool_addr: pool_addr,
             pool_kind: pool_factory_interfaces::PoolKind::Standard,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, 51, &pool_details)
@@ -2909,6 +3057,8 @@ mod anchor_validation_failure_tests {
             creator_pool_addr: pool_addr,
             pool_kind: pool_factory_interfaces::PoolKind::Standard,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, 52, &pool_details)
@@ -2952,6 +3102,8 @@ mod anchor_validation_failure_tests {
             creator_pool_addr: pool_addr.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Standard,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, 53, &pool_details)
@@ -3008,6 +3160,8 @@ mod anchor_validation_failure_tests {
             creator_pool_addr: pool_addr.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Standard,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, 54, &pool_details)
@@ -3092,6 +3246,8 @@ mod anchor_validation_failure_tests {
                 creator_pool_addr: addr.clone(),
                 pool_kind: pool_factory_interfaces::PoolKind::Standard,
                 commit_pool_ordinal: 0,
+                creator: None,
+                threshold_crossed: true,
             };
             POOLS_BY_ID
                 .save(&mut deps.storage, pid, &pool_details)
@@ -3177,16 +3333,12 @@ mod oracle_eligibility_tests {
             creator_pool_addr: pool_addr.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Commit,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID.save(deps.as_mut().storage, 1, &pool_details).unwrap();
-        // Faithful fixture (audits L-2 + M-5): keep reverse-index and
-        // POOL_COUNTER in sync with POOLS_BY_ID. Without these, the
-        // random-sampling auto-eligible loop never picks this pool
-        // (POOL_COUNTER == 0), and `lookup_pool_by_addr` falls back
-        // to the linear scan instead of the O(1) path.
-        crate::state::POOL_ID_BY_ADDRESS
-            .save(deps.as_mut().storage, pool_addr.clone(), &1u64)
-            .unwrap();
+        // Faithful fixture (audit M-5): keep POOL_COUNTER in sync with
+        // POOLS_BY_ID.
         let current_counter = crate::state::POOL_COUNTER
             .may_load(deps.as_ref().storage)
             .unwrap()
@@ -3213,9 +3365,6 @@ mod oracle_eligibility_tests {
                 },
             )
             .unwrap();
-        POOL_THRESHOLD_MINTED
-            .save(deps.as_mut().storage, 1, &true)
-            .unwrap();
         pool_addr
     }
 
@@ -3256,6 +3405,8 @@ mod oracle_eligibility_tests {
             creator_pool_addr: addr.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Standard,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(deps.as_mut().storage, pool_id, &pool_details)
@@ -3458,15 +3609,14 @@ mod oracle_eligibility_tests {
     /// source already applies in `get_eligible_creator_pools`.
     #[test]
     fn allowlist_rejects_pre_threshold_commit_pool() {
-        use crate::state::{POOLS_BY_ID, POOL_THRESHOLD_MINTED};
+        use crate::state::{set_pool_threshold_crossed, POOLS_BY_ID};
         let mut deps = mock_deps_with_querier(&[]);
         setup_factory_with_commit_pool(&mut deps, false);
 
         // Register a SECOND commit pool that has NOT crossed its
         // threshold. setup_factory_with_commit_pool already created
-        // pool_id 1 with `POOL_THRESHOLD_MINTED = true`; this new
-        // pool deliberately omits that save so it lands as
-        // pre-threshold.
+        // pool_id 1 with `threshold_crossed = true`; this new pool
+        // deliberately leaves it false so it lands as pre-threshold.
         let pre_threshold_pool = make_addr("pre_threshold_commit_pool");
         let pool_details = PoolDetails {
             pool_id: 2,
@@ -3481,18 +3631,15 @@ mod oracle_eligibility_tests {
             creator_pool_addr: pre_threshold_pool.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Commit,
             commit_pool_ordinal: 2,
+            creator: None,
+            threshold_crossed: false,
         };
         POOLS_BY_ID
             .save(deps.as_mut().storage, 2, &pool_details)
             .unwrap();
-        crate::state::POOL_ID_BY_ADDRESS
-            .save(deps.as_mut().storage, pre_threshold_pool.clone(), &2u64)
-            .unwrap();
         crate::state::POOL_COUNTER
             .save(deps.as_mut().storage, &2u64)
             .unwrap();
-        // Crucially: do NOT save POOL_THRESHOLD_MINTED for pool_id 2.
-        // That's what makes this a pre-threshold pool.
 
         let err = execute(
             deps.as_mut(),
@@ -3553,9 +3700,7 @@ mod oracle_eligibility_tests {
         // pre-threshold state, not to commit pools in general.
         crate::state::PENDING_ORACLE_ELIGIBLE_POOL_ADD
             .remove(deps.as_mut().storage, pre_threshold_pool.clone());
-        POOL_THRESHOLD_MINTED
-            .save(deps.as_mut().storage, 2, &true)
-            .unwrap();
+        assert!(set_pool_threshold_crossed(deps.as_mut().storage, 2).unwrap());
         execute(
             deps.as_mut(),
             mock_env(),
@@ -3652,6 +3797,51 @@ mod oracle_eligibility_tests {
         assert_eq!(eligible[0], commit_pool.to_string());
     }
 
+    /// The auto-eligible sampler draws only from threshold-crossed commit
+    /// pools: a long run of pre-threshold pools and a crossed standard
+    /// pool neither get picked nor crowd out the one eligible pool.
+    #[test]
+    fn auto_eligible_sampler_draws_only_crossed_commit_pools() {
+        let mut deps = mock_deps_with_querier(&[]);
+        let commit_pool = setup_factory_with_commit_pool(&mut deps, true);
+        for pool_id in 2..=40u64 {
+            POOLS_BY_ID
+                .save(
+                    deps.as_mut().storage,
+                    pool_id,
+                    &PoolDetails {
+                        pool_id,
+                        pool_token_info: [
+                            TokenType::Native {
+                                denom: "ubluechip".to_string(),
+                            },
+                            TokenType::CreatorToken {
+                                contract_addr: make_addr(&format!("token_{}", pool_id)),
+                            },
+                        ],
+                        creator_pool_addr: make_addr(&format!("pre_threshold_{}", pool_id)),
+                        pool_kind: pool_factory_interfaces::PoolKind::Commit,
+                        commit_pool_ordinal: pool_id,
+                        creator: None,
+                        threshold_crossed: false,
+                    },
+                )
+                .unwrap();
+        }
+        register_standard_pool(&mut deps, 41, &make_addr("std_pool_usdc"));
+        crate::state::POOL_COUNTER
+            .save(deps.as_mut().storage, &41u64)
+            .unwrap();
+
+        let (eligible, _) = get_eligible_creator_pools(
+            deps.as_ref(),
+            &mock_env(),
+            atom_bluechip_pool_addr().as_str(),
+        )
+        .unwrap();
+        assert_eq!(eligible, vec![commit_pool.to_string()]);
+    }
+
     /// Flag flip goes through the same 48h timelock; apply before the
     /// timelock fails; cancel discards the pending change.
     #[test]
@@ -4534,23 +4724,17 @@ mod cross_pool_integration_tests {
                     creator_pool_addr: addr.clone(),
                     pool_kind: kind.clone(),
                     commit_pool_ordinal: pool_id,
+                    creator: None,
+                    // Commit pools land threshold-crossed so the
+                    // auto-eligible source counts them.
+                    threshold_crossed: true,
                 },
             )
             .unwrap();
-        // Faithful fixture: mirror what the real `state::register_pool`
-        // writes. Several production code paths depend on these maps
-        // staying in sync — without them the test helper diverges from
-        // the live invariant.
-        //
-        // - `POOL_ID_BY_ADDRESS`: reverse address->id index
-        // consulted by `lookup_pool_by_addr`.
-        // - `POOL_COUNTER` upper bound: the auto-eligible
-        // random-sampling loop in `get_eligible_creator_pools` ranges
-        // `[1, POOL_COUNTER]`; without the bump, the loop never picks
-        // these test fixtures.
-        crate::state::POOL_ID_BY_ADDRESS
-            .save(deps.as_mut().storage, addr.clone(), &pool_id)
-            .unwrap();
+        // Faithful fixture: the `POOL_COUNTER` upper bound. The
+        // auto-eligible random-sampling loop in
+        // `get_eligible_creator_pools` ranges `[1, POOL_COUNTER]`;
+        // without the bump, the loop never picks these test fixtures.
         let current_counter = crate::state::POOL_COUNTER
             .may_load(deps.as_ref().storage)
             .unwrap()
@@ -4579,13 +4763,6 @@ mod cross_pool_integration_tests {
         // would return into the mock querier so the integration layer
         // exercises the same code path as production.
         deps.querier.set_pool_state(addr.as_str(), state);
-        // Mark commit pools as threshold-crossed so the auto-eligible
-        // source counts them.
-        if matches!(kind, pool_factory_interfaces::PoolKind::Commit) {
-            POOL_THRESHOLD_MINTED
-                .save(deps.as_mut().storage, pool_id, &true)
-                .unwrap();
-        }
     }
 
     /// Add a pool to the admin allowlist. Bypasses the timelock flow —
@@ -5067,17 +5244,29 @@ mod cross_pool_integration_tests {
 // "spawn N duplicates from N different addresses to bypass the
 // per-address rate limit" attack fails fast on the second attempt
 // onward.
-// - `migrate`: back-fills `PAIRS` from existing `POOLS_BY_ID` entries
+// - `migrate`: re-indexes existing `POOLS_BY_ID` entries
 // so chains migrating from a pre-uniqueness build land with the
 // invariant intact (FIRST pool seen wins; legacy duplicates remain
 // queryable but no further duplicates can be created).
 mod pair_uniqueness_tests {
     use super::*;
     use crate::state::{
-        canonical_pair_key, register_pool, FACTORYINSTANTIATEINFO, PAIRS,
+        canonical_pair_key, pool_id_for_pair, register_pool, FACTORYINSTANTIATEINFO,
     };
     use pool_factory_interfaces::PoolKind;
 
+    /// Writes `details` the way a pre-`IndexedMap` factory did: the
+    /// record under `pools_by_id` with no index entries.
+    fn save_legacy_pool(
+        storage: &mut dyn cosmwasm_std::Storage,
+        pool_id: u64,
+        details: &PoolDetails,
+    ) {
+        cw_storage_plus::Map::<u64, PoolDetails>::new("pools_by_id")
+            .save(storage, pool_id, details)
+            .unwrap();
+    }
+
     fn pool_details_for(pair: [TokenType; 2], pool_id: u64, kind: PoolKind) -> PoolDetails {
         PoolDetails {
             pool_id,
@@ -5085,6 +5274,8 @@ mod pair_uniqueness_tests {
             creator_pool_addr: make_addr(&format!("pool_{}", pool_id)),
             pool_kind: kind,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: false,
         }
     }
 
@@ -5132,7 +5323,7 @@ mod pair_uniqueness_tests {
         assert_ne!(canonical_pair_key(&native_pair), canonical_pair_key(&cw20_pair));
     }
 
-    /// register_pool: first call records the pair in PAIRS; second call
+    /// register_pool: first call indexes the pair; second call
     /// with the same pair (different pool_id, different address) errors
     /// with the canonical "duplicate pair" message.
     #[test]
@@ -5158,10 +5349,8 @@ mod pair_uniqueness_tests {
         )
         .expect("first registration must succeed");
 
-        // PAIRS now contains the canonical key pointing at pool 1.
-        let stored = PAIRS
-            .may_load(&deps.storage, canonical_pair_key(&pair))
-            .unwrap();
+        // The pair index now maps the canonical key to pool 1.
+        let stored = pool_id_for_pair(&deps.storage, canonical_pair_key(&pair)).unwrap();
         assert_eq!(stored, Some(1));
 
         // Second registration with the same pair must fail. We use a
@@ -5199,7 +5388,7 @@ mod pair_uniqueness_tests {
         );
     }
 
-    /// CreateStandardPool entry-time pre-check: if PAIRS already has an
+    /// CreateStandardPool entry-time pre-check: if the pair index has an
     /// entry for the canonical key, the handler must return
     /// `ContractError::DuplicatePair` BEFORE charging the creation fee or
     /// stamping the per-address rate-limit timestamp.
@@ -5231,17 +5420,18 @@ mod pair_uniqueness_tests {
         ];
 
         // Simulate that a previous tx already registered this pair as
-        // pool_id 1. (We seed PAIRS directly because the mock querier
-        // does not run the standard-pool reply chain to completion;
-        // production would have landed this entry through `register_pool`
-        // inside `finalize_standard_pool`.)
-        PAIRS
-            .save(
-                deps.as_mut().storage,
-                canonical_pair_key(&pair),
-                &1u64,
-            )
-            .unwrap();
+        // pool_id 1. (We seed the registry directly because the mock
+        // querier does not run the standard-pool reply chain to
+        // completion; production would have landed this entry through
+        // `register_pool` inside `finalize_standard_pool`.)
+        let existing = pool_details_for(pair.clone(), 1, PoolKind::Standard);
+        register_pool(
+            deps.as_mut().storage,
+            1,
+            &existing.creator_pool_addr.clone(),
+            &existing,
+        )
+        .unwrap();
 
         // A different sender attempts the same pair. Must fail with
         // DuplicatePair, NOT with rate-limit (the sender has no prior
@@ -5310,13 +5500,14 @@ mod pair_uniqueness_tests {
                 denom: "uatom".to_string(),
             },
         ];
-        PAIRS
-            .save(
-                deps.as_mut().storage,
-                canonical_pair_key(&pair),
-                &7u64,
-            )
-            .unwrap();
+        let existing = pool_details_for(pair.clone(), 7, PoolKind::Standard);
+        register_pool(
+            deps.as_mut().storage,
+            7,
+            &existing.creator_pool_addr.clone(),
+            &existing,
+        )
+        .unwrap();
 
         let caller = make_addr("dup_then_cooldown_caller");
         // Seed a recent rate-limit stamp so BOTH gates would fire.
@@ -5347,7 +5538,7 @@ mod pair_uniqueness_tests {
 
     /// Migrate back-fill: after instantiating the factory and seeding
     /// `POOLS_BY_ID` directly with two distinct pools (different pairs),
-    /// migrate must populate `PAIRS` with one entry per pair.
+    /// migrate must index every legacy record, one pair entry each.
     #[test]
     fn migrate_backfills_pairs_from_existing_pools() {
         let mut deps = mock_deps_with_querier(&[]);
@@ -5370,24 +5561,27 @@ mod pair_uniqueness_tests {
             },
         ];
 
-        POOLS_BY_ID
-            .save(
-                deps.as_mut().storage,
-                10,
-                &pool_details_for(pair1.clone(), 10, PoolKind::Standard),
-            )
-            .unwrap();
-        POOLS_BY_ID
-            .save(
-                deps.as_mut().storage,
-                11,
-                &pool_details_for(pair2.clone(), 11, PoolKind::Commit),
-            )
+        save_legacy_pool(
+            deps.as_mut().storage,
+            10,
+            &pool_details_for(pair1.clone(), 10, PoolKind::Standard),
+        );
+        save_legacy_pool(
+            deps.as_mut().storage,
+            11,
+            &pool_details_for(pair2.clone(), 11, PoolKind::Commit),
+        );
+
+        // Pool 11 crossed its threshold under the old side map.
+        cw_storage_plus::Map::<u64, bool>::new("pool_threshold_minted")
+            .save(deps.as_mut().storage, 11, &true)
             .unwrap();
 
-        // PAIRS empty pre-migrate.
-        assert!(PAIRS
-            .may_load(&deps.storage, canonical_pair_key(&pair1))
+        // Indexes empty pre-migrate.
+        assert!(pool_id_for_pair(&deps.storage, canonical_pair_key(&pair1))
+            .unwrap()
+            .is_none());
+        assert!(crate::state::pool_by_address(&deps.storage, &make_addr("pool_11"))
             .unwrap()
             .is_none());
 
@@ -5401,17 +5595,25 @@ mod pair_uniqueness_tests {
             crate::migrate::migrate(deps.as_mut(), mock_env(), Empty {}).expect("migrate ok");
 
         assert_eq!(
-            PAIRS
-                .may_load(&deps.storage, canonical_pair_key(&pair1))
-                .unwrap(),
+            pool_id_for_pair(&deps.storage, canonical_pair_key(&pair1)).unwrap(),
             Some(10),
         );
         assert_eq!(
-            PAIRS
-                .may_load(&deps.storage, canonical_pair_key(&pair2))
-                .unwrap(),
+            pool_id_for_pair(&deps.storage, canonical_pair_key(&pair2)).unwrap(),
             Some(11),
         );
+        let pool11 = crate::state::pool_by_address(&deps.storage, &make_addr("pool_11"))
+            .unwrap()
+            .expect("address index back-filled");
+        assert_eq!(pool11.pool_id, 11);
+        // The legacy threshold flag moved onto the record and its map
+        // was cleared; standard pools count as crossed.
+        assert!(pool11.threshold_crossed);
+        assert!(POOLS_BY_ID.load(&deps.storage, 10).unwrap().threshold_crossed);
+        assert!(cw_storage_plus::Map::<u64, bool>::new("pool_threshold_minted")
+            .may_load(&deps.storage, 11)
+            .unwrap()
+            .is_none());
         // Observability: backfilled count surfaced as an attribute.
         let backfilled = res
             .attributes
@@ -5427,6 +5629,83 @@ mod pair_uniqueness_tests {
         assert_eq!(legacy, Some("0"));
     }
 
+    /// Legacy commit pools have no `creator` on their record. Migrate
+    /// reads it from the pool's fee info and opens the creator's profile;
+    /// a pool that cannot answer keeps `creator: None`, and standard
+    /// pools are never queried.
+    #[test]
+    fn migrate_backfills_commit_pool_creators_from_fee_info() {
+        let mut deps = mock_deps_with_querier(&[]);
+        setup_factory(&mut deps);
+
+        let bluechip = TokenType::Native {
+            denom: "ubluechip".to_string(),
+        };
+        let pair_for = |token: &str| {
+            [
+                bluechip.clone(),
+                TokenType::CreatorToken {
+                    contract_addr: make_addr(token),
+                },
+            ]
+        };
+        save_legacy_pool(
+            deps.as_mut().storage,
+            1,
+            &pool_details_for(pair_for("token_1"), 1, PoolKind::Commit),
+        );
+        save_legacy_pool(
+            deps.as_mut().storage,
+            2,
+            &pool_details_for(pair_for("token_2"), 2, PoolKind::Commit),
+        );
+        save_legacy_pool(
+            deps.as_mut().storage,
+            3,
+            &pool_details_for(pair_for("token_3"), 3, PoolKind::Standard),
+        );
+
+        let creator = make_addr("legacy_creator");
+        deps.querier
+            .pool_creator_wallets
+            .insert(make_addr("pool_1").to_string(), creator.clone());
+        // Pool 3 would answer too, but standard pools are skipped.
+        deps.querier
+            .pool_creator_wallets
+            .insert(make_addr("pool_3").to_string(), make_addr("bluechip_wallet"));
+
+        cw2::set_contract_version(
+            &mut deps.storage,
+            "crates.io:bluechip-factory",
+            "0.1.0",
+        )
+        .unwrap();
+        let res =
+            crate::migrate::migrate(deps.as_mut(), mock_env(), Empty {}).expect("migrate ok");
+
+        assert_eq!(
+            POOLS_BY_ID.load(&deps.storage, 1).unwrap().creator,
+            Some(creator.clone())
+        );
+        assert_eq!(POOLS_BY_ID.load(&deps.storage, 2).unwrap().creator, None);
+        assert_eq!(POOLS_BY_ID.load(&deps.storage, 3).unwrap().creator, None);
+        assert_eq!(
+            crate::state::pool_ids_by_creator(&deps.storage, &creator).unwrap(),
+            vec![1]
+        );
+        let profile = crate::state::CREATOR_PROFILES
+            .load(&deps.storage, creator)
+            .expect("profile opened for the backfilled creator");
+        assert!(!profile.verified);
+        assert_eq!(profile.registered_at, mock_env().block.time);
+        let backfilled = res
+            .attributes
+            .iter()
+            .find(|a| a.key == "creators_backfilled")
+            .map(|a| a.value.as_str());
+        assert_eq!(backfilled, Some("1"));
+    }
+
     /// Migrate must preserve legacy duplicates (FIRST pool_id wins) and
     /// surface the skip count as an observability attribute. Lower
     /// pool_id wins because `POOLS_BY_ID.range(..)` iterates ascending.
@@ -5446,20 +5725,16 @@ mod pair_uniqueness_tests {
 
         // Two legacy duplicate pools at the same pair (this is exactly
         // the pre-fix sybil-attack outcome we're back-filling around).
-        POOLS_BY_ID
-            .save(
-                deps.as_mut().storage,
-                5,
-                &pool_details_for(pair.clone(), 5, PoolKind::Standard),
-            )
-            .unwrap();
-        POOLS_BY_ID
-            .save(
-                deps.as_mut().storage,
-                9,
-                &pool_details_for(pair.clone(), 9, PoolKind::Standard),
-            )
-            .unwrap();
+        save_legacy_pool(
+            deps.as_mut().storage,
+            5,
+            &pool_details_for(pair.clone(), 5, PoolKind::Standard),
+        );
+        save_legacy_pool(
+            deps.as_mut().storage,
+            9,
+            &pool_details_for(pair.clone(), 9, PoolKind::Standard),
+        );
 
         cw2::set_contract_version(
             &mut deps.storage,
@@ -5472,9 +5747,7 @@ mod pair_uniqueness_tests {
 
         // First-seen (lowest pool_id) wins.
         assert_eq!(
-            PAIRS
-                .may_load(&deps.storage, canonical_pair_key(&pair))
-                .unwrap(),
+            pool_id_for_pair(&deps.storage, canonical_pair_key(&pair)).unwrap(),
             Some(5),
         );
         let legacy = res
@@ -5528,13 +5801,11 @@ mod pair_uniqueness_tests {
                 denom: "uatom".to_string(),
             },
         ];
-        POOLS_BY_ID
-            .save(
-                deps.as_mut().storage,
-                42,
-                &pool_details_for(pair.clone(), 42, PoolKind::Standard),
-            )
-            .unwrap();
+        save_legacy_pool(
+            deps.as_mut().storage,
+            42,
+            &pool_details_for(pair.clone(), 42, PoolKind::Standard),
+        );
 
         cw2::set_contract_version(
             &mut deps.storage,
@@ -5556,9 +5827,7 @@ mod pair_uniqueness_tests {
             crate::migrate::migrate(deps.as_mut(), mock_env(), Empty {}).expect("re-migrate ok");
 
         assert_eq!(
-            PAIRS
-                .may_load(&deps.storage, canonical_pair_key(&pair))
-                .unwrap(),
+            pool_id_for_pair(&deps.storage, canonical_pair_key(&pair)).unwrap(),
             Some(42),
             "pool_id must NOT change on re-run",
         );
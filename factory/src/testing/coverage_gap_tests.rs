@@ -58,6 +58,7 @@ fn default_factory_config() -> FactoryInstantiate {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 14,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: make_addr("atom_bluechip_pool"),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -65,6 +66,9 @@ fn default_factory_config() -> FactoryInstantiate {
         standard_pool_creation_fee_usd: Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     }
 }
 
@@ -115,6 +119,10 @@ fn create_pool_refunds_surplus_to_sender() {
                     contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"),
                 },
             ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
         },
         token_info: CreatorTokenInfo {
             name: "RefundToken".to_string(),
@@ -171,6 +179,10 @@ fn create_pool_exact_pay_emits_no_refund() {
                     contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"),
                 },
             ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
         },
         token_info: CreatorTokenInfo {
             name: "ExactToken".to_string(),
@@ -312,11 +324,10 @@ fn register_standard_pool_with_bluechip_side(
         creator_pool_addr: addr.clone(),
         pool_kind: pool_factory_interfaces::PoolKind::Standard,
         commit_pool_ordinal: 0,
+        creator: None,
+        threshold_crossed: true,
     };
     POOLS_BY_ID.save(deps.as_mut().storage, pool_id, &pool_details).unwrap();
-    crate::state::POOL_ID_BY_ADDRESS
-        .save(deps.as_mut().storage, addr.clone(), &pool_id)
-        .unwrap();
     let counter = POOL_COUNTER.may_load(deps.as_ref().storage).unwrap().unwrap_or(0);
     if pool_id > counter {
         POOL_COUNTER.save(deps.as_mut().storage, &pool_id).unwrap();
@@ -360,11 +371,10 @@ fn register_standard_pool_no_bluechip(
         creator_pool_addr: addr.clone(),
         pool_kind: pool_factory_interfaces::PoolKind::Standard,
         commit_pool_ordinal: 0,
+        creator: None,
+        threshold_crossed: true,
     };
     POOLS_BY_ID.save(deps.as_mut().storage, pool_id, &pool_details).unwrap();
-    crate::state::POOL_ID_BY_ADDRESS
-        .save(deps.as_mut().storage, addr.clone(), &pool_id)
-        .unwrap();
     let counter = POOL_COUNTER.may_load(deps.as_ref().storage).unwrap().unwrap_or(0);
     if pool_id > counter {
         POOL_COUNTER.save(deps.as_mut().storage, &pool_id).unwrap();
@@ -41,6 +41,7 @@ fn default_init() -> FactoryInstantiate {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(1),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         // Under mock, the anchor pool is not queried during UpdateOraclePrice.
         atom_bluechip_anchor_pool_address: addr("unused_anchor"),
         bluechip_mint_contract_address: None,
@@ -50,6 +51,8 @@ fn default_init() -> FactoryInstantiate {
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds:
             crate::state::default_emergency_withdraw_delay_seconds(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     }
 }
 
@@ -36,6 +36,7 @@ fn test_repro_token_sort_order_bug() {
         commit_fee_creator: Decimal::percent(1),
         max_bluechip_lock_per_pool: Uint128::zero(),
         creator_excess_liquidity_lock_days: 0,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_pool.clone(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -43,6 +44,9 @@ fn test_repro_token_sort_order_bug() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
     FACTORYINSTANTIATEINFO
         .save(deps.as_mut().storage, &config)
@@ -63,6 +67,8 @@ fn test_repro_token_sort_order_bug() {
         creator_pool_addr: atom_pool.clone(),
         pool_kind: pool_factory_interfaces::PoolKind::Commit,
         commit_pool_ordinal: 0,
+        creator: None,
+        threshold_crossed: false,
     };
     POOLS_BY_ID
         .save(deps.as_mut().storage, 1, &pool_details)
@@ -129,6 +135,8 @@ fn test_repro_token_sort_order_bug() {
         creator_pool_addr: atom_pool.clone(),
         pool_kind: pool_factory_interfaces::PoolKind::Commit,
         commit_pool_ordinal: 0,
+        creator: None,
+        threshold_crossed: false,
     };
     POOLS_BY_ID
         .save(deps.as_mut().storage, 1, &inverted_pool_details)
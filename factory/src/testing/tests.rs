@@ -6,8 +6,8 @@ use crate::state::{
     POOL_COUNTER, POOL_CREATION_CONTEXT,
 };
 use cosmwasm_std::{
-    Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Env, Event, OwnedDeps, Reply, SubMsgResponse,
-    SubMsgResult, Uint128,
+    from_json, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Env, Event, OwnedDeps, Reply,
+    SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
 
 use crate::asset::{TokenInfo, TokenType};
@@ -77,6 +77,7 @@ fn create_default_instantiate_msg() -> FactoryInstantiate {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(1),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -84,6 +85,9 @@ fn create_default_instantiate_msg() -> FactoryInstantiate {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     }
 }
 
@@ -124,18 +128,11 @@ pub fn register_test_pool_addr(
                 // formula, so this helper MUST emit a non-zero ordinal to
                 // remain a faithful test fixture.
                 commit_pool_ordinal: pool_id,
+                creator: None,
+                threshold_crossed: false,
             },
         )
         .unwrap();
-    // Mirror `state::register_pool` — the reverse address->id index is a
-    // load-bearing invariant that `lookup_pool_by_addr` now depends on.
-    // Bypassing it in tests would leave any handler that resolves a pool
-    // by address (NotifyThresholdCrossed, PayDistributionBounty,
-    // SetAnchorPool, anchor-change config apply, oracle eligibility
-    // propose/apply) unable to find the test fixture.
-    crate::state::POOL_ID_BY_ADDRESS
-        .save(storage, pool_addr.clone(), &pool_id)
-        .unwrap();
 }
 
 pub fn setup_atom_pool(deps: &mut OwnedDeps<MockStorage, MockApi, WasmMockQuerier>) {
@@ -274,6 +271,7 @@ fn proper_initialization() {
         commit_fee_creator: Decimal::percent(10),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -281,6 +279,9 @@ fn proper_initialization() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     let env = mock_env();
@@ -385,13 +386,12 @@ fn test_oracle_initialization_with_multiple_pools() {
             creator_pool_addr: pool_addr.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Commit,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(deps.as_mut().storage, i, &pool_details)
             .unwrap();
-        crate::state::POOL_THRESHOLD_MINTED
-            .save(deps.as_mut().storage, i, &true)
-            .unwrap();
     }
 
     let msg = create_default_instantiate_msg();
@@ -446,6 +446,7 @@ fn create_pair() {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -453,6 +454,9 @@ fn create_pair() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     let env = mock_env();
@@ -477,7 +481,7 @@ fn create_pair() {
         env,
         info,
         ExecuteMsg::Create {
-            pool_msg: CreatePool { pool_token_info: pool_token_info.clone() },
+            pool_msg: CreatePool { pool_token_info: pool_token_info.clone(), raise_deadline: None, distribution_mode: Default::default(), early_supporter_curve: None, commit_allowlist: None },
             token_info: CreatorTokenInfo {
                 name: "Test Token".to_string(),
                 symbol: "TEST".to_string(),
@@ -515,6 +519,7 @@ fn test_create_pair_with_custom_params() {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -522,6 +527,9 @@ fn test_create_pair_with_custom_params() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     let env = mock_env();
@@ -541,7 +549,12 @@ fn test_create_pair_with_custom_params() {
                 TokenType::CreatorToken {
                     contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"),
                 },
-            ] },
+            ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
+        },
         token_info: CreatorTokenInfo {
             name: "Custom Token".to_string(),
             symbol: "CUSTOM".to_string(),
@@ -562,6 +575,224 @@ fn test_create_pair_with_custom_params() {
     );
 }
 
+#[test]
+fn test_create_rejects_out_of_range_raise_deadline() {
+    let mut deps = mock_dependencies(&[]);
+    setup_atom_pool(&mut deps);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin_addr(), &[]),
+        create_default_instantiate_msg(),
+    )
+    .unwrap();
+
+    let env = mock_env();
+    let too_soon = env
+        .block
+        .time
+        .plus_seconds(crate::state::RAISE_DEADLINE_MIN_SECONDS - 1);
+    let too_late = env
+        .block
+        .time
+        .plus_seconds(crate::state::RAISE_DEADLINE_MAX_SECONDS + 1);
+    for deadline in [too_soon, too_late] {
+        let mut msg = create_pool_msg("Deadline");
+        if let ExecuteMsg::Create { pool_msg, .. } = &mut msg {
+            pool_msg.raise_deadline = Some(deadline);
+        }
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&admin_addr(), &creation_fee_funds()),
+            msg,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("raise_deadline"), "got: {}", err);
+    }
+
+    let mut msg = create_pool_msg("Deadline");
+    if let ExecuteMsg::Create { pool_msg, .. } = &mut msg {
+        pool_msg.raise_deadline = Some(env.block.time.plus_seconds(30 * 86_400));
+    }
+    execute(
+        deps.as_mut(),
+        env,
+        message_info(&admin_addr(), &creation_fee_funds()),
+        msg,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_create_rejects_out_of_range_early_supporter_curve() {
+    use pool_factory_interfaces::EarlySupporterCurve;
+
+    let mut deps = mock_dependencies(&[]);
+    setup_atom_pool(&mut deps);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin_addr(), &[]),
+        create_default_instantiate_msg(),
+    )
+    .unwrap();
+
+    let day = crate::state::EARLY_SUPPORTER_DECAY_MIN_SECONDS;
+    for (curve, field) in [
+        (EarlySupporterCurve { max_multiplier: Decimal::one(), decay_seconds: day }, "max_multiplier"),
+        (
+            EarlySupporterCurve {
+                max_multiplier: crate::state::EARLY_SUPPORTER_MAX_MULTIPLIER + Decimal::percent(1),
+                decay_seconds: day,
+            },
+            "max_multiplier",
+        ),
+        (
+            EarlySupporterCurve { max_multiplier: Decimal::percent(200), decay_seconds: day - 1 },
+            "decay_seconds",
+        ),
+        (
+            EarlySupporterCurve {
+                max_multiplier: Decimal::percent(200),
+                decay_seconds: crate::state::EARLY_SUPPORTER_DECAY_MAX_SECONDS + 1,
+            },
+            "decay_seconds",
+        ),
+    ] {
+        let mut msg = create_pool_msg("Curve");
+        if let ExecuteMsg::Create { pool_msg, .. } = &mut msg {
+            pool_msg.early_supporter_curve = Some(curve);
+        }
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin_addr(), &creation_fee_funds()),
+            msg,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(&format!("early_supporter_curve.{}", field)),
+            "got: {}",
+            err
+        );
+    }
+
+    let mut msg = create_pool_msg("Curve");
+    if let ExecuteMsg::Create { pool_msg, .. } = &mut msg {
+        pool_msg.early_supporter_curve = Some(EarlySupporterCurve {
+            max_multiplier: Decimal::percent(200),
+            decay_seconds: 7 * day,
+        });
+    }
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin_addr(), &creation_fee_funds()),
+        msg,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_create_validates_commit_allowlist() {
+    use cosmwasm_std::HexBinary;
+    use pool_factory_interfaces::CommitAllowlist;
+
+    let mut deps = mock_dependencies(&[]);
+    setup_atom_pool(&mut deps);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin_addr(), &[]),
+        create_default_instantiate_msg(),
+    )
+    .unwrap();
+
+    let now = mock_env().block.time;
+    let wallet = make_addr("early_fan").to_string();
+    let valid = CommitAllowlist {
+        merkle_root: Some(HexBinary::from([7u8; 32])),
+        wallets: vec![wallet.clone()],
+        public_at: now.plus_seconds(86_400),
+    };
+    for (allowlist, expected) in [
+        (
+            CommitAllowlist {
+                merkle_root: None,
+                wallets: vec![],
+                ..valid.clone()
+            },
+            "needs a merkle_root",
+        ),
+        (
+            CommitAllowlist {
+                merkle_root: Some(HexBinary::from([7u8; 31])),
+                ..valid.clone()
+            },
+            "must be 32 bytes",
+        ),
+        (
+            CommitAllowlist {
+                wallets: vec![wallet.clone(); crate::state::COMMIT_ALLOWLIST_MAX_WALLETS + 1],
+                ..valid.clone()
+            },
+            "maximum is",
+        ),
+        (
+            CommitAllowlist {
+                public_at: now.plus_seconds(crate::state::COMMIT_ALLOWLIST_MAX_PHASE_SECONDS + 1),
+                ..valid.clone()
+            },
+            "public_at",
+        ),
+    ] {
+        let mut msg = create_pool_msg("Allow");
+        if let ExecuteMsg::Create { pool_msg, .. } = &mut msg {
+            pool_msg.commit_allowlist = Some(allowlist);
+        }
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin_addr(), &creation_fee_funds()),
+            msg,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains(expected), "got: {}", err);
+    }
+
+    // The phase has to end before a configured raise deadline.
+    let mut msg = create_pool_msg("Allow");
+    if let ExecuteMsg::Create { pool_msg, .. } = &mut msg {
+        pool_msg.raise_deadline =
+            Some(now.plus_seconds(crate::state::RAISE_DEADLINE_MIN_SECONDS));
+        pool_msg.commit_allowlist = Some(CommitAllowlist {
+            public_at: now.plus_seconds(crate::state::RAISE_DEADLINE_MIN_SECONDS),
+            ..valid.clone()
+        });
+    }
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin_addr(), &creation_fee_funds()),
+        msg,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("before raise_deadline"), "got: {}", err);
+
+    let mut msg = create_pool_msg("Allow");
+    if let ExecuteMsg::Create { pool_msg, .. } = &mut msg {
+        pool_msg.commit_allowlist = Some(valid);
+    }
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin_addr(), &creation_fee_funds()),
+        msg,
+    )
+    .unwrap();
+}
+
 fn create_pool_msg(name: &str) -> ExecuteMsg {
     ExecuteMsg::Create {
         pool_msg: CreatePool { pool_token_info: [
@@ -571,7 +802,12 @@ fn create_pool_msg(name: &str) -> ExecuteMsg {
                 TokenType::CreatorToken {
                     contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"),
                 },
-            ] },
+            ],
+            raise_deadline: None,
+            distribution_mode: Default::default(),
+            early_supporter_curve: None,
+            commit_allowlist: None,
+        },
         token_info: CreatorTokenInfo {
             name: name.to_string(),
             // Uppercase so the symbol passes factory validation (A-Z, 0-9 only).
@@ -734,6 +970,7 @@ fn test_complete_pool_creation_flow() {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -741,6 +978,9 @@ fn test_complete_pool_creation_flow() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     let env = mock_env();
@@ -759,7 +999,12 @@ fn test_complete_pool_creation_flow() {
             TokenType::CreatorToken {
                 contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"),
             },
-        ] };
+        ],
+        raise_deadline: None,
+        distribution_mode: Default::default(),
+        early_supporter_curve: None,
+        commit_allowlist: None,
+    };
 
     let create_msg = ExecuteMsg::Create {
         pool_msg: pool_msg.clone(),
@@ -838,15 +1083,30 @@ fn test_complete_pool_creation_flow() {
         "POOL_CREATION_CONTEXT should be removed after successful creation"
     );
 
-    // finalize_pool now emits three messages:
+    // finalize_pool now emits four messages:
     // 1. CW20 UpdateMinter (hand the creator-token's minter to the pool)
-    // 2. CW721 TransferOwnership (stage the pool as pending_owner)
-    // 3. AcceptNftOwnership {} dispatched to the pool itself, mirroring
+    // 2. CW20 UpdateMarketing (hand the marketing role to the pool)
+    // 3. CW721 TransferOwnership (stage the pool as pending_owner)
+    // 4. AcceptNftOwnership {} dispatched to the pool itself, mirroring
     // the symmetric two-phase NFT-accept flow already in place for
     // standard pools. The pool's handler then sends the matching
     // AcceptOwnership back to the CW721, closing the
     // pending-ownership window inside this create tx.
-    assert_eq!(res.messages.len(), 3);
+    assert_eq!(res.messages.len(), 4);
+    match &res.messages[1].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            let msg: cw20::Cw20ExecuteMsg = from_json(msg).unwrap();
+            assert_eq!(
+                msg,
+                cw20::Cw20ExecuteMsg::UpdateMarketing {
+                    project: None,
+                    description: None,
+                    marketing: Some(pool_addr.to_string()),
+                }
+            );
+        }
+        other => panic!("expected UpdateMarketing, got {:?}", other),
+    }
 }
 
 #[test]
@@ -885,6 +1145,7 @@ fn test_config() {
         commit_fee_creator: Decimal::percent(10),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -892,6 +1153,9 @@ fn test_config() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     assert_eq!(config.factory_admin_address, Addr::unchecked("admin1..."));
@@ -928,6 +1192,7 @@ fn test_reply_handling() {
         commit_fee_creator: Decimal::from_ratio(10u128, 100u128),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -935,6 +1200,9 @@ fn test_reply_handling() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     let env = mock_env();
@@ -952,7 +1220,12 @@ fn test_reply_handling() {
             TokenType::CreatorToken {
                 contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"), // Use placeholder
             },
-        ] };
+        ],
+        raise_deadline: None,
+        distribution_mode: Default::default(),
+        early_supporter_curve: None,
+        commit_allowlist: None,
+    };
 
     let ctx = PoolCreationContext {
         temp: TempPoolCreation {
@@ -1553,7 +1826,6 @@ fn test_oracle_anchor_only_when_basket_disabled() {
             .save(&mut deps.storage, pool_addr.clone(), &pool_state)
             .unwrap();
 
-        let creator_pool_addr_clone = pool_addr.clone();
         let pool_details = PoolDetails {
             pool_id,
             pool_token_info: [
@@ -1567,15 +1839,15 @@ fn test_oracle_anchor_only_when_basket_disabled() {
             creator_pool_addr: pool_addr,
             pool_kind: pool_factory_interfaces::PoolKind::Commit,
             commit_pool_ordinal: pool_id,
+            creator: None,
+            // Threshold-crossed so the oracle will include this test pool.
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, pool_id, &pool_details)
             .unwrap();
-        // Faithful fixture (audits L-2 + M-5): reverse-index + counter
-        // upper bound mirror what `state::register_pool` writes.
-        crate::state::POOL_ID_BY_ADDRESS
-            .save(&mut deps.storage, creator_pool_addr_clone, &pool_id)
-            .unwrap();
+        // Faithful fixture (audit M-5): the counter upper bound mirrors
+        // what the create path writes.
         let current_counter = crate::state::POOL_COUNTER
             .may_load(&deps.storage)
             .unwrap()
@@ -1585,10 +1857,6 @@ fn test_oracle_anchor_only_when_basket_disabled() {
                 .save(&mut deps.storage, &pool_id)
                 .unwrap();
         }
-        // Mark as threshold-crossed so the oracle will include this test pool.
-        crate::state::POOL_THRESHOLD_MINTED
-            .save(&mut deps.storage, pool_id, &true)
-            .unwrap();
     };
 
     add_test_pool(
@@ -1867,6 +2135,7 @@ fn test_query_pyth_atom_usd_price_success() {
         commit_fee_creator: Decimal::percent(10),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -1874,6 +2143,9 @@ fn test_query_pyth_atom_usd_price_success() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
     FACTORYINSTANTIATEINFO
         .save(deps.as_mut().storage, &config)
@@ -1914,6 +2186,7 @@ fn test_query_pyth_atom_usd_price_default() {
         commit_fee_creator: Decimal::percent(10),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -1921,6 +2194,9 @@ fn test_query_pyth_atom_usd_price_default() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
     FACTORYINSTANTIATEINFO
         .save(deps.as_mut().storage, &config)
@@ -1951,6 +2227,7 @@ fn test_query_pyth_extreme_atom_prices() {
         commit_fee_creator: Decimal::percent(10),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -1958,6 +2235,9 @@ fn test_query_pyth_extreme_atom_prices() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
     FACTORYINSTANTIATEINFO
         .save(deps.as_mut().storage, &config)
@@ -2007,6 +2287,7 @@ fn test_get_bluechip_usd_price_with_pyth() {
         commit_fee_creator: Decimal::percent(10),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -2014,6 +2295,9 @@ fn test_get_bluechip_usd_price_with_pyth() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
     FACTORYINSTANTIATEINFO
         .save(deps.as_mut().storage, &config)
@@ -2085,6 +2369,7 @@ fn test_bluechip_usd_price_with_different_atom_prices() {
         commit_fee_creator: Decimal::percent(10),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -2092,6 +2377,9 @@ fn test_bluechip_usd_price_with_different_atom_prices() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
     FACTORYINSTANTIATEINFO
         .save(deps.as_mut().storage, &config)
@@ -2166,6 +2454,7 @@ fn test_conversion_functions_with_pyth() {
         commit_fee_creator: Decimal::percent(10),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -2173,6 +2462,9 @@ fn test_conversion_functions_with_pyth() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
     FACTORYINSTANTIATEINFO
         .save(deps.as_mut().storage, &config)
@@ -2257,6 +2549,7 @@ fn test_bluechip_minting_on_threshold_crossing() {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -2264,6 +2557,9 @@ fn test_bluechip_minting_on_threshold_crossing() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     let env = mock_env();
@@ -2366,6 +2662,7 @@ fn test_no_mint_when_amount_is_zero() {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -2373,6 +2670,9 @@ fn test_no_mint_when_amount_is_zero() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     let env = mock_env();
@@ -2437,6 +2737,10 @@ fn create_test_pool_msg() -> CreatePool {
                 contract_addr: Addr::unchecked("WILL_BE_CREATED_BY_FACTORY"),
             },
         ],
+        raise_deadline: None,
+        distribution_mode: Default::default(),
+        early_supporter_curve: None,
+        commit_allowlist: None,
     }
 }
 
@@ -3051,17 +3355,12 @@ fn test_oracle_ignores_pools_without_threshold_crossed() {
             creator_pool_addr: pool_addr.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Commit,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: true,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, 1, &pool_details)
             .unwrap();
-        // Faithful fixture (audits L-2 + M-5).
-        crate::state::POOL_ID_BY_ADDRESS
-            .save(&mut deps.storage, pool_addr.clone(), &1u64)
-            .unwrap();
-        crate::state::POOL_THRESHOLD_MINTED
-            .save(&mut deps.storage, 1, &true)
-            .unwrap();
         let pool_state = PoolStateResponseForFactory {
             pool_contract_address: pool_addr.clone(),
             nft_ownership_accepted: true,
@@ -3095,18 +3394,17 @@ fn test_oracle_ignores_pools_without_threshold_crossed() {
             creator_pool_addr: pool_addr.clone(),
             pool_kind: pool_factory_interfaces::PoolKind::Commit,
             commit_pool_ordinal: 0,
+            creator: None,
+            threshold_crossed: false,
         };
         POOLS_BY_ID
             .save(&mut deps.storage, 2, &pool_details)
             .unwrap();
-        // Faithful fixture (audits L-2 + M-5). POOL_COUNTER bumped to 2
+        // Faithful fixture (audit M-5). POOL_COUNTER bumped to 2
         // here so the random-sampler at `get_eligible_creator_pools`
         // ranges `[1, 2]` and gets a real chance to pick both pools.
-        crate::state::POOL_ID_BY_ADDRESS
-            .save(&mut deps.storage, pool_addr.clone(), &2u64)
-            .unwrap();
+        // `threshold_crossed` deliberately left false for pool 2.
         crate::state::POOL_COUNTER.save(&mut deps.storage, &2u64).unwrap();
-        // Deliberately NOT saving POOL_THRESHOLD_MINTED for pool 2.
         let pool_state = PoolStateResponseForFactory {
             pool_contract_address: pool_addr.clone(),
             nft_ownership_accepted: true,
@@ -3922,6 +4220,8 @@ fn register_test_pool(deps: &mut OwnedDeps<MockStorage, MockApi, WasmMockQuerier
                 creator_pool_addr: addr.clone(),
                 pool_kind: pool_factory_interfaces::PoolKind::Commit,
                 commit_pool_ordinal: 0,
+                creator: None,
+                threshold_crossed: false,
             },
         )
         .unwrap();
@@ -5372,3 +5672,706 @@ mod post_reset_buffer_tests {
         );
     }
 }
+
+// ---------------------------------------------------------------------------
+// Pool registry queries
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_pool_registry_queries_filter_and_paginate() {
+    use crate::query::{query, PoolResponse, PoolsResponse, QueryMsg};
+    use crate::state::{register_pool, set_pool_threshold_crossed};
+    use pool_factory_interfaces::PoolKind;
+
+    let mut deps = mock_dependencies(&[]);
+    let api = MockApi::default();
+    let bluechip = TokenType::Native {
+        denom: "ubluechip".to_string(),
+    };
+    let pools = [
+        (1u64, api.addr_make("pool_one"), PoolKind::Commit, "token_one"),
+        (2, api.addr_make("pool_two"), PoolKind::Commit, "token_two"),
+        (3, api.addr_make("pool_three"), PoolKind::Standard, "token_three"),
+    ];
+    for (pool_id, addr, kind, token) in &pools {
+        register_pool(
+            deps.as_mut().storage,
+            *pool_id,
+            addr,
+            &PoolDetails {
+                pool_id: *pool_id,
+                pool_token_info: [
+                    bluechip.clone(),
+                    TokenType::CreatorToken {
+                        contract_addr: api.addr_make(token),
+                    },
+                ],
+                creator_pool_addr: addr.clone(),
+                pool_kind: kind.clone(),
+                commit_pool_ordinal: *pool_id,
+                creator: None,
+                threshold_crossed: false,
+            },
+        )
+        .unwrap();
+    }
+    assert!(set_pool_threshold_crossed(deps.as_mut().storage, 2).unwrap());
+    deps.querier.paused_pools.insert(pools[1].1.to_string());
+    deps.querier.query_error_pools.insert(pools[2].1.to_string());
+
+    let list = |deps: &cosmwasm_std::OwnedDeps<MockStorage, MockApi, WasmMockQuerier>,
+                msg: QueryMsg| {
+        from_json::<PoolsResponse>(query(deps.as_ref(), mock_env(), msg).unwrap())
+            .unwrap()
+            .pools
+            .into_iter()
+            .map(|p| p.details.pool_id)
+            .collect::<Vec<_>>()
+    };
+    let pools_msg = |start_after, limit, kind, threshold_crossed, paused| QueryMsg::Pools {
+        start_after,
+        limit,
+        kind,
+        threshold_crossed,
+        paused,
+    };
+
+    assert_eq!(list(&deps, pools_msg(None, None, None, None, None)), vec![1, 2, 3]);
+    assert_eq!(list(&deps, pools_msg(Some(1), Some(1), None, None, None)), vec![2]);
+    assert_eq!(
+        list(&deps, pools_msg(None, None, Some(PoolKind::Commit), None, None)),
+        vec![1, 2]
+    );
+    // Standard pools have no commit phase and count as crossed.
+    assert_eq!(
+        list(&deps, pools_msg(None, None, None, Some(true), None)),
+        vec![2, 3]
+    );
+    assert_eq!(
+        list(&deps, pools_msg(Some(2), None, None, Some(true), None)),
+        vec![3]
+    );
+    assert_eq!(
+        list(&deps, pools_msg(None, None, Some(PoolKind::Commit), Some(false), None)),
+        vec![1]
+    );
+    // A pool that can't be queried matches neither pause filter.
+    assert_eq!(
+        list(&deps, pools_msg(None, None, None, None, Some(false))),
+        vec![1]
+    );
+    assert_eq!(
+        list(&deps, pools_msg(None, None, None, None, Some(true))),
+        vec![2]
+    );
+    // `limit` bounds the pools read, so a sparse paused filter pages
+    // through short pages until the cursor runs out.
+    let page = |deps: &cosmwasm_std::OwnedDeps<MockStorage, MockApi, WasmMockQuerier>,
+                start_after| {
+        let res: PoolsResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                pools_msg(start_after, Some(1), None, None, Some(true)),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        (
+            res.pools
+                .into_iter()
+                .map(|p| p.details.pool_id)
+                .collect::<Vec<_>>(),
+            res.next_start_after,
+        )
+    };
+    assert_eq!(page(&deps, None), (vec![], Some(1)));
+    assert_eq!(page(&deps, Some(1)), (vec![2], Some(2)));
+    assert_eq!(page(&deps, Some(2)), (vec![], Some(3)));
+    assert_eq!(page(&deps, Some(3)), (vec![], None));
+    let by_token: PoolsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PoolsByToken {
+                token: "ubluechip".to_string(),
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(by_token.pools.len(), 2);
+    assert_eq!(by_token.next_start_after, Some(2));
+    assert_eq!(
+        list(
+            &deps,
+            QueryMsg::PoolsByToken {
+                token: api.addr_make("token_two").to_string(),
+                start_after: None,
+                limit: None,
+            }
+        ),
+        vec![2]
+    );
+    assert_eq!(
+        list(
+            &deps,
+            QueryMsg::PoolsByToken {
+                token: "ubluechip".to_string(),
+                start_after: Some(1),
+                limit: None,
+            }
+        ),
+        vec![2, 3]
+    );
+
+    // Pair lookups are order-independent.
+    let by_pair: Option<PoolResponse> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PoolByPair {
+                asset_a: TokenType::CreatorToken {
+                    contract_addr: api.addr_make("token_two"),
+                },
+                asset_b: bluechip.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let by_pair = by_pair.unwrap();
+    assert_eq!(by_pair.details.pool_id, 2);
+    assert!(by_pair.threshold_crossed);
+    assert_eq!(by_pair.paused, Some(true));
+
+    let by_addr: Option<PoolResponse> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PoolByAddress {
+                addr: pools[2].1.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let by_addr = by_addr.unwrap();
+    assert_eq!(by_addr.details.pool_id, 3);
+    assert_eq!(by_addr.paused, None);
+
+    let missing: Option<PoolResponse> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PoolByAddress {
+                addr: api.addr_make("unknown").to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(missing.is_none());
+}
+
+#[test]
+fn test_creator_profile_registry() {
+    use crate::query::{query, CreatorProfileResponse, CreatorsResponse, QueryMsg};
+
+    let mut deps = mock_dependencies(&[]);
+    setup_atom_pool(&mut deps);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&admin_addr(), &[]),
+        create_default_instantiate_msg(),
+    )
+    .unwrap();
+
+    let creator = make_addr("profile_creator");
+    let profile = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>, who: &Addr| {
+        from_json::<Option<CreatorProfileResponse>>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::CreatorProfile {
+                    creator: who.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    assert!(profile(&deps, &creator).is_none());
+
+    // Editing before the first Create has no profile to edit.
+    let update = ExecuteMsg::UpdateCreatorProfile {
+        display_name: Some("Profile Creator".to_string()),
+        avatar_uri: Some("ipfs://avatar".to_string()),
+        links: vec!["https://example.com".to_string()],
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&creator, &[]),
+        update.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CreatorProfileNotFound { .. }));
+
+    // Two pools from the same wallet share one profile.
+    for pool_id in 1u64..=2 {
+        let mut pool_env = env.clone();
+        pool_env.block.time = pool_env
+            .block
+            .time
+            .plus_seconds((pool_id - 1) * (crate::state::COMMIT_POOL_CREATE_RATE_LIMIT_SECONDS + 1));
+        execute(
+            deps.as_mut(),
+            pool_env.clone(),
+            message_info(&creator, &creation_fee_funds()),
+            create_pool_msg(&format!("Profile{}", pool_id)),
+        )
+        .unwrap();
+        simulate_complete_reply_chain(&mut deps, pool_env, pool_id);
+    }
+    let opened = profile(&deps, &creator).unwrap();
+    assert_eq!(opened.pool_ids, vec![1, 2]);
+    assert_eq!(opened.profile.display_name, None);
+    assert!(!opened.profile.verified);
+    assert_eq!(opened.profile.registered_at, env.block.time);
+
+    execute(deps.as_mut(), env.clone(), message_info(&creator, &[]), update).unwrap();
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&creator, &[]),
+        ExecuteMsg::UpdateCreatorProfile {
+            display_name: None,
+            avatar_uri: None,
+            links: vec!["https://a.example".to_string(); 6],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidCreatorProfile { .. }));
+
+    // Only the admin grants or revokes the badge.
+    let verify = |verified| ExecuteMsg::SetCreatorVerified {
+        creator: creator.to_string(),
+        verified,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&creator, &[]),
+        verify(true),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&admin_addr(), &[]),
+        verify(true),
+    )
+    .unwrap();
+    let edited = profile(&deps, &creator).unwrap().profile;
+    assert_eq!(edited.display_name.as_deref(), Some("Profile Creator"));
+    assert_eq!(edited.avatar_uri.as_deref(), Some("ipfs://avatar"));
+    assert_eq!(edited.links, vec!["https://example.com".to_string()]);
+    assert!(edited.verified);
+
+    execute(
+        deps.as_mut(),
+        env,
+        message_info(&admin_addr(), &[]),
+        verify(false),
+    )
+    .unwrap();
+    assert!(!profile(&deps, &creator).unwrap().profile.verified);
+
+    let paged: CreatorsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::CreatorsPaged {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(paged.creators.len(), 1);
+    assert_eq!(paged.creators[0].creator, creator);
+    assert_eq!(paged.creators[0].pool_ids, vec![1, 2]);
+    let after: CreatorsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::CreatorsPaged {
+                start_after: Some(creator.to_string()),
+                limit: Some(5),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(after.creators.is_empty());
+}
+
+#[test]
+fn test_creator_wallet_rotation_moves_pools_and_profile() {
+    use crate::query::{query, CreatorProfileResponse, QueryMsg};
+
+    let mut deps = mock_dependencies(&[]);
+    setup_atom_pool(&mut deps);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&admin_addr(), &[]),
+        create_default_instantiate_msg(),
+    )
+    .unwrap();
+
+    let creator = make_addr("rotating_creator");
+    let new_wallet = make_addr("creator_multisig");
+    let profile = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>, who: &Addr| {
+        from_json::<Option<CreatorProfileResponse>>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::CreatorProfile {
+                    creator: who.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    for pool_id in 1u64..=2 {
+        let mut pool_env = env.clone();
+        pool_env.block.time = pool_env
+            .block
+            .time
+            .plus_seconds((pool_id - 1) * (crate::state::COMMIT_POOL_CREATE_RATE_LIMIT_SECONDS + 1));
+        execute(
+            deps.as_mut(),
+            pool_env.clone(),
+            message_info(&creator, &creation_fee_funds()),
+            create_pool_msg(&format!("Rotate{}", pool_id)),
+        )
+        .unwrap();
+        simulate_complete_reply_chain(&mut deps, pool_env, pool_id);
+    }
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&creator, &[]),
+        ExecuteMsg::UpdateCreatorProfile {
+            display_name: Some("Rotating".to_string()),
+            avatar_uri: None,
+            links: vec![],
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&admin_addr(), &[]),
+        ExecuteMsg::SetCreatorVerified {
+            creator: creator.to_string(),
+            verified: true,
+        },
+    )
+    .unwrap();
+
+    let notify = ExecuteMsg::NotifyCreatorWalletRotated {
+        new_wallet: new_wallet.to_string(),
+    };
+    // Only a registered commit pool can report a rotation.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&creator, &[]),
+        notify.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let pool_addr = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>, id: u64| {
+        POOLS_BY_ID.load(&deps.storage, id).unwrap().creator_pool_addr
+    };
+    let pool_one = pool_addr(&deps, 1);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&pool_one, &[]),
+        notify.clone(),
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "profile_moved" && a.value == "true"));
+    let moved = profile(&deps, &new_wallet).unwrap();
+    assert_eq!(moved.pool_ids, vec![1]);
+    assert_eq!(moved.profile.display_name.as_deref(), Some("Rotating"));
+    // The badge stays with the wallet the admin verified.
+    assert!(!moved.profile.verified);
+    // The old wallet keeps its profile while it still lists a pool.
+    let old_profile = profile(&deps, &creator).unwrap();
+    assert_eq!(old_profile.pool_ids, vec![2]);
+    assert!(old_profile.profile.verified);
+
+    let pool_two = pool_addr(&deps, 2);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&pool_two, &[]),
+        notify,
+    )
+    .unwrap();
+    assert!(profile(&deps, &creator).is_none());
+    assert_eq!(profile(&deps, &new_wallet).unwrap().pool_ids, vec![1, 2]);
+
+    // The new wallet edits the carried-over profile directly.
+    execute(
+        deps.as_mut(),
+        env,
+        message_info(&new_wallet, &[]),
+        ExecuteMsg::UpdateCreatorProfile {
+            display_name: Some("Multisig".to_string()),
+            avatar_uri: None,
+            links: vec![],
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        profile(&deps, &new_wallet).unwrap().profile.display_name.as_deref(),
+        Some("Multisig")
+    );
+}
+
+#[test]
+fn test_roles_are_timelocked_and_scoped() {
+    use crate::query::{query, QueryMsg, RolesResponse};
+    use crate::state::{Role, ADMIN_TIMELOCK_SECONDS};
+
+    let mut deps = mock_dependencies(&[]);
+    setup_atom_pool(&mut deps);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&admin_addr(), &[]),
+        create_default_instantiate_msg(),
+    )
+    .unwrap();
+    let creator = make_addr("roles_creator");
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&creator, &creation_fee_funds()),
+        create_pool_msg("Roles"),
+    )
+    .unwrap();
+    simulate_complete_reply_chain(&mut deps, env.clone(), 1);
+
+    let pauser = make_addr("pauser");
+    let treasurer = make_addr("treasurer");
+    let roles = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>| {
+        from_json::<RolesResponse>(query(deps.as_ref(), mock_env(), QueryMsg::Roles {}).unwrap())
+            .unwrap()
+    };
+    let propose = |role: Role, holder: &Addr, grant: bool| ExecuteMsg::ProposeRoleChange {
+        role,
+        holder: holder.to_string(),
+        grant,
+    };
+    let apply = |role: Role, holder: &Addr| ExecuteMsg::ApplyRoleChange {
+        role,
+        holder: holder.to_string(),
+    };
+
+    // Only the super-admin can propose.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&pauser, &[]),
+        propose(Role::Pauser, &pauser, true),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Revoking a role nobody holds is a no-op and rejected.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&admin_addr(), &[]),
+        propose(Role::Pauser, &pauser, false),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::RoleChangeNoChange { .. }));
+
+    for (role, holder) in [(Role::Pauser, &pauser), (Role::Treasurer, &treasurer)] {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&admin_addr(), &[]),
+            propose(role, holder, true),
+        )
+        .unwrap();
+    }
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&admin_addr(), &[]),
+        propose(Role::Pauser, &pauser, true),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::RoleChangeAlreadyPending { .. }));
+
+    let view = roles(&deps);
+    assert_eq!(view.super_admin, admin_addr());
+    assert!(view.holders.is_empty());
+    assert_eq!(view.pending.len(), 2);
+    assert!(view.pending.iter().all(|p| p.grant
+        && p.effective_after == env.block.time.plus_seconds(ADMIN_TIMELOCK_SECONDS)));
+
+    // Nothing lands before the timelock, and the grantee has no power yet.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&admin_addr(), &[]),
+        apply(Role::Pauser, &pauser),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::TimelockNotExpired { .. }));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&pauser, &[]),
+        ExecuteMsg::PausePool { pool_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let mut later = env.clone();
+    later.block.time = later.block.time.plus_seconds(ADMIN_TIMELOCK_SECONDS);
+    for (role, holder) in [(Role::Pauser, &pauser), (Role::Treasurer, &treasurer)] {
+        execute(
+            deps.as_mut(),
+            later.clone(),
+            message_info(&admin_addr(), &[]),
+            apply(role, holder),
+        )
+        .unwrap();
+    }
+    let view = roles(&deps);
+    assert!(view.pending.is_empty());
+    assert_eq!(view.holders.len(), 2);
+    assert!(view
+        .holders
+        .iter()
+        .any(|h| h.role == Role::Pauser && h.holder == pauser && h.granted_at == later.block.time));
+
+    // Each role reaches only its own handlers.
+    execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&pauser, &[]),
+        ExecuteMsg::PausePool { pool_id: 1 },
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&pauser, &[]),
+        ExecuteMsg::SetOracleUpdateBounty {
+            new_bounty: Uint128::new(100_000),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    let err = execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&pauser, &[]),
+        ExecuteMsg::UpgradePools {
+            new_code_id: 200,
+            pool_ids: None,
+            migrate_msg: Binary::default(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&treasurer, &[]),
+        ExecuteMsg::SetOracleUpdateBounty {
+            new_bounty: Uint128::new(100_000),
+        },
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&treasurer, &[]),
+        ExecuteMsg::UnpausePool { pool_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Revokes go through the same timelock, and can be cancelled.
+    execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&admin_addr(), &[]),
+        propose(Role::Pauser, &pauser, false),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&admin_addr(), &[]),
+        ExecuteMsg::CancelRoleChange {
+            role: Role::Pauser,
+            holder: pauser.to_string(),
+        },
+    )
+    .unwrap();
+    assert!(roles(&deps).pending.is_empty());
+    execute(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&admin_addr(), &[]),
+        propose(Role::Pauser, &pauser, false),
+    )
+    .unwrap();
+    let mut revoked = later.clone();
+    revoked.block.time = revoked.block.time.plus_seconds(ADMIN_TIMELOCK_SECONDS);
+    execute(
+        deps.as_mut(),
+        revoked.clone(),
+        message_info(&admin_addr(), &[]),
+        apply(Role::Pauser, &pauser),
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        revoked,
+        message_info(&pauser, &[]),
+        ExecuteMsg::UnpausePool { pool_id: 1 },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    let view = roles(&deps);
+    assert_eq!(view.holders.len(), 1);
+    assert_eq!(view.holders[0].role, Role::Treasurer);
+}
@@ -60,6 +60,7 @@ fn test_propose_and_execute_update_config() {
         commit_fee_creator: Decimal::from_ratio(10u128, 100u128),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -67,6 +68,9 @@ fn test_propose_and_execute_update_config() {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     };
 
     let env = mock_env();
@@ -93,6 +97,36 @@ fn test_propose_and_execute_update_config() {
     let _ = the_admin; // ensure binding stays in scope across edits
     assert_eq!(err.to_string(), "Unauthorized");
 
+    // A vesting cliff past the vesting end is rejected at propose time.
+    let bad_vesting = ExecuteMsg::ProposeConfigUpdate {
+        config: FactoryInstantiate {
+            creator_vesting: pool_factory_interfaces::CreatorVestingConfig {
+                cliff_seconds: 200,
+                duration_seconds: 100,
+            },
+            ..new_config.clone()
+        },
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), bad_vesting).unwrap_err();
+    assert!(
+        err.to_string().contains("creator_vesting.cliff_seconds"),
+        "Unexpected error: {}",
+        err
+    );
+
+    let long_veto = ExecuteMsg::ProposeConfigUpdate {
+        config: FactoryInstantiate {
+            creator_wallet_veto_seconds: crate::state::CREATOR_WALLET_VETO_MAX_SECONDS + 1,
+            ..new_config.clone()
+        },
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), long_veto).unwrap_err();
+    assert!(
+        err.to_string().contains("creator_wallet_veto_seconds"),
+        "Unexpected error: {}",
+        err
+    );
+
     let res = execute(deps.as_mut(), env.clone(), info.clone(), propose_msg).unwrap();
     assert_eq!(res.attributes[0], ("action", "propose_config_update"));
 
@@ -244,6 +278,8 @@ fn test_update_specific_pool_from_registry() {
         creator_pool_addr: pool_addr.clone(),
         pool_kind: pool_factory_interfaces::PoolKind::Commit,
         commit_pool_ordinal: 0,
+        creator: None,
+        threshold_crossed: false,
     };
     POOLS_BY_ID
         .save(&mut deps.storage, pool_id, &pool_details)
@@ -769,6 +805,7 @@ fn default_factory_instantiate_msg() -> FactoryInstantiate {
         commit_fee_creator: Decimal::percent(5),
         max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
         creator_excess_liquidity_lock_days: 7,
+        launch_protection: Default::default(),
         atom_bluechip_anchor_pool_address: atom_bluechip_pool_addr(),
         bluechip_mint_contract_address: None,
         bluechip_denom: "ubluechip".to_string(),
@@ -776,6 +813,9 @@ fn default_factory_instantiate_msg() -> FactoryInstantiate {
         standard_pool_creation_fee_usd: cosmwasm_std::Uint128::new(1_000_000),
         threshold_payout_amounts: Default::default(),
         emergency_withdraw_delay_seconds: 86_400,
+        protocol_fee: Default::default(),
+        creator_vesting: Default::default(),
+        creator_wallet_veto_seconds: 0,
     }
 }
 
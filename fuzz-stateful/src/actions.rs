@@ -398,6 +398,10 @@ pub fn apply(world: &mut World, action: Action) -> ActionOutcome {
                     transaction_deadline: None,
                     belief_price: None,
                     max_spread: Some(Decimal::percent(10)),
+                    tier: None,
+                    beneficiary: None,
+                    referrer: None,
+                    allowlist_proof: None,
                 },
                 &[Coin::new(amount, BLUECHIP_DENOM)],
             );
@@ -893,6 +897,8 @@ pub fn apply(world: &mut World, action: Action) -> ActionOutcome {
             let msg = creator_pool::msg::ExecuteMsg::CollectFees {
                 position_id: first.position_id,
                 transaction_deadline: None,
+                output_asset: None,
+                min_output: None,
             };
             let res = world.app.execute_contract(user, pool.pool_addr.clone(), &msg, &[]);
             match res {
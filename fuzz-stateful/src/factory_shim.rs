@@ -155,7 +155,7 @@ pub fn execute(
                 Some(addr) if addr == info.sender => {}
                 _ => return Err(StdError::generic_err("unauthorized notify")),
             }
-            // Idempotency: production factory's POOL_THRESHOLD_MINTED guard.
+            // Idempotency: production factory's `PoolDetails.threshold_crossed` guard.
             if MINTED.may_load(deps.storage, pool_id)?.unwrap_or(false) {
                 return Err(StdError::generic_err("already minted"));
             }
@@ -173,6 +173,16 @@ pub fn execute(
             // to bluechip balance accounting.
             Ok(Response::new().add_attribute("action", "pay_bounty"))
         }
+        HarnessExecuteMsg::Factory(FactoryExecuteMsg::NotifyCreatorWalletRotated {
+            new_wallet: _,
+        }) => {
+            // Authenticate caller is a registered pool. The harness keeps
+            // no creator registry, so there is nothing to re-key.
+            let _ = POOL_ID_BY_ADDR
+                .may_load(deps.storage, &info.sender)?
+                .ok_or_else(|| StdError::generic_err("unregistered pool"))?;
+            Ok(Response::new().add_attribute("action", "creator_wallet_rotated"))
+        }
     }
 }
 
@@ -155,6 +155,10 @@ fn check_pool_invariants(world: &mut World, i: usize, snap: &crate::world::PoolH
                     transaction_deadline: None,
                     belief_price: None,
                     max_spread: None,
+                    tier: None,
+                    beneficiary: None,
+                    referrer: None,
+                    allowlist_proof: None,
                 },
                 &[Coin::new(1u128, BLUECHIP_DENOM)],
             );
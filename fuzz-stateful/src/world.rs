@@ -471,6 +471,14 @@ pub fn create_creator_pool(
                 token_address: cw20_addr.clone(),
                 max_bluechip_lock_per_pool: Uint128::new(10_000_000_000),
                 creator_excess_liquidity_lock_days: 7,
+                launch_protection: Default::default(),
+                protocol_fee_share: Default::default(),
+                raise_deadline: Default::default(),
+                creator_vesting: Default::default(),
+                creator_wallet_veto_seconds: 0,
+                distribution_mode: Default::default(),
+                early_supporter_curve: None,
+                commit_allowlist: None,
             },
             &[],
             &format!("pool-{pool_id}"),
@@ -630,6 +638,7 @@ pub fn create_standard_pool(
                 used_factory_addr: world.factory_shim.clone(),
                 position_nft_address: nft_addr.clone(),
                 bluechip_wallet_address: world.admin.clone(),
+                protocol_fee_share: Default::default(),
             },
             &[],
             &format!("std-pool-{pool_id}"),
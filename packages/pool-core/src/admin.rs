@@ -5,7 +5,9 @@
 //! `execute_emergency_withdraw_initiate` (Phase 1: pause + arm the 24h
 //! timelock) and `execute_emergency_withdraw_core_drain` (Phase 2: drain
 //! reserves+fee_reserves+CREATOR_FEE_POT, write the audit record, flip
-//! EMERGENCY_DRAINED). The creator-pool crate wraps these with its
+//! EMERGENCY_DRAINED). The protocol fee switch handlers
+//! (`execute_set_protocol_fee_share`, `execute_collect_protocol_fees`)
+//! are factory-only as well. The creator-pool crate wraps these with its
 //! commit-only bookkeeping (pre-threshold rejection, CREATOR_EXCESS_POSITION
 //! sweep, DISTRIBUTION_STATE halt); standard-pool calls them directly
 //! with no extras.
@@ -17,9 +19,9 @@ use crate::liquidity_helpers::{sync_position_on_transfer, verify_position_owners
 use crate::state::{
     EmergencyDrainSnapshot, EmergencyWithdrawalInfo, COMMITFEEINFO, CREATOR_FEE_POT,
     EMERGENCY_CLAIM_DORMANCY_SECONDS, EMERGENCY_DRAINED, EMERGENCY_DRAIN_SNAPSHOT,
-    EMERGENCY_WITHDRAWAL, LIQUIDITY_POSITIONS,
+    EMERGENCY_WITHDRAWAL, LIQUIDITY_POSITIONS, MAX_PROTOCOL_FEE_SHARE,
     PENDING_EMERGENCY_WITHDRAW, POOL_FEE_STATE, POOL_INFO, POOL_PAUSED, POOL_PAUSED_AUTO,
-    POOL_SPECS, POOL_STATE,
+    POOL_SPECS, POOL_STATE, PROTOCOL_FEE_STATE,
 };
 use pool_factory_interfaces::{EmergencyWithdrawDelayResponse, FactoryQueryMsg};
 use cosmwasm_std::{
@@ -236,6 +238,15 @@ pub fn execute_emergency_withdraw_core_drain(
         sweep_1 = sweep_1.checked_add(pot.amount_1)?;
         CREATOR_FEE_POT.remove(deps.storage);
     }
+    if let Some(mut protocol) = PROTOCOL_FEE_STATE.may_load(deps.storage)? {
+        sweep_0 = sweep_0.checked_add(protocol.owed_0)?;
+        sweep_1 = sweep_1.checked_add(protocol.owed_1)?;
+        protocol.total_collected_0 = protocol.total_collected_0.checked_add(protocol.owed_0)?;
+        protocol.total_collected_1 = protocol.total_collected_1.checked_add(protocol.owed_1)?;
+        protocol.owed_0 = Uint128::zero();
+        protocol.owed_1 = Uint128::zero();
+        PROTOCOL_FEE_STATE.save(deps.storage, &protocol)?;
+    }
     sweep_0 = sweep_0.checked_add(accumulation_drain_0)?;
     sweep_1 = sweep_1.checked_add(accumulation_drain_1)?;
 
@@ -763,6 +774,109 @@ pub fn execute_update_config_from_factory(
         .add_attribute("block_time", env.block.time.seconds().to_string()))
 }
 
+// ---------------------------------------------------------------------------
+// Protocol fee switch (factory-only)
+// ---------------------------------------------------------------------------
+
+/// Sets the protocol share of swap fees. The factory sends this after a
+/// timelocked config update changes the share for this pool's kind;
+/// fees already accrued are unaffected.
+pub fn execute_set_protocol_fee_share(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    share: Decimal,
+) -> Result<Response, ContractError> {
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    if info.sender != pool_info.factory_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+    save_protocol_fee_share(deps.storage, share)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_protocol_fee_share")
+        .add_attribute("share", share.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
+/// Bounds-checks `share` against `MAX_PROTOCOL_FEE_SHARE` and stores it,
+/// keeping any fees already owed. Also used by both pools' instantiate
+/// to seed the share the factory passed in.
+pub fn save_protocol_fee_share(
+    storage: &mut dyn Storage,
+    share: Decimal,
+) -> Result<(), ContractError> {
+    if share > MAX_PROTOCOL_FEE_SHARE {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "protocol fee share must not exceed {}",
+            MAX_PROTOCOL_FEE_SHARE
+        ))));
+    }
+    let mut protocol = PROTOCOL_FEE_STATE.may_load(storage)?.unwrap_or_default();
+    protocol.share = share;
+    PROTOCOL_FEE_STATE.save(storage, &protocol)?;
+    Ok(())
+}
+
+/// Pays every owed protocol fee to the factory's live
+/// `bluechip_wallet_address` (falling back to the `COMMITFEEINFO`
+/// snapshot if the factory can't be queried, same as the emergency
+/// drain).
+pub fn execute_collect_protocol_fees(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    if info.sender != pool_info.factory_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_not_drained(deps.storage)?;
+
+    let mut protocol = PROTOCOL_FEE_STATE.may_load(deps.storage)?.unwrap_or_default();
+    let (amount_0, amount_1) = (protocol.owed_0, protocol.owed_1);
+    if amount_0.is_zero() && amount_1.is_zero() {
+        return Err(ContractError::NoProtocolFeesOwed {});
+    }
+    protocol.total_collected_0 = protocol.total_collected_0.checked_add(amount_0)?;
+    protocol.total_collected_1 = protocol.total_collected_1.checked_add(amount_1)?;
+    protocol.owed_0 = Uint128::zero();
+    protocol.owed_1 = Uint128::zero();
+    PROTOCOL_FEE_STATE.save(deps.storage, &protocol)?;
+
+    let recipient = match deps.querier.query_wasm_smart::<
+        pool_factory_interfaces::BluechipWalletResponse,
+    >(
+        pool_info.factory_addr.to_string(),
+        &pool_factory_interfaces::FactoryQueryMsg::BluechipWalletAddress {},
+    ) {
+        Ok(resp) => resp.address,
+        Err(_) => COMMITFEEINFO.load(deps.storage)?.bluechip_wallet_address,
+    };
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    for (index, amount) in [(0usize, amount_0), (1usize, amount_1)] {
+        if !amount.is_zero() {
+            messages.push(
+                TokenInfo {
+                    info: pool_info.pool_info.asset_infos[index].clone(),
+                    amount,
+                }
+                .into_msg(&deps.querier, recipient.clone())?,
+            );
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "collect_protocol_fees")
+        .add_attribute("recipient", recipient.to_string())
+        .add_attribute("amount_0", amount_0.to_string())
+        .add_attribute("amount_1", amount_1.to_string())
+        .add_attribute("pool_contract", env.contract.address.to_string())
+        .add_attribute("block_height", env.block.height.to_string()))
+}
+
 /// Two-phase emergency-withdraw dispatcher shared by `creator-pool` and
 /// `standard-pool`. Picks Phase 1 (initiate) or Phase 2 (core drain)
 /// based on whether `PENDING_EMERGENCY_WITHDRAW` is already set, and
@@ -31,6 +31,8 @@ pub enum ContractError {
     PoolPausedLowLiquidity {},
     #[error("No distribution or threshold locks and none are in progress")]
     NothingToRecover {},
+    #[error("No protocol fees are owed")]
+    NoProtocolFeesOwed {},
 
     #[error("Invalid threshold parameters: {msg}")]
     InvalidThresholdParams { msg: String },
@@ -41,6 +43,76 @@ pub enum ContractError {
     #[error("You can not swap until the threshold is crossed. You must commit to transact with this pool")]
     ShortOfThreshold {},
 
+    #[error("The raise ended at {deadline} without reaching its threshold; commits are closed")]
+    RaiseExpired { deadline: Timestamp },
+
+    #[error("Commit refunds are not open: the raise has no deadline, is still running, or crossed its threshold")]
+    RefundsNotOpen {},
+
+    #[error("No commit refund is owed to this address")]
+    NoCommitRefund {},
+
+    #[error("This address already has an active subscription")]
+    SubscriptionExists {},
+
+    #[error("No subscription found for this address")]
+    NoSubscription {},
+
+    #[error("Subscription renewal is not due until {next_due}")]
+    SubscriptionNotDue { next_due: Timestamp },
+
+    #[error("Subscription escrow {escrow} is below the {needed} bluechip this renewal needs")]
+    SubscriptionUnderfunded { needed: Uint128, escrow: Uint128 },
+
+    #[error("Invalid subscription: {reason}")]
+    InvalidSubscription { reason: String },
+
+    #[error("Commits are allowlist-only until {public_at}; wallet is not allowlisted")]
+    NotAllowlisted { public_at: Timestamp },
+
+    #[error("Commit allowlist cannot be changed: {reason}")]
+    CommitAllowlistLocked { reason: String },
+
+    #[error("Invalid sponsorship: {reason}")]
+    InvalidSponsorship { reason: String },
+
+    #[error("No sponsorship found for this address")]
+    NoSponsorship {},
+
+    #[error("Invalid revenue split: {reason}")]
+    InvalidRevenueSplit { reason: String },
+
+    #[error("Invalid token marketing update: {reason}")]
+    InvalidTokenMarketing { reason: String },
+
+    #[error("Invalid creator wallet change: {reason}")]
+    InvalidCreatorWallet { reason: String },
+
+    #[error("No creator wallet change is pending")]
+    NoPendingCreatorWallet {},
+
+    #[error("Creator wallet change is in its veto window until {accept_after}")]
+    CreatorWalletVetoWindow { accept_after: Timestamp },
+
+    #[error("No revenue split proposal is pending")]
+    NoPendingRevenueSplit {},
+
+    #[error("Revenue split proposal cannot be applied before {effective_after}")]
+    RevenueSplitTimelocked { effective_after: Timestamp },
+
+    #[error("Sponsorship escrow is locked until {expires_at}, the threshold, or the raise deadline")]
+    SponsorshipNotReclaimable { expires_at: Timestamp },
+
+    #[error("Unknown subscription tier \"{tier}\"")]
+    UnknownSubscriptionTier { tier: String },
+
+    #[error("Commit worth {got} USD is below the {price} USD price of tier \"{tier}\"")]
+    TierUnderpaid {
+        tier: String,
+        got: Uint128,
+        price: Uint128,
+    },
+
     #[error("You are trying to commit too frequently.")]
     TooFrequentCommits { wait_time: u64 },
 
@@ -69,6 +141,36 @@ pub enum ContractError {
         max: Uint128,
     },
 
+    #[error("Invalid per-wallet commit cap for {field}: {reason}")]
+    InvalidCommitWalletCap {
+        field: &'static str,
+        reason: String,
+    },
+
+    #[error("Wallet has reached its pre-threshold commit cap of ${cap} USD")]
+    CommitCapReached { cap: Uint128 },
+
+    #[error("Invalid referral fee share {got} (must be <= {max})")]
+    InvalidReferralFeeShare { got: Decimal, max: Decimal },
+
+    #[error("A commit cannot name its payer or beneficiary as referrer")]
+    SelfReferral {},
+
+    #[error("No referral rewards to claim")]
+    NoReferralRewards {},
+
+    #[error("This pool has no creator reward vesting schedule")]
+    NoCreatorVesting {},
+
+    #[error("No vested creator reward to claim")]
+    NoVestedCreatorReward {},
+
+    #[error("Commit rewards are not claimable: the pool uses push distribution or has not crossed its threshold")]
+    PullDistributionInactive {},
+
+    #[error("No unclaimed commit reward for this address")]
+    NoCommitReward {},
+
     #[error("belief_price cannot be zero")]
     InvalidBeliefPrice {},
 
@@ -130,6 +232,12 @@ pub enum ContractError {
     #[error("Post-threshold cooldown active: trades resume at block {until_block}")]
     PostThresholdCooldownActive { until_block: u64 },
 
+    #[error("Launch window active: offer exceeds the maximum of {max_offer} until block {until_block}")]
+    LaunchSwapTooLarge { max_offer: Uint128, until_block: u64 },
+
+    #[error("Launch window active: wallet buy cap reached ({remaining} remaining) until block {until_block}")]
+    LaunchWalletCapExceeded { remaining: Uint128, until_block: u64 },
+
     #[error("Cannot remove locked liquidity (this position has {locked} locked)")]
     LockedLiquidity { locked: Uint128 },
 
@@ -0,0 +1,199 @@
+//! Bounded on-chain trade history.
+//!
+//! `PoolAnalytics` only carries lifetime counters; this module keeps
+//! the time-bucketed view dashboards need without an indexer. Every
+//! swap-shaped trade (plain swaps in `pool_core::swap`, post-threshold
+//! commits and the threshold-crosser's excess swap in creator-pool)
+//! calls [`record_trade`] once, which folds the trade into the current
+//! bucket of every `VolumeGranularity`.
+//!
+//! Storage is a fixed-size ring per granularity: slot
+//! `bucket_index % capacity` is overwritten in place when the ring laps,
+//! so a pool's history footprint never grows past
+//! `HOURLY_VOLUME_BUCKETS + DAILY_VOLUME_BUCKETS` entries.
+
+use crate::state::{VolumeBucket, VolumeGranularity};
+use cosmwasm_std::{StdResult, Storage, Uint128};
+
+/// Amounts a single trade contributes to the history. Volumes follow
+/// the `PoolAnalytics.total_volume_*` convention (offer amount on the
+/// offer side, net return on the ask side); fees are the commission
+/// in the asset it was charged in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TradeRecord {
+    pub volume_0: Uint128,
+    pub volume_1: Uint128,
+    pub fees_0: Uint128,
+    pub fees_1: Uint128,
+}
+
+impl TradeRecord {
+    /// Build a record from the swap handler's view of a trade.
+    /// `offer_index == 0` means token0 was offered, so the return and
+    /// the commission are both token1-denominated.
+    pub fn from_swap(
+        offer_index: usize,
+        offer_amount: Uint128,
+        return_amount: Uint128,
+        commission_amount: Uint128,
+    ) -> Self {
+        if offer_index == 0 {
+            Self {
+                volume_0: offer_amount,
+                volume_1: return_amount,
+                fees_0: Uint128::zero(),
+                fees_1: commission_amount,
+            }
+        } else {
+            Self {
+                volume_0: return_amount,
+                volume_1: offer_amount,
+                fees_0: commission_amount,
+                fees_1: Uint128::zero(),
+            }
+        }
+    }
+}
+
+/// Fold `trade` into the current bucket of every granularity. A slot
+/// still holding a previous lap's window is reset before the trade is
+/// added. Counters saturate rather than error — history is
+/// observability data and must never be the reason a swap reverts.
+pub fn record_trade(storage: &mut dyn Storage, now: u64, trade: &TradeRecord) -> StdResult<()> {
+    for granularity in VolumeGranularity::ALL {
+        let width = granularity.bucket_seconds();
+        let index = now / width;
+        let slot = index % granularity.capacity();
+        let start = index * width;
+        let history = granularity.history();
+
+        let mut bucket = match history.may_load(storage, slot)? {
+            Some(bucket) if bucket.start == start => bucket,
+            _ => VolumeBucket {
+                start,
+                ..VolumeBucket::default()
+            },
+        };
+        bucket.volume_0 = bucket.volume_0.saturating_add(trade.volume_0);
+        bucket.volume_1 = bucket.volume_1.saturating_add(trade.volume_1);
+        bucket.fees_0 = bucket.fees_0.saturating_add(trade.fees_0);
+        bucket.fees_1 = bucket.fees_1.saturating_add(trade.fees_1);
+        bucket.trade_count = bucket.trade_count.saturating_add(1);
+        history.save(storage, slot, &bucket)?;
+    }
+    Ok(())
+}
+
+/// Live buckets for `granularity`, newest first, walking back at most
+/// `limit` windows from the one containing `now`. Windows with no
+/// recorded trades (never written, or overwritten by a later lap) are
+/// skipped rather than returned as zero rows, so a quiet pool yields a
+/// short list.
+pub fn load_recent_buckets(
+    storage: &dyn Storage,
+    granularity: VolumeGranularity,
+    now: u64,
+    limit: u64,
+) -> StdResult<Vec<VolumeBucket>> {
+    let width = granularity.bucket_seconds();
+    let capacity = granularity.capacity();
+    let history = granularity.history();
+    let current = now / width;
+
+    let mut buckets = Vec::new();
+    for back in 0..limit.min(capacity) {
+        let Some(index) = current.checked_sub(back) else {
+            break;
+        };
+        if let Some(bucket) = history.may_load(storage, index % capacity)? {
+            if bucket.start == index * width {
+                buckets.push(bucket);
+            }
+        }
+    }
+    Ok(buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{DAILY_VOLUME_BUCKETS, HOURLY_VOLUME_BUCKETS, SECONDS_PER_HOUR};
+    use cosmwasm_std::testing::MockStorage;
+
+    fn trade(volume_0: u128, fees_1: u128) -> TradeRecord {
+        TradeRecord {
+            volume_0: Uint128::new(volume_0),
+            volume_1: Uint128::zero(),
+            fees_0: Uint128::zero(),
+            fees_1: Uint128::new(fees_1),
+        }
+    }
+
+    #[test]
+    fn record_trade_accumulates_within_bucket() {
+        let mut storage = MockStorage::new();
+        let now = 10 * SECONDS_PER_HOUR + 5;
+        record_trade(&mut storage, now, &trade(100, 3)).unwrap();
+        record_trade(&mut storage, now + 60, &trade(50, 1)).unwrap();
+
+        let hourly =
+            load_recent_buckets(&storage, VolumeGranularity::Hourly, now + 60, 48).unwrap();
+        assert_eq!(hourly.len(), 1);
+        assert_eq!(hourly[0].start, 10 * SECONDS_PER_HOUR);
+        assert_eq!(hourly[0].volume_0, Uint128::new(150));
+        assert_eq!(hourly[0].fees_1, Uint128::new(4));
+        assert_eq!(hourly[0].trade_count, 2);
+
+        let daily = load_recent_buckets(&storage, VolumeGranularity::Daily, now, 90).unwrap();
+        assert_eq!(daily.len(), 1);
+        assert_eq!(daily[0].trade_count, 2);
+    }
+
+    #[test]
+    fn record_trade_resets_slot_after_ring_laps() {
+        let mut storage = MockStorage::new();
+        let first = 3 * SECONDS_PER_HOUR;
+        let lapped = first + HOURLY_VOLUME_BUCKETS * SECONDS_PER_HOUR;
+        record_trade(&mut storage, first, &trade(100, 0)).unwrap();
+        record_trade(&mut storage, lapped, &trade(7, 0)).unwrap();
+
+        let hourly = load_recent_buckets(
+            &storage,
+            VolumeGranularity::Hourly,
+            lapped,
+            HOURLY_VOLUME_BUCKETS,
+        )
+        .unwrap();
+        // The first window shared a slot with the lapped one and was
+        // overwritten, not summed into it.
+        assert_eq!(hourly.len(), 1);
+        assert_eq!(hourly[0].start, lapped);
+        assert_eq!(hourly[0].volume_0, Uint128::new(7));
+        assert_eq!(hourly[0].trade_count, 1);
+    }
+
+    #[test]
+    fn load_recent_buckets_is_newest_first_and_capped() {
+        let mut storage = MockStorage::new();
+        let base = 100 * SECONDS_PER_HOUR;
+        for h in 0..5u64 {
+            record_trade(&mut storage, base + h * SECONDS_PER_HOUR, &trade(1, 0)).unwrap();
+        }
+        let now = base + 4 * SECONDS_PER_HOUR;
+        let hourly = load_recent_buckets(&storage, VolumeGranularity::Hourly, now, 3).unwrap();
+        let starts: Vec<u64> = hourly.iter().map(|b| b.start).collect();
+        assert_eq!(
+            starts,
+            vec![
+                base + 4 * SECONDS_PER_HOUR,
+                base + 3 * SECONDS_PER_HOUR,
+                base + 2 * SECONDS_PER_HOUR
+            ]
+        );
+
+        // A limit beyond capacity is clamped, never walks past the ring.
+        let daily =
+            load_recent_buckets(&storage, VolumeGranularity::Daily, now, 10_000).unwrap();
+        assert!(daily.len() as u64 <= DAILY_VOLUME_BUCKETS);
+    }
+}
@@ -12,6 +12,8 @@
 //! - Liquidity positions: deposit, add, remove (partial / full /
 //! percentage), collect fees, NFT ownership sync, fee-size
 //! multiplier clipping.
+//! - Trade history: bounded hourly / daily volume + fee buckets fed
+//! by the swap paths.
 //! - Asset handling: pair-shape-agnostic transfer/collect helpers for
 //! Native/CW20/CW20-CW20/Native-Native pools.
 //! - Admin ops shared by both pool kinds: pause, unpause, emergency
@@ -34,6 +36,7 @@ pub mod error;
 pub mod state;
 pub mod asset;
 pub mod swap;
+pub mod history;
 pub mod msg;
 pub mod generic;
 pub mod liquidity_helpers;
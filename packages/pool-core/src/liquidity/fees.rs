@@ -9,19 +9,25 @@
 //! of a single deposit's accrued fees the LP can take home if the
 //! position's `fee_size_multiplier` has been reduced; see
 //! `liquidity_helpers::calc_capped_fees_with_clip` for the math.
+//!
+//! With an `output_asset`, the other side's payout is swapped through
+//! the pool's own reserves (`swap::swap_fees_through_reserves`, normal
+//! swap commission) so the LP receives a single asset, subject to
+//! `min_output`.
 
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Timestamp, Uint128};
 
+use crate::asset::TokenType;
 use crate::error::ContractError;
 use crate::generic::{enforce_transaction_deadline, with_reentrancy_guard};
 use crate::liquidity_helpers::{
-    build_fee_transfer_msgs, calc_capped_fees_with_clip, sync_position_on_transfer,
-    verify_position_ownership,
+    build_fee_transfer_msgs, calc_capped_fees_with_clip, check_slippage,
+    sync_position_on_transfer, verify_position_ownership,
 };
 use crate::state::{
     CREATOR_FEE_POT, LIQUIDITY_POSITIONS, POOL_FEE_STATE, POOL_INFO, POOL_STATE,
 };
-use crate::swap::update_price_accumulator;
+use crate::swap::{swap_fees_through_reserves, update_price_accumulator};
 
 pub fn execute_collect_fees(
     deps: DepsMut,
@@ -29,6 +35,8 @@ pub fn execute_collect_fees(
     info: MessageInfo,
     position_id: String,
     transaction_deadline: Option<Timestamp>,
+    output_asset: Option<TokenType>,
+    min_output: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     // Optional caller-supplied deadline. Fee math grows monotonically, so
     // a late collect can only return *more* than the caller anticipated —
@@ -42,7 +50,7 @@ pub fn execute_collect_fees(
     // creator token could otherwise re-enter the pool before the response
     // commits and double-collect fees against a stale fee_growth checkpoint.
     with_reentrancy_guard(deps, move |deps| {
-        execute_collect_fees_inner(deps, env, info, position_id)
+        execute_collect_fees_inner(deps, env, info, position_id, output_asset, min_output)
     })
 }
 
@@ -51,9 +59,21 @@ fn execute_collect_fees_inner(
     env: Env,
     info: MessageInfo,
     position_id: String,
+    output_asset: Option<TokenType>,
+    min_output: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let mut pool_fee_state = POOL_FEE_STATE.load(deps.storage)?;
     let pool_info = POOL_INFO.load(deps.storage)?;
+    let output_index = output_asset
+        .map(|asset| {
+            pool_info
+                .pool_info
+                .asset_infos
+                .iter()
+                .position(|t| t.equal(&asset))
+                .ok_or(ContractError::AssetMismatch {})
+        })
+        .transpose()?;
     let mut pool_state = POOL_STATE.load(deps.storage)?;
     verify_position_ownership(
         deps.as_ref(),
@@ -98,11 +118,48 @@ fn execute_collect_fees_inner(
     pot.amount_1 = pot.amount_1.checked_add(clipped_1)?;
     CREATOR_FEE_POT.save(deps.storage, &pot)?;
 
+    // Convert the other side's payout into the requested asset. Runs
+    // after the position checkpoint is taken, so the commission this
+    // conversion pays is shared by every LP from the next collect on.
+    let (mut payout_0, mut payout_1) = (fees_owed_0, fees_owed_1);
+    let mut converted = Uint128::zero();
+    let mut conversion_commission = Uint128::zero();
+    if let Some(output_index) = output_index {
+        let offer_index = 1 - output_index;
+        let offer_amount = if offer_index == 0 { payout_0 } else { payout_1 };
+        if !offer_amount.is_zero() {
+            let outcome = swap_fees_through_reserves(
+                deps.storage,
+                &env,
+                &info.sender,
+                &mut pool_state,
+                &mut pool_fee_state,
+                offer_index,
+                offer_amount,
+            )?;
+            converted = offer_amount;
+            conversion_commission = outcome.commission_amount;
+            if output_index == 0 {
+                payout_0 = payout_0.checked_add(outcome.return_amount)?;
+                payout_1 = Uint128::zero();
+            } else {
+                payout_1 = payout_1.checked_add(outcome.return_amount)?;
+                payout_0 = Uint128::zero();
+            }
+        }
+        let total_out = if output_index == 0 { payout_0 } else { payout_1 };
+        check_slippage(
+            total_out,
+            min_output,
+            &pool_info.pool_info.asset_infos[output_index].to_string(),
+        )?;
+    }
+
     LIQUIDITY_POSITIONS.save(deps.storage, &position_id, &liquidity_position)?;
     POOL_STATE.save(deps.storage, &pool_state)?;
     POOL_FEE_STATE.save(deps.storage, &pool_fee_state)?;
 
-    let fee_msgs = build_fee_transfer_msgs(&pool_info, &info.sender, fees_owed_0, fees_owed_1)?;
+    let fee_msgs = build_fee_transfer_msgs(&pool_info, &info.sender, payout_0, payout_1)?;
 
     Ok(Response::new()
         .add_messages(fee_msgs)
@@ -112,6 +169,10 @@ fn execute_collect_fees_inner(
             ("collector", info.sender.to_string()),
             ("fees_0", fees_owed_0.to_string()),
             ("fees_1", fees_owed_1.to_string()),
+            ("payout_0", payout_0.to_string()),
+            ("payout_1", payout_1.to_string()),
+            ("converted_amount", converted.to_string()),
+            ("conversion_commission", conversion_commission.to_string()),
             ("clipped_to_creator_pot_0", clipped_0.to_string()),
             ("clipped_to_creator_pot_1", clipped_1.to_string()),
             ("fee_reserve_0_after", pool_fee_state.fee_reserve_0.to_string()),
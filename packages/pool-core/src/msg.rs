@@ -1,7 +1,8 @@
 //! Shared wire-format types used by both creator-pool and standard-pool.
 //!
 //! Split boundary:
-//! - Shared (this module): CommitFeeInfo, PoolConfigUpdate, Cw20HookMsg,
+//! - Shared (this module): CommitFeeInfo (re-exported from
+//! pool-factory-interfaces), PoolConfigUpdate, Cw20HookMsg,
 //! CommitStatus, and every response struct returned by a query
 //! handler that lives in `pool_core::query`.
 //! - Per-contract (in creator-pool / standard-pool): ExecuteMsg,
@@ -14,17 +15,13 @@
 //! byte-for-byte identical to the creator-pool pre-split build.
 
 use crate::asset::TokenInfo;
-use crate::state::PoolAnalytics;
+use crate::state::{Candle, CandleInterval, PoolAnalytics, VolumeBucket, VolumeGranularity};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
 
-#[cw_serde]
-pub struct CommitFeeInfo {
-    pub bluechip_wallet_address: Addr,
-    pub creator_wallet_address: Addr,
-    pub commit_fee_bluechip: Decimal,
-    pub commit_fee_creator: Decimal,
-}
+// The factory reads a pool's fee info during migrate, so these two live
+// in the interfaces crate alongside the other factory-facing types.
+pub use pool_factory_interfaces::{CommitFeeInfo, FeeInfoResponse};
 
 #[cw_serde]
 #[derive(Default)]
@@ -49,6 +46,22 @@ pub struct PoolConfigUpdate {
     /// `min_commit_usd_pre_threshold` above.
     #[serde(default)]
     pub min_commit_usd_post_threshold: Option<Uint128>,
+    /// Creator-pool only: the share of `commit_fee_creator` paid to a
+    /// commit's `referrer`. Zero disables referral rewards. Same
+    /// factory-propose / pool-apply bound checks as the commit floors.
+    #[serde(default)]
+    pub referral_fee_share: Option<Decimal>,
+    /// Creator-pool only: the most pre-threshold USD (6 decimals) one
+    /// wallet may have credited to the commit ledger. `Some(0)` lifts
+    /// the cap. A non-zero cap must be at least the pool's
+    /// `min_commit_usd_pre_threshold`.
+    #[serde(default)]
+    pub max_commit_usd_per_wallet: Option<Uint128>,
+    /// Creator-pool only: the largest share of the USD threshold one
+    /// wallet may commit pre-threshold. `Some(0)` lifts the cap; at
+    /// most 100%. When both caps are set the tighter one applies.
+    #[serde(default)]
+    pub max_commit_share_per_wallet: Option<Decimal>,
     // `usd_payment_tolerance_bps` removed — see `PoolSpecs` doc-comment
     // in `pool-core::state` for rationale.
     //
@@ -98,6 +111,12 @@ pub struct SimulationResponse {
     pub return_amount: Uint128,
     pub spread_amount: Uint128,
     pub commission_amount: Uint128,
+    /// Rate `commission_amount` was charged at: the LP fee, or the
+    /// decaying launch fee while one is active.
+    pub commission_rate: Decimal,
+    /// Portion of `commission_amount` above the LP fee, routed to the
+    /// creator fee pot.
+    pub launch_fee_amount: Uint128,
 }
 
 #[cw_serde]
@@ -105,6 +124,8 @@ pub struct ReverseSimulationResponse {
     pub offer_amount: Uint128,
     pub spread_amount: Uint128,
     pub commission_amount: Uint128,
+    pub commission_rate: Decimal,
+    pub launch_fee_amount: Uint128,
 }
 
 #[cw_serde]
@@ -114,11 +135,6 @@ pub struct CumulativePricesResponse {
     pub price1_cumulative_last: Uint128,
 }
 
-#[cw_serde]
-pub struct FeeInfoResponse {
-    pub fee_info: CommitFeeInfo,
-}
-
 #[cw_serde]
 pub struct PoolStateResponse {
     pub nft_ownership_accepted: bool,
@@ -136,6 +152,19 @@ pub struct PoolFeeStateResponse {
     pub total_fees_collected_1: Uint128,
 }
 
+/// Protocol fee switch state. `owed_*` is accrued and awaiting
+/// collection to the bluechip wallet; the totals are lifetime counters.
+#[cw_serde]
+pub struct ProtocolFeesResponse {
+    pub share: Decimal,
+    pub owed_0: Uint128,
+    pub owed_1: Uint128,
+    pub total_accrued_0: Uint128,
+    pub total_accrued_1: Uint128,
+    pub total_collected_0: Uint128,
+    pub total_collected_1: Uint128,
+}
+
 #[cw_serde]
 pub struct PositionResponse {
     pub position_id: String,
@@ -159,6 +188,12 @@ pub struct PoolInfoResponse {
     pub pool_state: PoolStateResponse,
     pub fee_state: PoolFeeStateResponse,
     pub total_positions: u64,
+    /// Commit pools only: the per-wallet pre-threshold caps from
+    /// `PoolConfigUpdate`. Always `None` on standard pools.
+    #[serde(default)]
+    pub max_commit_usd_per_wallet: Option<Uint128>,
+    #[serde(default)]
+    pub max_commit_share_per_wallet: Option<Decimal>,
 }
 
 #[cw_serde]
@@ -174,4 +209,25 @@ pub struct PoolAnalyticsResponse {
     pub total_usd_raised: Uint128,
     pub total_bluechip_raised: Uint128,
     pub total_positions: u64,
+    /// Annualised LP fee yield over the trailing `FEE_APR_WINDOW_HOURS`
+    /// of hourly history, with token1 fees valued at the current spot
+    /// price and measured against total reserves. Zero on an empty pool.
+    pub fee_apr: Decimal,
+}
+
+#[cw_serde]
+pub struct VolumeHistoryResponse {
+    pub granularity: VolumeGranularity,
+    pub bucket_seconds: u64,
+    /// Newest first; windows with no trades are omitted.
+    pub buckets: Vec<VolumeBucket>,
+}
+
+#[cw_serde]
+pub struct CandlesResponse {
+    pub interval: CandleInterval,
+    pub interval_seconds: u64,
+    /// Oldest first. Windows without trades have no candle; charting
+    /// clients carry the previous `close` forward.
+    pub candles: Vec<Candle>,
 }
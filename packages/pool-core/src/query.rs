@@ -10,20 +10,28 @@
 //! wrapper supplies the commit-adjacent fields (`threshold_status`,
 //! `total_usd_raised`, `total_bluechip_raised`). Creator-pool loads
 //! commit ledger state; standard-pool passes `FullyCommitted` and zero.
+//! The fee APR it reports is derived from the hourly trade history in
+//! `crate::history`.
 
 use crate::asset::TokenInfo;
+use crate::history::load_recent_buckets;
 use crate::liquidity_helpers::calculate_unclaimed_fees;
 use crate::msg::{
-    CommitStatus, ConfigResponse, CumulativePricesResponse, FeeInfoResponse, PoolAnalyticsResponse,
-    PoolFeeStateResponse, PoolInfoResponse, PoolStateResponse, PositionResponse, PositionsResponse,
-    ReverseSimulationResponse, SimulationResponse,
+    CandlesResponse, CommitStatus, ConfigResponse, CumulativePricesResponse, FeeInfoResponse,
+    PoolAnalyticsResponse, PoolFeeStateResponse, PoolInfoResponse, PoolStateResponse,
+    PositionResponse, PositionsResponse, ProtocolFeesResponse, ReverseSimulationResponse,
+    SimulationResponse, VolumeHistoryResponse,
 };
 use crate::state::{
-    PoolDetails, Position, PoolFeeState, COMMITFEEINFO, IS_THRESHOLD_HIT, LIQUIDITY_POSITIONS,
-    NEXT_POSITION_ID, OWNER_POSITIONS, POOL_ANALYTICS, POOL_FEE_STATE, POOL_INFO, POOL_PAUSED,
-    POOL_SPECS, POOL_STATE,
+    CandleInterval, PoolDetails, PoolFeeState, Position, VolumeGranularity,
+    CANDLES_QUERY_DEFAULT_LIMIT, CANDLES_QUERY_MAX_LIMIT, COMMITFEEINFO, FEE_APR_WINDOW_HOURS,
+    IS_THRESHOLD_HIT, LIQUIDITY_POSITIONS, NEXT_POSITION_ID, OWNER_POSITIONS, POOL_ANALYTICS,
+    POOL_FEE_STATE, POOL_INFO, POOL_PAUSED, POOL_SPECS, POOL_STATE, PROTOCOL_FEE_STATE,
+};
+use crate::swap::{
+    compute_offer_amount, compute_swap, effective_commission_rate, split_launch_commission,
+    update_price_accumulator,
 };
-use crate::swap::{compute_offer_amount, compute_swap, update_price_accumulator};
 use cosmwasm_std::{
     to_json_binary, Binary, Decimal, Deps, Env, Order, StdError, StdResult, Uint128,
 };
@@ -42,7 +50,11 @@ pub fn query_pair_info(deps: Deps) -> StdResult<PoolDetails> {
     Ok(pool_info.pool_info)
 }
 
-pub fn query_simulation(deps: Deps, offer_asset: TokenInfo) -> StdResult<SimulationResponse> {
+pub fn query_simulation(
+    deps: Deps,
+    env: Env,
+    offer_asset: TokenInfo,
+) -> StdResult<SimulationResponse> {
     let pool_info = POOL_INFO.load(deps.storage)?;
     let pool_specs = POOL_SPECS.load(deps.storage)?;
     let contract_addr = pool_info.pool_info.contract_addr.clone();
@@ -65,22 +77,29 @@ pub fn query_simulation(deps: Deps, offer_asset: TokenInfo) -> StdResult<Simulat
         ));
     }
 
+    let commission_rate =
+        effective_commission_rate(deps.storage, pool_specs.lp_fee, env.block.height)?;
     let (return_amount, spread_amount, commission_amount) = compute_swap(
         offer_pool.amount,
         ask_pool.amount,
         offer_asset.amount,
-        pool_specs.lp_fee,
+        commission_rate,
     )?;
+    let (_, launch_fee_amount) =
+        split_launch_commission(commission_amount, pool_specs.lp_fee, commission_rate);
 
     Ok(SimulationResponse {
         return_amount,
         spread_amount,
         commission_amount,
+        commission_rate,
+        launch_fee_amount,
     })
 }
 
 pub fn query_reverse_simulation(
     deps: Deps,
+    env: Env,
     ask_asset: TokenInfo,
 ) -> StdResult<ReverseSimulationResponse> {
     let pool_info = POOL_INFO.load(deps.storage)?;
@@ -105,17 +124,23 @@ pub fn query_reverse_simulation(
         ));
     }
 
+    let commission_rate =
+        effective_commission_rate(deps.storage, pool_specs.lp_fee, env.block.height)?;
     let (offer_amount, spread_amount, commission_amount) = compute_offer_amount(
         offer_pool.amount,
         ask_pool.amount,
         ask_asset.amount,
-        pool_specs.lp_fee,
+        commission_rate,
     )?;
+    let (_, launch_fee_amount) =
+        split_launch_commission(commission_amount, pool_specs.lp_fee, commission_rate);
 
     Ok(ReverseSimulationResponse {
         offer_amount,
         spread_amount,
         commission_amount,
+        commission_rate,
+        launch_fee_amount,
     })
 }
 
@@ -185,6 +210,19 @@ pub fn query_fee_state(deps: Deps) -> StdResult<PoolFeeStateResponse> {
     })
 }
 
+pub fn query_protocol_fees(deps: Deps) -> StdResult<ProtocolFeesResponse> {
+    let protocol = PROTOCOL_FEE_STATE.may_load(deps.storage)?.unwrap_or_default();
+    Ok(ProtocolFeesResponse {
+        share: protocol.share,
+        owed_0: protocol.owed_0,
+        owed_1: protocol.owed_1,
+        total_accrued_0: protocol.total_accrued_0,
+        total_accrued_1: protocol.total_accrued_1,
+        total_collected_0: protocol.total_collected_0,
+        total_collected_1: protocol.total_collected_1,
+    })
+}
+
 /// Build a `PositionResponse` from a pre-loaded `Position` and a pre-loaded
 /// `PoolFeeState`. Lets list queries load `POOL_FEE_STATE` once and reuse it
 /// across every row instead of reloading per-position.
@@ -297,6 +335,8 @@ pub fn query_pool_info(deps: Deps) -> StdResult<PoolInfoResponse> {
             total_fees_collected_1: pool_fee_state.total_fees_collected_1,
         },
         total_positions: next_position_id,
+        max_commit_usd_per_wallet: None,
+        max_commit_share_per_wallet: None,
     })
 }
 
@@ -306,6 +346,7 @@ pub fn query_pool_info(deps: Deps) -> StdResult<PoolInfoResponse> {
 /// from whatever state it has access to.
 pub fn query_analytics_core(
     deps: Deps,
+    env: Env,
     threshold_status: CommitStatus,
     total_usd_raised: Uint128,
     total_bluechip_raised: Uint128,
@@ -326,6 +367,8 @@ pub fn query_analytics_core(
         "0".to_string()
     };
 
+    let fee_apr = trailing_fee_apr(deps, &env, pool_state.reserve0, pool_state.reserve1)?;
+
     Ok(PoolAnalyticsResponse {
         analytics,
         current_price_0_to_1,
@@ -338,6 +381,83 @@ pub fn query_analytics_core(
         total_usd_raised,
         total_bluechip_raised,
         total_positions: next_position_id,
+        fee_apr,
+    })
+}
+
+/// Annualise the fees recorded over the last `FEE_APR_WINDOW_HOURS`.
+/// Everything is priced in token0: token1 fees convert at the spot
+/// reserve ratio and TVL is `2 * reserve0` (both sides are equal in
+/// value at spot). Returns zero when either reserve is empty.
+fn trailing_fee_apr(
+    deps: Deps,
+    env: &Env,
+    reserve0: Uint128,
+    reserve1: Uint128,
+) -> StdResult<Decimal> {
+    if reserve0.is_zero() || reserve1.is_zero() {
+        return Ok(Decimal::zero());
+    }
+    let buckets = load_recent_buckets(
+        deps.storage,
+        VolumeGranularity::Hourly,
+        env.block.time.seconds(),
+        FEE_APR_WINDOW_HOURS,
+    )?;
+    let (fees_0, fees_1) = buckets.iter().fold(
+        (Uint128::zero(), Uint128::zero()),
+        |(f0, f1), b| (f0.saturating_add(b.fees_0), f1.saturating_add(b.fees_1)),
+    );
+    let fees_1_in_0 = fees_1.multiply_ratio(reserve0, reserve1);
+    let window_fees = fees_0.saturating_add(fees_1_in_0);
+    let tvl = reserve0.saturating_mul(Uint128::new(2));
+    let periods_per_year = Uint128::from(365 * 24 / FEE_APR_WINDOW_HOURS);
+
+    Decimal::checked_from_ratio(window_fees.saturating_mul(periods_per_year), tvl)
+        .map_err(|e| StdError::generic_err(format!("Fee APR overflow: {}", e)))
+}
+
+pub fn query_volume_history(
+    deps: Deps,
+    env: Env,
+    granularity: VolumeGranularity,
+    limit: Option<u32>,
+) -> StdResult<VolumeHistoryResponse> {
+    let limit = limit.map_or(granularity.capacity(), u64::from);
+    let buckets = load_recent_buckets(deps.storage, granularity, env.block.time.seconds(), limit)?;
+    Ok(VolumeHistoryResponse {
+        granularity,
+        bucket_seconds: granularity.bucket_seconds(),
+        buckets,
+    })
+}
+
+/// Page through `interval`'s candles in ascending start order.
+/// `start_after` is an exclusive window start (unix seconds).
+pub fn query_candles(
+    deps: Deps,
+    interval: CandleInterval,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<CandlesResponse> {
+    let limit = limit
+        .unwrap_or(CANDLES_QUERY_DEFAULT_LIMIT)
+        .min(CANDLES_QUERY_MAX_LIMIT) as usize;
+    let candles = interval
+        .candles()
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, candle)| candle))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(CandlesResponse {
+        interval,
+        interval_seconds: interval.seconds(),
+        candles,
     })
 }
 
@@ -375,5 +495,6 @@ pub fn query_for_factory(deps: Deps, _env: Env, msg: PoolQueryMsg) -> StdResult<
             })
         }
         PoolQueryMsg::IsPaused {} => to_json_binary(&query_is_paused(deps)?),
+        PoolQueryMsg::FeeInfo {} => to_json_binary(&query_fee_info(deps)?),
     }
 }
@@ -31,6 +31,22 @@ pub struct CreatorFeePot {
     pub amount_1: Uint128,
 }
 
+/// Protocol share of swap fees. `share` is the fraction of each swap's
+/// LP commission diverted away from fee growth; the diverted amounts
+/// sit in `owed_*` until the factory collects them to the bluechip
+/// wallet. The `total_*` counters only ever grow.
+#[cw_serde]
+#[derive(Default)]
+pub struct ProtocolFeeState {
+    pub share: Decimal,
+    pub owed_0: Uint128,
+    pub owed_1: Uint128,
+    pub total_accrued_0: Uint128,
+    pub total_accrued_1: Uint128,
+    pub total_collected_0: Uint128,
+    pub total_collected_1: Uint128,
+}
+
 impl Default for CreatorFeePot {
     fn default() -> Self {
         Self {
@@ -75,6 +91,169 @@ impl Default for PoolAnalytics {
     }
 }
 
+/// One time bucket of swap activity in the rolling volume history.
+///
+/// `start` is the bucket's opening block time (seconds, aligned to the
+/// granularity) and doubles as the ring-slot tag: the history is stored
+/// as a fixed number of slots indexed by `bucket_index % capacity`, so a
+/// slot whose `start` doesn't match the window being read or written
+/// holds a stale window from a previous lap and is treated as empty.
+/// Fees are denominated in the asset they were charged in — a swap
+/// offering token0 pays its commission in token1 and vice versa,
+/// matching `update_pool_fee_growth`.
+#[cw_serde]
+#[derive(Default)]
+pub struct VolumeBucket {
+    pub start: u64,
+    pub volume_0: Uint128,
+    pub volume_1: Uint128,
+    pub fees_0: Uint128,
+    pub fees_1: Uint128,
+    pub trade_count: u64,
+}
+
+/// Bucket width selector for `VolumeHistory` queries and the
+/// swap-path recorder. Each granularity owns its own bounded ring of
+/// `VolumeBucket`s (see `HOURLY_VOLUME_HISTORY` / `DAILY_VOLUME_HISTORY`).
+#[cw_serde]
+#[derive(Copy)]
+pub enum VolumeGranularity {
+    Hourly,
+    Daily,
+}
+
+impl VolumeGranularity {
+    pub const ALL: [VolumeGranularity; 2] = [VolumeGranularity::Hourly, VolumeGranularity::Daily];
+
+    /// Width of one bucket in seconds.
+    pub fn bucket_seconds(&self) -> u64 {
+        match self {
+            VolumeGranularity::Hourly => SECONDS_PER_HOUR,
+            VolumeGranularity::Daily => SECONDS_PER_DAY,
+        }
+    }
+
+    /// Number of ring slots retained for this granularity.
+    pub fn capacity(&self) -> u64 {
+        match self {
+            VolumeGranularity::Hourly => HOURLY_VOLUME_BUCKETS,
+            VolumeGranularity::Daily => DAILY_VOLUME_BUCKETS,
+        }
+    }
+
+    /// Ring storage backing this granularity.
+    pub fn history(&self) -> Map<u64, VolumeBucket> {
+        match self {
+            VolumeGranularity::Hourly => HOURLY_VOLUME_HISTORY,
+            VolumeGranularity::Daily => DAILY_VOLUME_HISTORY,
+        }
+    }
+}
+
+/// One OHLC candle. Prices are token1 quoted in token0 (bluechip per
+/// creator token on a creator pool), scaled by
+/// `swap::PRICE_ACCUMULATOR_SCALE` and read from reserves, so `open`
+/// is the spot price before the window's first trade and `close` the
+/// spot price after its last. Volumes follow the
+/// `PoolAnalytics.total_volume_*` convention.
+#[cw_serde]
+pub struct Candle {
+    pub start: u64,
+    pub open: Uint128,
+    pub high: Uint128,
+    pub low: Uint128,
+    pub close: Uint128,
+    pub volume_0: Uint128,
+    pub volume_1: Uint128,
+}
+
+/// Candle width selector for `Candles` queries and the swap-path
+/// recorder. Each interval keeps its own `Map<start, Candle>` and
+/// prunes candles older than `retention()` windows as new ones open.
+#[cw_serde]
+#[derive(Copy)]
+pub enum CandleInterval {
+    FiveMinutes,
+    Hourly,
+    Daily,
+}
+
+impl CandleInterval {
+    pub const ALL: [CandleInterval; 3] = [
+        CandleInterval::FiveMinutes,
+        CandleInterval::Hourly,
+        CandleInterval::Daily,
+    ];
+
+    /// Width of one candle in seconds.
+    pub fn seconds(&self) -> u64 {
+        match self {
+            CandleInterval::FiveMinutes => 5 * 60,
+            CandleInterval::Hourly => SECONDS_PER_HOUR,
+            CandleInterval::Daily => SECONDS_PER_DAY,
+        }
+    }
+
+    /// Number of most-recent windows kept before pruning.
+    pub fn retention(&self) -> u64 {
+        match self {
+            CandleInterval::FiveMinutes => FIVE_MINUTE_CANDLE_RETENTION,
+            CandleInterval::Hourly => HOURLY_CANDLE_RETENTION,
+            CandleInterval::Daily => DAILY_CANDLE_RETENTION,
+        }
+    }
+
+    /// Storage backing this interval, keyed by candle start time.
+    pub fn candles(&self) -> Map<u64, Candle> {
+        match self {
+            CandleInterval::FiveMinutes => FIVE_MINUTE_CANDLES,
+            CandleInterval::Hourly => HOURLY_CANDLES,
+            CandleInterval::Daily => DAILY_CANDLES,
+        }
+    }
+}
+
+/// Launch-window limits armed on a commit pool when its threshold
+/// crosses (from the factory-supplied `LaunchProtectionConfig`).
+/// `wallet_buy_cap` is already resolved to creator-token base units
+/// against the freshly-seeded reserve; zero means uncapped, as does a
+/// zero `max_swap_bps`.
+#[cw_serde]
+pub struct LaunchWindow {
+    /// First block at which the limits no longer apply.
+    pub ends_at_block: u64,
+    pub max_swap_bps: u16,
+    pub wallet_buy_cap: Uint128,
+}
+
+/// Anti-snipe commission schedule armed alongside the launch window.
+/// Swaps pay `initial_fee` through `start_block`, then a rate falling
+/// linearly to the pool's LP fee at `end_block`.
+#[cw_serde]
+pub struct LaunchFeeSchedule {
+    pub initial_fee: Decimal,
+    pub start_block: u64,
+    pub end_block: u64,
+}
+
+impl LaunchFeeSchedule {
+    /// Commission rate at `block_height`. Never below `lp_fee`, so a
+    /// schedule configured under the pool's own fee has no effect.
+    pub fn rate_at(&self, lp_fee: Decimal, block_height: u64) -> Decimal {
+        if self.initial_fee <= lp_fee || block_height >= self.end_block {
+            return lp_fee;
+        }
+        if block_height <= self.start_block {
+            return self.initial_fee;
+        }
+        let remaining = Decimal::from_ratio(
+            self.end_block - block_height,
+            self.end_block - self.start_block,
+        );
+        lp_fee + (self.initial_fee - lp_fee) * remaining
+    }
+}
+
 #[cw_serde]
 pub struct EmergencyWithdrawalInfo {
     pub withdrawn_at: u64,
@@ -295,6 +474,20 @@ pub const POOL_FEE_STATE: Item<PoolFeeState> = Item::new("pool_fee_state");
 pub const POOL_SPECS: Item<PoolSpecs> = Item::new("pool_specs");
 /// Cumulative counters for swaps, commits, deposits, withdrawals.
 pub const POOL_ANALYTICS: Item<PoolAnalytics> = Item::new("pool_analytics");
+/// Rolling hourly volume/fee/trade-count ring, keyed by
+/// `(block_time / 3600) % HOURLY_VOLUME_BUCKETS`. Written by
+/// `history::record_trade` on every swap-shaped trade; pools that
+/// predate the history simply start with an empty ring.
+pub const HOURLY_VOLUME_HISTORY: Map<u64, VolumeBucket> = Map::new("volume_hourly");
+/// Rolling daily ring, same shape as `HOURLY_VOLUME_HISTORY` with
+/// `DAILY_VOLUME_BUCKETS` slots.
+pub const DAILY_VOLUME_HISTORY: Map<u64, VolumeBucket> = Map::new("volume_daily");
+/// OHLC candles keyed by window start (unix seconds), one map per
+/// `CandleInterval`. Written by `swap::record_candles`; ordered keys
+/// let the `Candles` query paginate with `start_after`.
+pub const FIVE_MINUTE_CANDLES: Map<u64, Candle> = Map::new("candles_5m");
+pub const HOURLY_CANDLES: Map<u64, Candle> = Map::new("candles_1h");
+pub const DAILY_CANDLES: Map<u64, Candle> = Map::new("candles_1d");
 /// All LP positions keyed by string position id.
 pub const LIQUIDITY_POSITIONS: Map<&str, Position> = Map::new("positions");
 /// Reverse index: positions owned by a given address.
@@ -440,6 +633,15 @@ pub const IS_THRESHOLD_HIT: Item<bool> = Item::new("threshold_hit");
 /// drift); on a healthy standard pool the swept amount is zero.
 pub const CREATOR_FEE_POT: Item<CreatorFeePot> = Item::new("creator_fee_pot");
 
+/// Protocol fee switch. `share` is seeded at instantiate from the
+/// factory's per-kind `ProtocolFeeConfig` and re-synced by the factory
+/// (`SetProtocolFeeShare`) after a config update. Absent on pools that
+/// predate the switch, which `may_load(...).unwrap_or_default()` reads
+/// as a zero share. Like `CREATOR_FEE_POT`, the `owed_*` balances are
+/// held outside the reserves and fee reserves, and the emergency drain
+/// sweeps them.
+pub const PROTOCOL_FEE_STATE: Item<ProtocolFeeState> = Item::new("protocol_fee_state");
+
 /// When `true` (default-on-load via `.unwrap_or(true)` for backwards
 /// compatibility with pre-flag fixtures), the deposit / add / remove
 /// paths route each position's `fee_size_multiplier` through
@@ -506,6 +708,25 @@ pub const ORACLE_INFO: Item<OracleInfo> = Item::new("oracle_info");
 pub const POST_THRESHOLD_COOLDOWN_UNTIL_BLOCK: Item<u64> =
     Item::new("post_threshold_cooldown_until_block");
 
+// Launch-window limits that follow the cooldown. Armed by the
+// threshold-crossing handlers when the factory configured a window;
+// absent on standard pools and on commit pools created without one,
+// which makes `swap::enforce_launch_window` a no-op.
+//
+// Read by: execute_simple_swap, process_post_threshold_commit.
+pub const LAUNCH_WINDOW: Item<LaunchWindow> = Item::new("launch_window");
+/// Creator tokens each wallet has bought while the launch window was
+/// active, checked against `LaunchWindow.wallet_buy_cap`.
+pub const LAUNCH_WALLET_BUYS: Map<&Addr, Uint128> = Map::new("launch_wallet_buys");
+
+// Decaying launch fee. Armed next to `LAUNCH_WINDOW` when the factory
+// configured a non-zero `launch_fee`; absent elsewhere, which leaves
+// every swap at `PoolSpecs.lp_fee`.
+//
+// Read by: execute_simple_swap, process_post_threshold_commit and the
+// simulation queries (via `swap::effective_commission_rate`).
+pub const LAUNCH_FEE: Item<LaunchFeeSchedule> = Item::new("launch_fee");
+
 // -- Constants ------------------------------------------------------------
 
 /// Uniswap-V2-style minimum-liquidity floor permanently locked on the
@@ -551,6 +772,9 @@ pub const MAX_LP_FEE: Decimal = Decimal::percent(10);
 /// swap (no fee growth → no creator/LP rewards → infinite-loss
 /// liquidity provision).
 pub const MIN_LP_FEE: Decimal = Decimal::permille(1);
+/// Hard ceiling on `ProtocolFeeState.share`. LPs always keep at least
+/// half of the LP fee; mirrored by the factory's `PROTOCOL_FEE_SHARE_MAX`.
+pub const MAX_PROTOCOL_FEE_SHARE: Decimal = Decimal::percent(50);
 
 /// `pool_kind` attribute value emitted in `instantiate` responses by
 /// the standard-pool wasm. Pinned here so off-chain indexers can pin
@@ -585,6 +809,36 @@ pub const MAX_CONSECUTIVE_DISTRIBUTION_FAILURES: u32 = 5;
 /// Seconds in a day. Used by the creator-excess unlock-time math
 /// (`creator_excess_liquidity_lock_days * SECONDS_PER_DAY`).
 pub const SECONDS_PER_DAY: u64 = 86_400;
+/// Seconds in an hour. Width of an hourly volume-history bucket.
+pub const SECONDS_PER_HOUR: u64 = 3_600;
+
+/// Slots retained in `HOURLY_VOLUME_HISTORY` — two days of hourly
+/// buckets, enough for a 24h chart plus the previous day to compare
+/// against.
+pub const HOURLY_VOLUME_BUCKETS: u64 = 48;
+/// Slots retained in `DAILY_VOLUME_HISTORY` — one quarter of daily
+/// buckets. Older windows are overwritten in place, so storage per
+/// pool is bounded at `HOURLY_VOLUME_BUCKETS + DAILY_VOLUME_BUCKETS`
+/// entries regardless of pool age.
+pub const DAILY_VOLUME_BUCKETS: u64 = 90;
+/// Hourly buckets summed for the trailing-24h fee figure behind
+/// `PoolAnalyticsResponse.fee_apr`.
+pub const FEE_APR_WINDOW_HOURS: u64 = 24;
+
+/// Candle retention per interval: one day of 5-minute candles, one
+/// week of hourly candles and one year of daily candles.
+pub const FIVE_MINUTE_CANDLE_RETENTION: u64 = 288;
+pub const HOURLY_CANDLE_RETENTION: u64 = 168;
+pub const DAILY_CANDLE_RETENTION: u64 = 365;
+/// Upper bound on expired candles deleted per interval by a single
+/// trade, so a pool that was idle for months doesn't pay for the whole
+/// backlog in one swap. Any remainder is cleared by later trades.
+pub const CANDLE_PRUNE_BATCH: usize = 8;
+/// Default page size for `QueryMsg::Candles` when `limit` is omitted.
+pub const CANDLES_QUERY_DEFAULT_LIMIT: u32 = 100;
+/// Hard ceiling on `QueryMsg::Candles.limit`; matches the longest
+/// retention so a full daily chart fits in one page.
+pub const CANDLES_QUERY_MAX_LIMIT: u32 = 365;
 
 /// Default page size for `QueryMsg::PoolCommits` (per-pool committer
 /// pagination) when the caller doesn't supply `limit`.
@@ -4,6 +4,17 @@
 //! - Pure AMM math: `compute_swap`, `compute_offer_amount`,
 //! `assert_max_spread`, `update_price_accumulator`. No storage; may
 //! mutate a caller-provided `PoolState` ref.
+//! - Price candles: `candle_price` + `record_candles`, the OHLC
+//! recorder every price-moving trade calls after updating reserves.
+//! - Launch protection: `enforce_launch_window` and the decaying launch
+//! fee (`effective_commission_rate`, `split_launch_commission`,
+//! `credit_launch_fee`).
+//! - Protocol fee switch: `accrue_protocol_fee`, which carves the
+//! protocol share out of the LP commission before fee growth.
+//! - Reserve swap core: `swap_through_reserves`, the gates, commission,
+//! reserve update and trade recording every swap shares, and
+//! `swap_fees_through_reserves`, its `CollectFees` entry for when the LP
+//! asks for a single output asset.
 //! - Swap orchestration: `execute_swap_cw20` (CW20 `Receive` hook),
 //! `simple_swap` (reentrancy + rate-limit wrapper), and
 //! `execute_simple_swap` (the actual swap handler). All
@@ -16,18 +27,22 @@
 
 use crate::asset::{TokenInfo, TokenInfoPoolExt, TokenType};
 use crate::error::ContractError;
+use crate::history::{record_trade, TradeRecord};
 use crate::generic::{check_rate_limit, decimal2decimal256, enforce_transaction_deadline,
     update_pool_fee_growth, with_reentrancy_guard};
 use crate::msg::Cw20HookMsg;
 use crate::state::{
-    PoolCtx, PoolInfo, PoolState, CREATOR_FEE_POT, IS_THRESHOLD_HIT, MINIMUM_LIQUIDITY,
-    POOL_ANALYTICS, POOL_FEE_STATE, POOL_INFO, POOL_PAUSED, POOL_STATE,
+    Candle, CandleInterval, PoolCtx, PoolFeeState, PoolInfo, PoolState, CANDLE_PRUNE_BATCH,
+    CREATOR_FEE_POT, LAUNCH_FEE, LAUNCH_WALLET_BUYS, PROTOCOL_FEE_STATE, LAUNCH_WINDOW,
+    IS_THRESHOLD_HIT, MINIMUM_LIQUIDITY, POOL_ANALYTICS, POOL_FEE_STATE, POOL_INFO, POOL_PAUSED,
+    POOL_SPECS, POOL_STATE,
     POST_THRESHOLD_COOLDOWN_UNTIL_BLOCK,
 };
 use cosmwasm_std::{
-    from_json, Addr, Decimal, Decimal256, DepsMut, Env, Fraction, MessageInfo, Response, StdError,
-    StdResult, Uint128, Uint256,
+    from_json, Addr, Decimal, Decimal256, DepsMut, Env, Fraction, MessageInfo, Order, Response,
+    StdError, StdResult, Storage, Uint128, Uint256,
 };
+use cw_storage_plus::Bound;
 use cw20::Cw20ReceiveMsg;
 use std::str::FromStr;
 
@@ -156,6 +171,369 @@ pub fn update_price_accumulator(
     Ok(())
 }
 
+/// Spot price of token1 in token0 at `PRICE_ACCUMULATOR_SCALE`
+/// precision — the value tracked by OHLC candles. Zero while either
+/// reserve is empty; saturates rather than erroring on overflow.
+pub fn candle_price(reserve0: Uint128, reserve1: Uint128) -> Uint128 {
+    if reserve0.is_zero() || reserve1.is_zero() {
+        return Uint128::zero();
+    }
+    reserve0
+        .checked_multiply_ratio(PRICE_ACCUMULATOR_SCALE, reserve1)
+        .unwrap_or(Uint128::MAX)
+}
+
+/// Fold one trade into the current candle of every `CandleInterval`.
+/// `price_before` / `price_after` are `candle_price` readings around
+/// the reserve update: a new candle opens at `price_before`, and both
+/// readings feed high / low. Opening a candle also prunes up to
+/// `CANDLE_PRUNE_BATCH` candles that fell out of the interval's
+/// retention window, keeping storage bounded without a keeper.
+pub fn record_candles(
+    storage: &mut dyn Storage,
+    now: u64,
+    price_before: Uint128,
+    price_after: Uint128,
+    volume_0: Uint128,
+    volume_1: Uint128,
+) -> StdResult<()> {
+    for interval in CandleInterval::ALL {
+        let width = interval.seconds();
+        let start = now - now % width;
+        let candles = interval.candles();
+
+        let mut candle = match candles.may_load(storage, start)? {
+            Some(candle) => candle,
+            None => {
+                // Keep the `retention()` most recent windows, this one included.
+                let cutoff = start.saturating_sub((interval.retention() - 1) * width);
+                let expired = candles
+                    .keys(storage, None, Some(Bound::exclusive(cutoff)), Order::Ascending)
+                    .take(CANDLE_PRUNE_BATCH)
+                    .collect::<StdResult<Vec<u64>>>()?;
+                for key in expired {
+                    candles.remove(storage, key);
+                }
+                Candle {
+                    start,
+                    open: price_before,
+                    high: price_before,
+                    low: price_before,
+                    close: price_before,
+                    volume_0: Uint128::zero(),
+                    volume_1: Uint128::zero(),
+                }
+            }
+        };
+        candle.high = candle.high.max(price_before).max(price_after);
+        candle.low = candle.low.min(price_before).min(price_after);
+        candle.close = price_after;
+        candle.volume_0 = candle.volume_0.saturating_add(volume_0);
+        candle.volume_1 = candle.volume_1.saturating_add(volume_1);
+        candles.save(storage, start, &candle)?;
+    }
+    Ok(())
+}
+
+/// Launch-window gate shared by plain swaps and post-threshold commits.
+/// While `LAUNCH_WINDOW` is armed and unexpired, rejects offers above
+/// `max_swap_bps` of the offer-side reserve and buys (token0 offered)
+/// that would take `trader`'s cumulative creator-token purchases past
+/// `wallet_buy_cap`. Accepted buys are added to `LAUNCH_WALLET_BUYS`;
+/// the caller's error path reverts that write along with the swap.
+pub fn enforce_launch_window(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    trader: &Addr,
+    offer_index: usize,
+    offer_amount: Uint128,
+    offer_pool: Uint128,
+    return_amount: Uint128,
+) -> Result<(), ContractError> {
+    let Some(window) = LAUNCH_WINDOW.may_load(storage)? else {
+        return Ok(());
+    };
+    if block_height >= window.ends_at_block {
+        return Ok(());
+    }
+
+    if window.max_swap_bps > 0 {
+        let max_offer = offer_pool.multiply_ratio(window.max_swap_bps, 10_000u128);
+        if offer_amount > max_offer {
+            return Err(ContractError::LaunchSwapTooLarge {
+                max_offer,
+                until_block: window.ends_at_block,
+            });
+        }
+    }
+
+    if offer_index == 0 && !window.wallet_buy_cap.is_zero() {
+        let bought = LAUNCH_WALLET_BUYS
+            .may_load(storage, trader)?
+            .unwrap_or_default();
+        let total = bought.checked_add(return_amount)?;
+        if total > window.wallet_buy_cap {
+            return Err(ContractError::LaunchWalletCapExceeded {
+                remaining: window.wallet_buy_cap.saturating_sub(bought),
+                until_block: window.ends_at_block,
+            });
+        }
+        LAUNCH_WALLET_BUYS.save(storage, trader, &total)?;
+    }
+    Ok(())
+}
+
+/// Commission rate a swap pays at `block_height`: `lp_fee`, raised by the
+/// `LAUNCH_FEE` schedule while one is armed and still decaying.
+pub fn effective_commission_rate(
+    storage: &dyn Storage,
+    lp_fee: Decimal,
+    block_height: u64,
+) -> StdResult<Decimal> {
+    Ok(match LAUNCH_FEE.may_load(storage)? {
+        Some(schedule) => schedule.rate_at(lp_fee, block_height),
+        None => lp_fee,
+    })
+}
+
+/// Splits a commission charged at `rate` into the LP share (what `lp_fee`
+/// alone would have charged) and the launch-fee surcharge above it.
+pub fn split_launch_commission(
+    commission: Uint128,
+    lp_fee: Decimal,
+    rate: Decimal,
+) -> (Uint128, Uint128) {
+    if rate <= lp_fee {
+        return (commission, Uint128::zero());
+    }
+    let lp_part = commission.multiply_ratio(lp_fee.atomics(), rate.atomics());
+    (lp_part, commission - lp_part)
+}
+
+/// Credits a launch-fee surcharge to `CREATOR_FEE_POT`. Commission is
+/// taken in the ask asset, so a token0 offer lands in `amount_1`.
+pub fn credit_launch_fee(
+    storage: &mut dyn Storage,
+    offer_index: usize,
+    surcharge: Uint128,
+) -> StdResult<()> {
+    if surcharge.is_zero() {
+        return Ok(());
+    }
+    let mut pot = CREATOR_FEE_POT.may_load(storage)?.unwrap_or_default();
+    if offer_index == 0 {
+        pot.amount_1 = pot.amount_1.checked_add(surcharge)?;
+    } else {
+        pot.amount_0 = pot.amount_0.checked_add(surcharge)?;
+    }
+    CREATOR_FEE_POT.save(storage, &pot)
+}
+
+/// Diverts the protocol share of `lp_commission` into
+/// `PROTOCOL_FEE_STATE.owed_*` (ask side, like every commission) and
+/// returns the amount taken; the caller credits only the remainder to
+/// LP fee growth. A zero share (or no state) takes nothing.
+pub fn accrue_protocol_fee(
+    storage: &mut dyn Storage,
+    offer_index: usize,
+    lp_commission: Uint128,
+) -> StdResult<Uint128> {
+    let Some(mut protocol) = PROTOCOL_FEE_STATE.may_load(storage)? else {
+        return Ok(Uint128::zero());
+    };
+    let cut = lp_commission.mul_floor(protocol.share);
+    if cut.is_zero() {
+        return Ok(cut);
+    }
+    if offer_index == 0 {
+        protocol.owed_1 = protocol.owed_1.checked_add(cut)?;
+        protocol.total_accrued_1 = protocol.total_accrued_1.checked_add(cut)?;
+    } else {
+        protocol.owed_0 = protocol.owed_0.checked_add(cut)?;
+        protocol.total_accrued_0 = protocol.total_accrued_0.checked_add(cut)?;
+    }
+    PROTOCOL_FEE_STATE.save(storage, &protocol)?;
+    Ok(cut)
+}
+
+/// Amounts produced by one `swap_through_reserves` call.
+pub struct ReserveSwapOutcome {
+    pub return_amount: Uint128,
+    pub spread_amount: Uint128,
+    pub commission_amount: Uint128,
+    pub launch_fee_amount: Uint128,
+    pub protocol_fee_amount: Uint128,
+    /// `POOL_ANALYTICS.total_swap_count` after this swap.
+    pub total_swap_count: u64,
+}
+
+/// The reserve-side core shared by every swap: pause / cooldown / drain
+/// gates, commission (with the launch fee and protocol cut), launch
+/// caps, reserve update, fee growth, analytics, trade and candle
+/// recording. `check_spread` sees `(return + commission, spread)` after
+/// the dust check and before the launch caps; callers without a
+/// slippage bound pass a no-op. Mutates `pool_state` / `pool_fee_state`
+/// in place and leaves saving them to the caller.
+#[allow(clippy::too_many_arguments)]
+pub fn swap_through_reserves(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    pool_state: &mut PoolState,
+    pool_fee_state: &mut PoolFeeState,
+    lp_fee: Decimal,
+    offer_index: usize,
+    offer_amount: Uint128,
+    check_spread: impl FnOnce(Uint128, Uint128) -> Result<(), ContractError>,
+) -> Result<ReserveSwapOutcome, ContractError> {
+    if POOL_PAUSED.may_load(storage)?.unwrap_or(false) {
+        return Err(ContractError::PoolPausedLowLiquidity {});
+    }
+    // Post-threshold-crossing cooldown. Set inside the threshold-crossing
+    // commit handler to (crossing_block + POST_THRESHOLD_COOLDOWN_BLOCKS + 1),
+    // so the crossing block plus the next N blocks are gated. Eliminates
+    // the atomic same-block sandwich on the freshly-seeded pool. Standard
+    // pools never set this (no threshold crossing), so the may_load default
+    // of 0 makes this a no-op for them.
+    let cooldown_until = POST_THRESHOLD_COOLDOWN_UNTIL_BLOCK
+        .may_load(storage)?
+        .unwrap_or(0);
+    if env.block.height < cooldown_until {
+        return Err(ContractError::PostThresholdCooldownActive {
+            until_block: cooldown_until,
+        });
+    }
+    // Drain guard: reject swaps when either side is below MINIMUM_LIQUIDITY.
+    // Don't try to persist POOL_PAUSED here — returning Err would revert the
+    // save, so it's dead state. The reserve check alone is sufficient to
+    // block every swap path; admins unlock the pool by restoring reserves or
+    // by calling the factory's explicit UnpausePool route if POOL_PAUSED was
+    // ever set by a successful admin action.
+    if pool_state.reserve0 < MINIMUM_LIQUIDITY || pool_state.reserve1 < MINIMUM_LIQUIDITY {
+        return Err(ContractError::InsufficientReserves {});
+    }
+
+    let (offer_pool, ask_pool) = if offer_index == 0 {
+        (pool_state.reserve0, pool_state.reserve1)
+    } else {
+        (pool_state.reserve1, pool_state.reserve0)
+    };
+    let commission_rate = effective_commission_rate(storage, lp_fee, env.block.height)?;
+    let (return_amt, spread_amt, commission_amt) =
+        compute_swap(offer_pool, ask_pool, offer_amount, commission_rate)?;
+    let (lp_commission, launch_fee_amt) =
+        split_launch_commission(commission_amt, lp_fee, commission_rate);
+
+    // Reject dust swaps where the constant-product math floored
+    // return_amt to zero. Without this, the offer would be absorbed into
+    // the pool while the sender receives nothing — effectively donating
+    // to LPs. Better to surface the "offer too small" error and let the
+    // caller bump their size or abandon.
+    if return_amt.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    check_spread(return_amt.checked_add(commission_amt)?, spread_amt)?;
+
+    enforce_launch_window(
+        storage,
+        env.block.height,
+        sender,
+        offer_index,
+        offer_amount,
+        offer_pool,
+        return_amt,
+    )?;
+
+    let offer_pool_post = offer_pool.checked_add(offer_amount)?;
+    let ask_pool_post = ask_pool.checked_sub(return_amt.checked_add(commission_amt)?)?;
+    if ask_pool_post < MINIMUM_LIQUIDITY {
+        return Err(ContractError::InsufficientReserves {});
+    }
+
+    // TWAP: accumulate price using OLD reserves before updating
+    update_price_accumulator(pool_state, env.block.time.seconds())?;
+    let price_before = candle_price(pool_state.reserve0, pool_state.reserve1);
+    if offer_index == 0 {
+        pool_state.reserve0 = offer_pool_post;
+        pool_state.reserve1 = ask_pool_post;
+    } else {
+        pool_state.reserve0 = ask_pool_post;
+        pool_state.reserve1 = offer_pool_post;
+    }
+
+    let protocol_fee_amt = accrue_protocol_fee(storage, offer_index, lp_commission)?;
+    let lp_commission = lp_commission.checked_sub(protocol_fee_amt)?;
+    update_pool_fee_growth(pool_fee_state, pool_state, offer_index, lp_commission)?;
+    credit_launch_fee(storage, offer_index, launch_fee_amt)?;
+
+    let mut analytics = POOL_ANALYTICS.may_load(storage)?.unwrap_or_default();
+    analytics.total_swap_count += 1;
+    let (volume_0, volume_1) = if offer_index == 0 {
+        (offer_amount, return_amt)
+    } else {
+        (return_amt, offer_amount)
+    };
+    analytics.total_volume_0 = analytics.total_volume_0.saturating_add(volume_0);
+    analytics.total_volume_1 = analytics.total_volume_1.saturating_add(volume_1);
+    analytics.last_trade_block = env.block.height;
+    analytics.last_trade_timestamp = env.block.time.seconds();
+    POOL_ANALYTICS.save(storage, &analytics)?;
+    record_trade(
+        storage,
+        env.block.time.seconds(),
+        &TradeRecord::from_swap(offer_index, offer_amount, return_amt, lp_commission),
+    )?;
+    record_candles(
+        storage,
+        env.block.time.seconds(),
+        price_before,
+        candle_price(pool_state.reserve0, pool_state.reserve1),
+        volume_0,
+        volume_1,
+    )?;
+
+    Ok(ReserveSwapOutcome {
+        return_amount: return_amt,
+        spread_amount: spread_amt,
+        commission_amount: commission_amt,
+        launch_fee_amount: launch_fee_amt,
+        protocol_fee_amount: protocol_fee_amt,
+        total_swap_count: analytics.total_swap_count,
+    })
+}
+
+/// Swaps `offer_amount` of already-collected fees on `offer_index` into
+/// the other asset through the pool's own reserves, for `CollectFees`
+/// with an `output_asset`. The fee tokens are already in the pool's
+/// balance (the caller has debited them from `fee_reserve`), so the
+/// offer side just moves into reserves and the return comes out of the
+/// ask reserve. Runs the same `swap_through_reserves` core as
+/// `execute_simple_swap`, without a spread bound (the caller checks
+/// `min_output` on the total payout); the caller saves `pool_state` /
+/// `pool_fee_state` afterwards.
+pub fn swap_fees_through_reserves(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    pool_state: &mut PoolState,
+    pool_fee_state: &mut PoolFeeState,
+    offer_index: usize,
+    offer_amount: Uint128,
+) -> Result<ReserveSwapOutcome, ContractError> {
+    let lp_fee = POOL_SPECS.load(storage)?.lp_fee;
+    swap_through_reserves(
+        storage,
+        env,
+        sender,
+        pool_state,
+        pool_fee_state,
+        lp_fee,
+        offer_index,
+        offer_amount,
+        |_, _| Ok(()),
+    )
+}
+
 /// Reverse swap: computes the required offer amount for a desired ask amount.
 pub fn compute_offer_amount(
     offer_pool: Uint128,
@@ -354,14 +732,29 @@ pub fn execute_swap_cw20(
             let creator_pot = CREATOR_FEE_POT
                 .may_load(deps.storage)?
                 .unwrap_or_default();
-            let (reserve_offer, fee_reserve_offer, pot_offer) = if offer_index == 0 {
-                (pool_state.reserve0, pool_fee_state.fee_reserve_0, creator_pot.amount_0)
-            } else {
-                (pool_state.reserve1, pool_fee_state.fee_reserve_1, creator_pot.amount_1)
-            };
+            let protocol = PROTOCOL_FEE_STATE
+                .may_load(deps.storage)?
+                .unwrap_or_default();
+            let (reserve_offer, fee_reserve_offer, pot_offer, protocol_offer) =
+                if offer_index == 0 {
+                    (
+                        pool_state.reserve0,
+                        pool_fee_state.fee_reserve_0,
+                        creator_pot.amount_0,
+                        protocol.owed_0,
+                    )
+                } else {
+                    (
+                        pool_state.reserve1,
+                        pool_fee_state.fee_reserve_1,
+                        creator_pot.amount_1,
+                        protocol.owed_1,
+                    )
+                };
             let expected_min = reserve_offer
                 .checked_add(fee_reserve_offer)?
                 .checked_add(pot_offer)?
+                .checked_add(protocol_offer)?
                 .checked_add(cw20_msg.amount)?;
             let actual_balance =
                 pool_factory_interfaces::asset::query_token_balance_strict(
@@ -474,104 +867,44 @@ pub fn execute_simple_swap(
     // the chain if the swap fails downstream, identical to before.
     check_rate_limit(deps, &env, &pool_specs, &sender)?;
 
-    let (offer_index, offer_pool, ask_pool) =
-        if offer_asset.info.equal(&pool_info.pool_info.asset_infos[0]) {
-            (0usize, pool_state.reserve0, pool_state.reserve1)
-        } else if offer_asset.info.equal(&pool_info.pool_info.asset_infos[1]) {
-            (1usize, pool_state.reserve1, pool_state.reserve0)
-        } else {
-            return Err(ContractError::AssetMismatch {});
-        };
-
-    if POOL_PAUSED.may_load(deps.storage)?.unwrap_or(false) {
-        return Err(ContractError::PoolPausedLowLiquidity {});
-    }
-    // Post-threshold-crossing cooldown. Set inside the threshold-crossing
-    // commit handler to (crossing_block + POST_THRESHOLD_COOLDOWN_BLOCKS + 1),
-    // so the crossing block plus the next N blocks are gated. Eliminates
-    // the atomic same-block sandwich on the freshly-seeded pool. Standard
-    // pools never set this (no threshold crossing), so the may_load default
-    // of 0 makes this a no-op for them.
-    let cooldown_until = POST_THRESHOLD_COOLDOWN_UNTIL_BLOCK
-        .may_load(deps.storage)?
-        .unwrap_or(0);
-    if env.block.height < cooldown_until {
-        return Err(ContractError::PostThresholdCooldownActive {
-            until_block: cooldown_until,
-        });
-    }
-    // Drain guard: reject swaps when either side is below MINIMUM_LIQUIDITY.
-    // Don't try to persist POOL_PAUSED here — returning Err would revert the
-    // save, so it's dead state. The reserve check alone is sufficient to
-    // block every swap path; admins unlock the pool by restoring reserves or
-    // by calling the factory's explicit UnpausePool route if POOL_PAUSED was
-    // ever set by a successful admin action.
-    if pool_state.reserve0 < MINIMUM_LIQUIDITY || pool_state.reserve1 < MINIMUM_LIQUIDITY {
-        return Err(ContractError::InsufficientReserves {});
-    }
-
-    let (return_amt, spread_amt, commission_amt) =
-        compute_swap(offer_pool, ask_pool, offer_asset.amount, pool_specs.lp_fee)?;
-
-    // Reject dust swaps where the constant-product math floored
-    // return_amt to zero. Without this, the trader's offer would be
-    // absorbed into the pool while they receive nothing — effectively
-    // donating to LPs. Better to surface the "offer too small" error
-    // and let the caller bump their size or abandon.
-    if return_amt.is_zero() {
-        return Err(ContractError::ZeroAmount {});
-    }
-
-    assert_max_spread(
-        belief_price,
-        max_spread,
-        allow_high_max_spread,
-        offer_asset.amount,
-        return_amt.checked_add(commission_amt)?,
-        spread_amt,
-    )?;
-
-    let offer_pool_post = offer_pool.checked_add(offer_asset.amount)?;
-    let ask_pool_post = ask_pool.checked_sub(return_amt.checked_add(commission_amt)?)?;
-
-    if ask_pool_post < MINIMUM_LIQUIDITY {
-        return Err(ContractError::InsufficientReserves {});
-    }
-
-    // TWAP: accumulate price using OLD reserves before updating
-    update_price_accumulator(&mut pool_state, env.block.time.seconds())?;
-
-    if offer_index == 0 {
-        pool_state.reserve0 = offer_pool_post;
-        pool_state.reserve1 = ask_pool_post;
+    let offer_index = if offer_asset.info.equal(&pool_info.pool_info.asset_infos[0]) {
+        0usize
+    } else if offer_asset.info.equal(&pool_info.pool_info.asset_infos[1]) {
+        1usize
     } else {
-        pool_state.reserve0 = ask_pool_post;
-        pool_state.reserve1 = offer_pool_post;
-    }
+        return Err(ContractError::AssetMismatch {});
+    };
 
-    update_pool_fee_growth(
+    let ReserveSwapOutcome {
+        return_amount: return_amt,
+        spread_amount: spread_amt,
+        commission_amount: commission_amt,
+        launch_fee_amount: launch_fee_amt,
+        protocol_fee_amount: protocol_fee_amt,
+        total_swap_count,
+    } = swap_through_reserves(
+        deps.storage,
+        &env,
+        &sender,
+        &mut pool_state,
         &mut pool_fee_state,
-        &pool_state,
+        pool_specs.lp_fee,
         offer_index,
-        commission_amt,
+        offer_asset.amount,
+        |return_with_commission, spread_amt| {
+            assert_max_spread(
+                belief_price,
+                max_spread,
+                allow_high_max_spread,
+                offer_asset.amount,
+                return_with_commission,
+                spread_amt,
+            )
+        },
     )?;
     POOL_FEE_STATE.save(deps.storage, &pool_fee_state)?;
     POOL_STATE.save(deps.storage, &pool_state)?;
 
-    // Update analytics counters
-    let mut analytics = POOL_ANALYTICS.may_load(deps.storage)?.unwrap_or_default();
-    analytics.total_swap_count += 1;
-    if offer_index == 0 {
-        analytics.total_volume_0 = analytics.total_volume_0.saturating_add(offer_asset.amount);
-        analytics.total_volume_1 = analytics.total_volume_1.saturating_add(return_amt);
-    } else {
-        analytics.total_volume_1 = analytics.total_volume_1.saturating_add(offer_asset.amount);
-        analytics.total_volume_0 = analytics.total_volume_0.saturating_add(return_amt);
-    }
-    analytics.last_trade_block = env.block.height;
-    analytics.last_trade_timestamp = env.block.time.seconds();
-    POOL_ANALYTICS.save(deps.storage, &analytics)?;
-
     let ask_asset_info = if offer_index == 0 {
         pool_info.pool_info.asset_infos[1].clone()
     } else {
@@ -607,6 +940,8 @@ pub fn execute_simple_swap(
         ("return_amount", return_amt.to_string()),
         ("spread_amount", spread_amt.to_string()),
         ("commission_amount", commission_amt.to_string()),
+        ("launch_fee_amount", launch_fee_amt.to_string()),
+        ("protocol_fee_amount", protocol_fee_amt.to_string()),
         ("effective_price", effective_price),
         ("reserve0_after", pool_state.reserve0.to_string()),
         ("reserve1_after", pool_state.reserve1.to_string()),
@@ -615,7 +950,7 @@ pub fn execute_simple_swap(
         ("pool_contract", pool_state.pool_contract_address.to_string()),
         ("block_height", env.block.height.to_string()),
         ("block_time", env.block.time.seconds().to_string()),
-        ("total_swap_count", analytics.total_swap_count.to_string()),
+        ("total_swap_count", total_swap_count.to_string()),
     ]))
 }
 
@@ -745,4 +1080,142 @@ mod tests {
             Uint128::zero());
         assert!(matches!(r, Err(ContractError::MaxSpreadAssertion {})));
     }
+
+    #[test]
+    fn candle_price_scales_and_handles_empty_reserves() {
+        assert_eq!(
+            candle_price(Uint128::new(1_000), Uint128::new(2_000)),
+            Uint128::new(PRICE_ACCUMULATOR_SCALE / 2)
+        );
+        assert_eq!(candle_price(Uint128::zero(), Uint128::new(5)), Uint128::zero());
+        assert_eq!(candle_price(Uint128::new(5), Uint128::zero()), Uint128::zero());
+    }
+
+    fn candle_trade(storage: &mut dyn Storage, now: u64, before: u128, after: u128, volume: u128) {
+        record_candles(
+            storage,
+            now,
+            Uint128::new(before),
+            Uint128::new(after),
+            Uint128::new(volume),
+            Uint128::new(volume),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn record_candles_tracks_ohlc_within_window() {
+        let mut storage = cosmwasm_std::testing::MockStorage::new();
+        let t = 1_000 * 3_600;
+        candle_trade(&mut storage, t, 100, 120, 5);
+        candle_trade(&mut storage, t + 10, 120, 90, 2);
+        candle_trade(&mut storage, t + 20, 90, 110, 1);
+
+        for interval in CandleInterval::ALL {
+            let candle = interval
+                .candles()
+                .load(&storage, t - t % interval.seconds())
+                .unwrap();
+            assert_eq!(candle.open, Uint128::new(100));
+            assert_eq!(candle.high, Uint128::new(120));
+            assert_eq!(candle.low, Uint128::new(90));
+            assert_eq!(candle.close, Uint128::new(110));
+            assert_eq!(candle.volume_0, Uint128::new(8));
+            assert_eq!(candle.volume_1, Uint128::new(8));
+        }
+    }
+
+    #[test]
+    fn record_candles_prunes_expired_windows() {
+        let mut storage = cosmwasm_std::testing::MockStorage::new();
+        let interval = CandleInterval::FiveMinutes;
+        let width = interval.seconds();
+        let first = 10 * width;
+        candle_trade(&mut storage, first, 1, 1, 1);
+
+        // Still inside retention: the first candle survives.
+        let inside = first + (interval.retention() - 1) * width;
+        candle_trade(&mut storage, inside, 1, 1, 1);
+        assert!(interval.candles().has(&storage, first));
+
+        // One window later the first candle falls out and is pruned.
+        let outside = first + interval.retention() * width;
+        candle_trade(&mut storage, outside, 1, 1, 1);
+        assert!(!interval.candles().has(&storage, first));
+        assert!(interval.candles().has(&storage, inside));
+    }
+
+    #[test]
+    fn launch_fee_decays_linearly_to_lp_fee() {
+        let lp_fee = Decimal::permille(3);
+        let schedule = crate::state::LaunchFeeSchedule {
+            initial_fee: Decimal::percent(10),
+            start_block: 100,
+            end_block: 200,
+        };
+        assert_eq!(schedule.rate_at(lp_fee, 50), Decimal::percent(10));
+        assert_eq!(schedule.rate_at(lp_fee, 100), Decimal::percent(10));
+        // Halfway: lp_fee + (10% - 0.3%) / 2.
+        assert_eq!(
+            schedule.rate_at(lp_fee, 150),
+            Decimal::from_ratio(515u128, 10_000u128)
+        );
+        assert_eq!(schedule.rate_at(lp_fee, 200), lp_fee);
+        assert_eq!(schedule.rate_at(lp_fee, 10_000), lp_fee);
+        // A schedule below the pool's own fee never lowers it.
+        assert_eq!(schedule.rate_at(Decimal::percent(20), 100), Decimal::percent(20));
+
+        let mut storage = cosmwasm_std::testing::MockStorage::new();
+        assert_eq!(effective_commission_rate(&storage, lp_fee, 100).unwrap(), lp_fee);
+        LAUNCH_FEE.save(&mut storage, &schedule).unwrap();
+        assert_eq!(
+            effective_commission_rate(&storage, lp_fee, 100).unwrap(),
+            Decimal::percent(10)
+        );
+    }
+
+    #[test]
+    fn split_launch_commission_separates_lp_share() {
+        let (lp, surcharge) =
+            split_launch_commission(Uint128::new(1_000), Decimal::permille(3), Decimal::percent(3));
+        assert_eq!(lp, Uint128::new(100));
+        assert_eq!(surcharge, Uint128::new(900));
+
+        let (lp, surcharge) =
+            split_launch_commission(Uint128::new(1_000), Decimal::permille(3), Decimal::permille(3));
+        assert_eq!(lp, Uint128::new(1_000));
+        assert!(surcharge.is_zero());
+    }
+
+    #[test]
+    fn accrue_protocol_fee_credits_ask_side() {
+        let mut storage = cosmwasm_std::testing::MockStorage::new();
+        // No state saved yet: nothing is taken.
+        assert!(accrue_protocol_fee(&mut storage, 0, Uint128::new(1_000))
+            .unwrap()
+            .is_zero());
+
+        PROTOCOL_FEE_STATE
+            .save(
+                &mut storage,
+                &crate::state::ProtocolFeeState {
+                    share: Decimal::percent(20),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            accrue_protocol_fee(&mut storage, 0, Uint128::new(1_000)).unwrap(),
+            Uint128::new(200)
+        );
+        assert_eq!(
+            accrue_protocol_fee(&mut storage, 1, Uint128::new(99)).unwrap(),
+            Uint128::new(19)
+        );
+        let protocol = PROTOCOL_FEE_STATE.load(&storage).unwrap();
+        assert_eq!(protocol.owed_1, Uint128::new(200));
+        assert_eq!(protocol.owed_0, Uint128::new(19));
+        assert_eq!(protocol.total_accrued_1, Uint128::new(200));
+        assert_eq!(protocol.total_accrued_0, Uint128::new(19));
+    }
 }
@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, HexBinary, Timestamp, Uint128};
 
 pub mod asset;
 pub mod cw721_msgs;
@@ -18,6 +18,10 @@ pub enum PoolQueryMsg {
     GetPoolState {},
     GetAllPools {},
     IsPaused {},
+    /// Returns the pool's `FeeInfoResponse`. Commit pools report their
+    /// creator wallet here; standard pools report the bluechip wallet in
+    /// both address slots.
+    FeeInfo {},
 }
 
 #[cw_serde]
@@ -52,6 +56,29 @@ impl Default for PoolKind {
         Self::Commit
     }
 }
+
+/// How a commit pool pays out `commit_return_amount` once its threshold
+/// crosses. Chosen per pool at `Create` and fixed for the pool's life.
+///
+/// - `Push` — keepers drive `ContinueDistribution` batches that mint
+///   every committer's share (the original behavior).
+/// - `Pull` — the pool freezes the raise totals at crossing and each
+///   committer mints their own share with `ClaimCommitReward`, whenever
+///   they like. No keepers, batches or stall recovery involved.
+///
+/// Default is `Push` so existing clients and stored records keep the
+/// keeper-driven flow.
+#[cw_serde]
+pub enum DistributionMode {
+    Push,
+    Pull,
+}
+
+impl Default for DistributionMode {
+    fn default() -> Self {
+        Self::Push
+    }
+}
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum FactoryQueryMsg {
@@ -87,6 +114,27 @@ pub enum FactoryQueryMsg {
     BluechipWalletAddress {},
 }
 
+/// Factory-governed protocol share of each pool's swap LP fee, per pool
+/// kind (`FactoryInstantiate.protocol_fee`, 48h timelocked). Passed to
+/// each pool at instantiate and pushed to existing pools through the
+/// factory's `SyncPoolProtocolFee`. Zero (the default) leaves the full
+/// LP fee with liquidity providers.
+#[cw_serde]
+#[derive(Default)]
+pub struct ProtocolFeeConfig {
+    pub commit_pool_share: Decimal,
+    pub standard_pool_share: Decimal,
+}
+
+impl ProtocolFeeConfig {
+    pub fn share_for(&self, kind: &PoolKind) -> Decimal {
+        match kind {
+            PoolKind::Commit => self.commit_pool_share,
+            PoolKind::Standard => self.standard_pool_share,
+        }
+    }
+}
+
 #[cw_serde]
 pub struct EmergencyWithdrawDelayResponse {
     pub delay_seconds: u64,
@@ -111,6 +159,19 @@ pub struct ConversionResponse {
     pub timestamp: u64,
 }
 
+#[cw_serde]
+pub struct CommitFeeInfo {
+    pub bluechip_wallet_address: Addr,
+    pub creator_wallet_address: Addr,
+    pub commit_fee_bluechip: Decimal,
+    pub commit_fee_creator: Decimal,
+}
+
+#[cw_serde]
+pub struct FeeInfoResponse {
+    pub fee_info: CommitFeeInfo,
+}
+
 #[cw_serde]
 pub struct PoolStateResponseForFactory {
     pub pool_contract_address: Addr,
@@ -140,6 +201,9 @@ pub enum FactoryExecuteMsg {
     // POOLS_BY_CONTRACT_ADDRESS, so unregistered contracts cannot drain
     // the reserve by pretending to be a pool.
     PayDistributionBounty { recipient: String },
+    // Called by a commit pool's AcceptCreatorWallet handler so the
+    // factory's registry and creator profile follow the new wallet.
+    NotifyCreatorWalletRotated { new_wallet: String },
 }
 
 #[cw_serde]
@@ -179,4 +243,113 @@ pub struct StandardPoolInstantiateMsg {
     /// the factory has no withdrawal mechanism, so funds drained to it
     /// would be permanently locked.
     pub bluechip_wallet_address: Addr,
+    /// Protocol share of swap fees (`ProtocolFeeConfig.standard_pool_share`).
+    #[serde(default)]
+    pub protocol_fee_share: Decimal,
+}
+
+/// Early-supporter weighting for a commit pool's committer rewards,
+/// chosen by the creator at `Create`. A pre-threshold commit made
+/// `t` seconds after pool creation counts its USD at
+/// `max_multiplier - (max_multiplier - 1) * t / decay_seconds` (and at
+/// 1x once `t >= decay_seconds`) when the commit-return tranche is
+/// shared out. The tranche total is unchanged; only the split moves
+/// toward earlier committers.
+#[cw_serde]
+pub struct EarlySupporterCurve {
+    pub max_multiplier: Decimal,
+    pub decay_seconds: u64,
+}
+
+impl EarlySupporterCurve {
+    /// The multiplier for a commit `elapsed_seconds` after pool creation.
+    pub fn multiplier_at(&self, elapsed_seconds: u64) -> Decimal {
+        if elapsed_seconds >= self.decay_seconds || self.max_multiplier <= Decimal::one() {
+            return Decimal::one();
+        }
+        let bonus = self.max_multiplier - Decimal::one();
+        let remaining = Decimal::from_ratio(
+            self.decay_seconds - elapsed_seconds,
+            self.decay_seconds,
+        );
+        Decimal::one() + bonus * remaining
+    }
+}
+
+/// Linear vesting for a commit pool's `creator_reward_amount`. Set on
+/// the factory (`FactoryInstantiate.creator_vesting`, 48h timelocked)
+/// and snapshotted into each commit pool's instantiate message. When the
+/// threshold crosses, the pool mints the creator reward to itself and
+/// releases it linearly from the crossing time over `duration_seconds`,
+/// with nothing claimable until `cliff_seconds` have passed.
+/// `duration_seconds == 0` (the default) disables vesting and the reward
+/// is minted straight to the creator as before.
+#[cw_serde]
+#[derive(Default)]
+pub struct CreatorVestingConfig {
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+}
+
+impl CreatorVestingConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.duration_seconds > 0
+    }
+}
+
+/// Early-access phase for a commit pool. Until `public_at`, only
+/// allowlisted wallets may make pre-threshold commits; afterwards
+/// commits are open to everyone. Set at `Create` or by the creator
+/// through the factory's `SetPoolCommitAllowlist`.
+///
+/// A wallet is allowlisted if it is in `wallets` or proves membership
+/// under `merkle_root`. Leaves are `sha256(address)` over the bech32
+/// address string; each parent is `sha256(min(a, b) || max(a, b))`, so
+/// a proof is just the sibling hashes from leaf to root.
+#[cw_serde]
+pub struct CommitAllowlist {
+    pub merkle_root: Option<HexBinary>,
+    #[serde(default)]
+    pub wallets: Vec<String>,
+    pub public_at: Timestamp,
+}
+
+/// A named membership tier on a commit pool, set by the pool's creator
+/// through the factory's `SetPoolSubscriptionTiers`. A commit tagged
+/// with the tier buys `duration_seconds` of membership for every
+/// `usd_price` (6 decimals) it is worth.
+#[cw_serde]
+pub struct SubscriptionTier {
+    pub name: String,
+    pub usd_price: Uint128,
+    pub duration_seconds: u64,
+}
+
+/// Post-threshold launch-window limits for commit pools. Set on the
+/// factory (`FactoryInstantiate.launch_protection`, 48h timelocked) and
+/// snapshotted into each commit pool's instantiate message; the pool
+/// arms the window when its threshold crosses.
+///
+/// For `window_blocks` blocks after the crossing, every swap and
+/// post-threshold commit is limited to `max_swap_bps` of the offer-side
+/// reserve, and each wallet may receive at most `max_wallet_buy_bps` of
+/// the freshly-seeded creator-token reserve in total. A zero bps value
+/// disables that particular limit; `window_blocks == 0` (the default)
+/// disables the window entirely.
+///
+/// Independently, `launch_fee` replaces the pool's LP fee as the swap
+/// commission once trading resumes and decays linearly back to
+/// `PoolSpecs.lp_fee` over `launch_fee_decay_blocks`. The portion above
+/// the LP fee is credited to the creator fee pot. A zero fee or zero
+/// decay length disables it.
+#[cw_serde]
+#[derive(Default)]
+pub struct LaunchProtectionConfig {
+    pub window_blocks: u64,
+    pub max_swap_bps: u16,
+    pub max_wallet_buy_bps: u16,
+    #[serde(default)]
+    pub launch_fee: Decimal,
+    #[serde(default)]
+    pub launch_fee_decay_blocks: u64,
 }
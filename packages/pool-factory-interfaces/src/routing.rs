@@ -91,6 +91,10 @@ pub struct RouterSwapSimulationResponse {
     pub return_amount: Uint128,
     pub spread_amount: Uint128,
     pub commission_amount: Uint128,
+    #[serde(default)]
+    pub commission_rate: Decimal,
+    #[serde(default)]
+    pub launch_fee_amount: Uint128,
 }
 
 /// JSON-equivalent of `pool::asset::PoolPairInfo`.
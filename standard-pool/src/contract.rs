@@ -17,7 +17,8 @@ use cosmwasm_std::{
 use cw2::set_contract_version;
 use pool_core::admin::{
     ensure_not_drained, execute_cancel_emergency_withdraw, execute_claim_emergency_share,
-    execute_emergency_withdraw_dispatch, execute_pause,
+    execute_collect_protocol_fees, execute_emergency_withdraw_dispatch, execute_pause,
+    execute_set_protocol_fee_share, save_protocol_fee_share,
     execute_sweep_unclaimed_emergency_shares, execute_unpause,
     execute_update_config_from_factory,
 };
@@ -144,6 +145,7 @@ pub fn instantiate(
     OWNER_POSITIONS.save(deps.storage, (&env.contract.address, "0"), &true)?;
     ORACLE_INFO.save(deps.storage, &oracle_info)?;
     POOL_ANALYTICS.save(deps.storage, &PoolAnalytics::default())?;
+    save_protocol_fee_share(deps.storage, msg.protocol_fee_share)?;
 
     Ok(Response::new()
         .add_attribute("action", "instantiate")
@@ -372,6 +374,10 @@ pub fn execute(
         ExecuteMsg::Pause {} => execute_pause(deps, env, info),
         ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
         ExecuteMsg::EmergencyWithdraw {} => execute_emergency_withdraw(deps, env, info),
+        ExecuteMsg::SetProtocolFeeShare { share } => {
+            execute_set_protocol_fee_share(deps, env, info, share)
+        }
+        ExecuteMsg::CollectProtocolFees {} => execute_collect_protocol_fees(deps, env, info),
         ExecuteMsg::CancelEmergencyWithdraw {} => {
             execute_cancel_emergency_withdraw(deps, env, info)
         }
@@ -435,11 +441,21 @@ pub fn execute(
         ExecuteMsg::CollectFees {
             position_id,
             transaction_deadline,
+            output_asset,
+            min_output,
         } => {
             // Permitted during EmergencyPending so an LP about to remove
             // can sweep their share of fee_reserve before the drain.
             check_pool_writable_for_remove(deps.storage)?;
-            execute_collect_fees(deps, env, info, position_id, transaction_deadline)
+            execute_collect_fees(
+                deps,
+                env,
+                info,
+                position_id,
+                transaction_deadline,
+                output_asset,
+                min_output,
+            )
         }
         ExecuteMsg::RemovePartialLiquidity {
             position_id,
@@ -15,7 +15,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Decimal, Timestamp, Uint128};
 use cw20::Cw20ReceiveMsg;
-use pool_core::asset::TokenInfo;
+use pool_core::asset::{TokenInfo, TokenType};
 
 // Response types referenced ONLY by the `#[returns(T)]` annotations on
 // `QueryMsg` variants below. cosmwasm-schema's `QueryResponses` derive
@@ -34,12 +34,14 @@ use pool_core::asset::TokenInfo;
 // from git history.
 #[allow(unused_imports)]
 use pool_core::msg::{
-    ConfigResponse, CumulativePricesResponse, FeeInfoResponse, PoolAnalyticsResponse,
-    PoolConfigUpdate, PoolFeeStateResponse, PoolInfoResponse, PoolStateResponse, PositionResponse,
-    PositionsResponse, ReverseSimulationResponse, SimulationResponse,
+    CandlesResponse, ConfigResponse, CumulativePricesResponse, FeeInfoResponse,
+    PoolAnalyticsResponse, PoolConfigUpdate, PoolFeeStateResponse, PoolInfoResponse,
+    PoolStateResponse, PositionResponse, PositionsResponse, ProtocolFeesResponse,
+    ReverseSimulationResponse, SimulationResponse, VolumeHistoryResponse,
 };
 #[allow(unused_imports)]
 use pool_core::state::PoolDetails;
+use pool_core::state::{CandleInterval, VolumeGranularity};
 #[allow(unused_imports)]
 use pool_factory_interfaces::{AllPoolsResponse, IsPausedResponse, PoolStateResponseForFactory};
 
@@ -98,6 +100,12 @@ pub enum ExecuteMsg {
         /// so a late collect can only return more, not less.
         #[serde(default)]
         transaction_deadline: Option<Timestamp>,
+        /// See the creator-pool `CollectFees` variant: pay all fees in
+        /// this asset, converting the other side through the reserves.
+        #[serde(default)]
+        output_asset: Option<TokenType>,
+        #[serde(default)]
+        min_output: Option<Uint128>,
     },
     RemovePartialLiquidity {
         position_id: String,
@@ -146,6 +154,13 @@ pub enum ExecuteMsg {
     // bluechip wallet. One-shot; `residual_swept` flag prevents
     // double-sweeps.
     SweepUnclaimedEmergencyShares {},
+
+    // Factory-only protocol fee switch: re-sync the protocol share of
+    // swap fees, and pay everything owed to the bluechip wallet.
+    SetProtocolFeeShare {
+        share: Decimal,
+    },
+    CollectProtocolFees {},
 }
 
 #[cw_serde]
@@ -191,6 +206,24 @@ pub enum QueryMsg {
     PoolInfo {},
     #[returns(PoolAnalyticsResponse)]
     Analytics {},
+    /// Recent hourly or daily volume + fee buckets, newest first.
+    /// `limit` defaults to (and is capped at) the ring's capacity.
+    #[returns(VolumeHistoryResponse)]
+    VolumeHistory {
+        granularity: VolumeGranularity,
+        limit: Option<u32>,
+    },
+    /// Protocol fee share plus owed / lifetime protocol fee totals.
+    #[returns(ProtocolFeesResponse)]
+    ProtocolFees {},
+    /// OHLC candles for `interval`, oldest first. `start_after` is an
+    /// exclusive candle start time for pagination.
+    #[returns(CandlesResponse)]
+    Candles {
+        interval: CandleInterval,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     /// Factory-facing variant — returns `PoolStateResponseForFactory`
     /// (different shape from the LP-side `PoolState {}` above).
     /// Forwarded to `pool_core::query::query_for_factory`. Frontend
@@ -7,9 +7,10 @@ use crate::msg::QueryMsg;
 use cosmwasm_std::{entry_point, to_json_binary, Binary, Deps, Env, StdResult, Uint128};
 use pool_core::msg::{CommitStatus, PoolAnalyticsResponse};
 use pool_core::query::{
-    query_analytics_core, query_config, query_cumulative_prices, query_fee_info, query_fee_state,
-    query_for_factory, query_pair_info, query_pool_info, query_pool_state, query_position,
-    query_positions, query_positions_by_owner, query_reverse_simulation, query_simulation,
+    query_analytics_core, query_candles, query_config, query_cumulative_prices, query_fee_info,
+    query_fee_state, query_for_factory, query_pair_info, query_pool_info, query_pool_state,
+    query_position, query_positions, query_positions_by_owner, query_protocol_fees,
+    query_reverse_simulation, query_simulation, query_volume_history,
 };
 use pool_factory_interfaces::PoolQueryMsg;
 
@@ -19,7 +20,8 @@ use pool_factory_interfaces::PoolQueryMsg;
 ///
 /// - **LP / SDK shapes**: `Pair`, `Config`, `Simulation`,
 /// `ReverseSimulation`, `CumulativePrices`, `FeeInfo`, `PoolState`,
-/// `FeeState`, `Position*`, `PoolInfo`, `Analytics`. Each
+/// `FeeState`, `Position*`, `PoolInfo`, `Analytics`, `VolumeHistory`,
+/// `Candles`, `ProtocolFees`. Each
 /// forwards to a shared `pool_core::query::*` handler. The
 /// `PoolState {}` shape (`PoolStateResponse`) is the LP-side type;
 /// `GetPoolState {}` below is a different shape for factory use.
@@ -39,10 +41,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Pair {} => to_json_binary(&query_pair_info(deps)?),
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
         QueryMsg::Simulation { offer_asset } => {
-            to_json_binary(&query_simulation(deps, offer_asset)?)
+            to_json_binary(&query_simulation(deps, env, offer_asset)?)
         }
         QueryMsg::ReverseSimulation { ask_asset } => {
-            to_json_binary(&query_reverse_simulation(deps, ask_asset)?)
+            to_json_binary(&query_reverse_simulation(deps, env, ask_asset)?)
         }
         QueryMsg::CumulativePrices {} => to_json_binary(&query_cumulative_prices(deps, env)?),
         QueryMsg::FeeInfo {} => to_json_binary(&query_fee_info(deps)?),
@@ -58,7 +60,16 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             limit,
         } => to_json_binary(&query_positions_by_owner(deps, owner, start_after, limit)?),
         QueryMsg::PoolInfo {} => to_json_binary(&query_pool_info(deps)?),
-        QueryMsg::Analytics {} => to_json_binary(&query_analytics(deps)?),
+        QueryMsg::Analytics {} => to_json_binary(&query_analytics(deps, env)?),
+        QueryMsg::VolumeHistory { granularity, limit } => {
+            to_json_binary(&query_volume_history(deps, env, granularity, limit)?)
+        }
+        QueryMsg::ProtocolFees {} => to_json_binary(&query_protocol_fees(deps)?),
+        QueryMsg::Candles {
+            interval,
+            start_after,
+            limit,
+        } => to_json_binary(&query_candles(deps, interval, start_after, limit)?),
         QueryMsg::GetPoolState {} => {
             query_for_factory(deps, env, PoolQueryMsg::GetPoolState {})
         }
@@ -69,9 +80,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
 /// Standard-pool analytics wrapper: no commit ledger, no threshold —
 /// always FullyCommitted, zero raised on both sides.
-fn query_analytics(deps: Deps) -> StdResult<PoolAnalyticsResponse> {
+fn query_analytics(deps: Deps, env: Env) -> StdResult<PoolAnalyticsResponse> {
     query_analytics_core(
         deps,
+        env,
         CommitStatus::FullyCommitted,
         Uint128::zero(),
         Uint128::zero(),
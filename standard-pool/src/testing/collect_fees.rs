@@ -5,8 +5,9 @@
 
 use cosmwasm_std::testing::{message_info, mock_env};
 use cosmwasm_std::{Coin, CosmosMsg, Decimal, Uint128, WasmMsg};
+use pool_core::asset::TokenType;
 use pool_core::state::{
-    CreatorFeePot, PoolFeeState, CREATOR_FEE_POT, LIQUIDITY_POSITIONS, POOL_FEE_STATE,
+    CreatorFeePot, PoolFeeState, CREATOR_FEE_POT, LIQUIDITY_POSITIONS, POOL_FEE_STATE, POOL_STATE,
 };
 
 use super::fixtures::{instantiate_default_pool, BLUECHIP_DENOM};
@@ -84,6 +85,8 @@ fn collect_fees_emits_transfers_and_debits_reserve() {
         ExecuteMsg::CollectFees {
             position_id: "1".to_string(),
             transaction_deadline: None,
+            output_asset: None,
+            min_output: None,
         },
     )
     .unwrap();
@@ -120,6 +123,101 @@ fn collect_fees_emits_transfers_and_debits_reserve() {
     assert!(fees_after.fee_reserve_1 < Uint128::new(10_000_000));
 }
 
+/// With `output_asset`, the CW20-side fees are swapped through the
+/// reserves and everything is paid out as a single native transfer.
+#[test]
+fn collect_fees_with_output_asset_pays_single_asset() {
+    let (mut deps, addrs) = instantiate_default_pool();
+    deposit(&mut deps, &addrs.pool_owner);
+    seed_fees(
+        &mut deps,
+        Decimal::permille(1),
+        Uint128::new(10_000_000),
+        Uint128::new(10_000_000),
+    );
+    let native = TokenType::Native {
+        denom: BLUECHIP_DENOM.to_string(),
+    };
+    let state_before = POOL_STATE.load(&deps.storage).unwrap();
+
+    // Unknown asset and an unreachable minimum are both rejected.
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.pool_owner, &[]),
+        ExecuteMsg::CollectFees {
+            position_id: "1".to_string(),
+            transaction_deadline: None,
+            output_asset: Some(TokenType::Native {
+                denom: "uatom".to_string(),
+            }),
+            min_output: None,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::AssetMismatch {}));
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.pool_owner, &[]),
+        ExecuteMsg::CollectFees {
+            position_id: "1".to_string(),
+            transaction_deadline: None,
+            output_asset: Some(native.clone()),
+            min_output: Some(Uint128::new(10_000_000)),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::SlippageExceeded { .. }));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.pool_owner, &[]),
+        ExecuteMsg::CollectFees {
+            position_id: "1".to_string(),
+            transaction_deadline: None,
+            output_asset: Some(native),
+            min_output: Some(Uint128::new(1)),
+        },
+    )
+    .unwrap();
+
+    let attr = |key: &str| -> Uint128 {
+        res.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .unwrap()
+            .value
+            .parse()
+            .unwrap()
+    };
+    let (fees_0, fees_1, payout_0) = (attr("fees_0"), attr("fees_1"), attr("payout_0"));
+    assert!(attr("payout_1").is_zero());
+    assert_eq!(attr("converted_amount"), fees_1);
+    assert!(!attr("conversion_commission").is_zero());
+    assert!(payout_0 > fees_0);
+
+    assert_eq!(res.messages.len(), 1);
+    match &res.messages[0].msg {
+        CosmosMsg::Bank(cosmwasm_std::BankMsg::Send { to_address, amount }) => {
+            assert_eq!(to_address, addrs.pool_owner.as_str());
+            assert_eq!(amount[0].amount, payout_0);
+        }
+        other => panic!("expected a single bank send, got {:?}", other),
+    }
+
+    // The CW20 fees went into reserve1; the converted native came out
+    // of reserve0 along with the commission, which is back in fee
+    // reserve for LPs.
+    let state_after = POOL_STATE.load(&deps.storage).unwrap();
+    assert_eq!(state_after.reserve1, state_before.reserve1 + fees_1);
+    assert_eq!(
+        state_after.reserve0,
+        state_before.reserve0 - (payout_0 - fees_0) - attr("conversion_commission")
+    );
+}
+
 /// Standard pools bypass the `fee_size_multiplier` dust-griefing
 /// penalty (the multiplier is pinned at `Decimal::one()` at deposit /
 /// add / remove time on standard pools via `APPLY_DUST_MULTIPLIER ==
@@ -169,6 +267,8 @@ fn standard_pool_collect_fees_does_not_clip_to_creator_pot() {
         ExecuteMsg::CollectFees {
             position_id: "1".to_string(),
             transaction_deadline: None,
+            output_asset: None,
+            min_output: None,
         },
     )
     .unwrap();
@@ -249,6 +349,8 @@ fn collect_fees_rejects_non_owner() {
         ExecuteMsg::CollectFees {
             position_id: "1".to_string(),
             transaction_deadline: None,
+            output_asset: None,
+            min_output: None,
         },
     )
     .unwrap_err();
@@ -269,6 +371,8 @@ fn collect_fees_on_zero_growth_returns_zero_transfers() {
         ExecuteMsg::CollectFees {
             position_id: "1".to_string(),
             transaction_deadline: None,
+            output_asset: None,
+            min_output: None,
         },
     )
     .unwrap();
@@ -125,6 +125,7 @@ pub fn standard_instantiate_msg(addrs: &FixtureAddrs) -> StandardPoolInstantiate
         used_factory_addr: addrs.factory.clone(),
         position_nft_address: addrs.position_nft.clone(),
         bluechip_wallet_address: addrs.bluechip_wallet.clone(),
+        protocol_fee_share: Default::default(),
     }
 }
 
@@ -12,6 +12,7 @@ mod deposit_liquidity;
 mod emergency_withdraw;
 mod fixtures;
 mod instantiation;
+mod protocol_fees;
 mod queries;
 mod remove_liquidity;
 mod swap;
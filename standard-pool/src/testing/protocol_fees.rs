@@ -0,0 +1,177 @@
+//! Pool-core protocol fee switch via standard-pool's execute / query
+//! dispatch: factory-only share updates, accrual on swaps, and the
+//! factory-triggered collection to the bluechip wallet.
+
+use cosmwasm_std::testing::{message_info, mock_env};
+use cosmwasm_std::{from_json, Coin, CosmosMsg, Decimal, Uint128, WasmMsg};
+use pool_core::asset::{TokenInfo, TokenType};
+use pool_core::msg::ProtocolFeesResponse;
+
+use super::fixtures::{instantiate_default_pool, BLUECHIP_DENOM};
+use crate::contract::execute;
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::query::query;
+
+fn seed_pool(
+    deps: &mut cosmwasm_std::OwnedDeps<
+        cosmwasm_std::testing::MockStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >,
+    user: &cosmwasm_std::Addr,
+) {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(user, &[Coin::new(1_000_000_000u128, BLUECHIP_DENOM)]),
+        ExecuteMsg::DepositLiquidity {
+            amount0: Uint128::new(1_000_000_000),
+            amount1: Uint128::new(2_000_000_000),
+            min_amount0: None,
+            min_amount1: None,
+            transaction_deadline: None,
+        },
+    )
+    .unwrap();
+}
+
+fn protocol_fees(
+    deps: &cosmwasm_std::OwnedDeps<
+        cosmwasm_std::testing::MockStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >,
+) -> ProtocolFeesResponse {
+    from_json(query(deps.as_ref(), mock_env(), QueryMsg::ProtocolFees {}).unwrap()).unwrap()
+}
+
+#[test]
+fn set_protocol_fee_share_is_factory_only_and_bounded() {
+    let (mut deps, addrs) = instantiate_default_pool();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.pool_owner, &[]),
+        ExecuteMsg::SetProtocolFeeShare {
+            share: Decimal::percent(10),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.factory, &[]),
+        ExecuteMsg::SetProtocolFeeShare {
+            share: Decimal::percent(51),
+        },
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("protocol fee share"));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.factory, &[]),
+        ExecuteMsg::SetProtocolFeeShare {
+            share: Decimal::percent(50),
+        },
+    )
+    .unwrap();
+    assert_eq!(protocol_fees(&deps).share, Decimal::percent(50));
+}
+
+#[test]
+fn swap_accrues_protocol_fee_and_factory_collects_to_bluechip_wallet() {
+    let (mut deps, addrs) = instantiate_default_pool();
+    seed_pool(&mut deps, &addrs.pool_owner);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.factory, &[]),
+        ExecuteMsg::SetProtocolFeeShare {
+            share: Decimal::percent(20),
+        },
+    )
+    .unwrap();
+
+    let trader = cosmwasm_std::testing::MockApi::default().addr_make("trader");
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&trader, &[Coin::new(1_000_000u128, BLUECHIP_DENOM)]),
+        ExecuteMsg::SimpleSwap {
+            offer_asset: TokenInfo {
+                info: TokenType::Native {
+                    denom: BLUECHIP_DENOM.to_string(),
+                },
+                amount: Uint128::new(1_000_000),
+            },
+            belief_price: None,
+            max_spread: None,
+            allow_high_max_spread: None,
+            to: None,
+            transaction_deadline: None,
+        },
+    )
+    .unwrap();
+
+    // Native offered, so the commission (and the protocol cut) is in the
+    // CW20 side.
+    let fees = protocol_fees(&deps);
+    assert!(fees.owed_0.is_zero());
+    assert!(!fees.owed_1.is_zero());
+    assert_eq!(fees.total_accrued_1, fees.owed_1);
+    let attr = res
+        .attributes
+        .iter()
+        .find(|a| a.key == "protocol_fee_amount")
+        .unwrap();
+    assert_eq!(attr.value, fees.owed_1.to_string());
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&trader, &[]),
+        ExecuteMsg::CollectProtocolFees {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // The fixture's factory mock doesn't answer BluechipWalletAddress,
+    // so collection falls back to the instantiate-time wallet.
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.factory, &[]),
+        ExecuteMsg::CollectProtocolFees {},
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, msg, .. }) => {
+            assert_eq!(contract_addr, addrs.creator_token.as_str());
+            let body = String::from_utf8_lossy(msg.as_slice());
+            assert!(body.contains(addrs.bluechip_wallet.as_str()));
+            assert!(body.contains(&fees.owed_1.to_string()));
+        }
+        other => panic!("expected CW20 transfer, got {:?}", other),
+    }
+
+    let after = protocol_fees(&deps);
+    assert!(after.owed_1.is_zero());
+    assert_eq!(after.total_collected_1, fees.owed_1);
+    assert_eq!(after.total_accrued_1, fees.owed_1);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&addrs.factory, &[]),
+        ExecuteMsg::CollectProtocolFees {},
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NoProtocolFeesOwed {}));
+}
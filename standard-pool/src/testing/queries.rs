@@ -4,10 +4,13 @@ use cosmwasm_std::testing::{message_info, mock_env};
 use cosmwasm_std::{from_json, Coin, Uint128};
 use pool_core::asset::TokenType;
 use pool_core::msg::{
-    CommitStatus, ConfigResponse, FeeInfoResponse, PoolAnalyticsResponse, PoolFeeStateResponse,
-    PoolStateResponse, PositionResponse, PositionsResponse,
+    CandlesResponse, CommitStatus, ConfigResponse, FeeInfoResponse, PoolAnalyticsResponse,
+    PoolFeeStateResponse, PoolStateResponse, PositionResponse, PositionsResponse,
+    VolumeHistoryResponse,
 };
-use pool_core::state::PoolDetails;
+use pool_core::asset::TokenInfo;
+use pool_core::state::{CandleInterval, PoolDetails, VolumeGranularity, SECONDS_PER_HOUR};
+use pool_core::swap::PRICE_ACCUMULATOR_SCALE;
 
 use super::fixtures::{instantiate_default_pool, BLUECHIP_DENOM};
 use crate::contract::execute;
@@ -153,3 +156,134 @@ fn query_analytics_always_fully_committed() {
     assert_eq!(resp.total_usd_raised, Uint128::zero());
     assert_eq!(resp.total_bluechip_raised, Uint128::zero());
 }
+
+#[test]
+fn query_volume_history_and_fee_apr_after_swap() {
+    let (mut deps, addrs) = instantiate_default_pool();
+    seed(&mut deps, &addrs.pool_owner);
+
+    // No trades yet: empty history, zero APR.
+    let bin = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::VolumeHistory {
+            granularity: VolumeGranularity::Hourly,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let resp: VolumeHistoryResponse = from_json(bin).unwrap();
+    assert_eq!(resp.bucket_seconds, SECONDS_PER_HOUR);
+    assert!(resp.buckets.is_empty());
+
+    let trader = cosmwasm_std::testing::MockApi::default().addr_make("trader");
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&trader, &[Coin::new(1_000_000u128, BLUECHIP_DENOM)]),
+        ExecuteMsg::SimpleSwap {
+            offer_asset: TokenInfo {
+                info: TokenType::Native {
+                    denom: BLUECHIP_DENOM.to_string(),
+                },
+                amount: Uint128::new(1_000_000),
+            },
+            belief_price: None,
+            max_spread: None,
+            allow_high_max_spread: None,
+            to: None,
+            transaction_deadline: None,
+        },
+    )
+    .unwrap();
+
+    for granularity in [VolumeGranularity::Hourly, VolumeGranularity::Daily] {
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::VolumeHistory {
+                granularity,
+                limit: Some(5),
+            },
+        )
+        .unwrap();
+        let resp: VolumeHistoryResponse = from_json(bin).unwrap();
+        assert_eq!(resp.buckets.len(), 1);
+        let bucket = &resp.buckets[0];
+        let now = mock_env().block.time.seconds();
+        assert_eq!(bucket.start, now - now % resp.bucket_seconds);
+        assert_eq!(bucket.volume_0, Uint128::new(1_000_000));
+        assert_eq!(bucket.trade_count, 1);
+        // Native offered -> commission charged in the creator token.
+        assert!(bucket.fees_0.is_zero());
+        assert!(!bucket.fees_1.is_zero());
+    }
+
+    let bin = query(deps.as_ref(), mock_env(), QueryMsg::Analytics {}).unwrap();
+    let analytics: PoolAnalyticsResponse = from_json(bin).unwrap();
+    assert!(analytics.fee_apr > cosmwasm_std::Decimal::zero());
+}
+
+#[test]
+fn query_candles_paginates_after_swaps() {
+    let (mut deps, addrs) = instantiate_default_pool();
+    seed(&mut deps, &addrs.pool_owner);
+
+    let trader = cosmwasm_std::testing::MockApi::default().addr_make("trader");
+    let swap = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, env: cosmwasm_std::Env| {
+        execute(
+            deps.as_mut(),
+            env,
+            message_info(&trader, &[Coin::new(1_000_000u128, BLUECHIP_DENOM)]),
+            ExecuteMsg::SimpleSwap {
+                offer_asset: TokenInfo {
+                    info: TokenType::Native {
+                        denom: BLUECHIP_DENOM.to_string(),
+                    },
+                    amount: Uint128::new(1_000_000),
+                },
+                belief_price: None,
+                max_spread: None,
+                allow_high_max_spread: None,
+                to: None,
+                transaction_deadline: None,
+            },
+        )
+        .unwrap();
+    };
+
+    // Two trades in consecutive hours.
+    let mut env = mock_env();
+    swap(&mut deps, env.clone());
+    env.block.time = env.block.time.plus_seconds(SECONDS_PER_HOUR);
+    env.block.height += 1_000;
+    swap(&mut deps, env.clone());
+
+    let candles_query = |start_after, limit| QueryMsg::Candles {
+        interval: CandleInterval::Hourly,
+        start_after,
+        limit,
+    };
+    let bin = query(deps.as_ref(), env.clone(), candles_query(None, None)).unwrap();
+    let resp: CandlesResponse = from_json(bin).unwrap();
+    assert_eq!(resp.interval_seconds, SECONDS_PER_HOUR);
+    assert_eq!(resp.candles.len(), 2);
+    let (first, second) = (&resp.candles[0], &resp.candles[1]);
+    assert!(first.start < second.start);
+    // Seeded at 1:2, so the first candle opens at 0.5 scaled. Buying the
+    // creator token pushes its bluechip price up across both candles.
+    assert_eq!(first.open, Uint128::new(PRICE_ACCUMULATOR_SCALE / 2));
+    assert!(first.close > first.open);
+    assert_eq!(second.open, first.close);
+    assert!(second.close > second.open);
+    assert_eq!(first.volume_0, Uint128::new(1_000_000));
+
+    let bin = query(
+        deps.as_ref(),
+        env,
+        candles_query(Some(first.start), Some(10)),
+    )
+    .unwrap();
+    let page: CandlesResponse = from_json(bin).unwrap();
+    assert_eq!(page.candles, vec![second.clone()]);
+}